@@ -5,7 +5,7 @@ use cosmwasm_schema::{export_schema, remove_schemas, schema_for};
 use satoshis_palace_bullvsbear::{
     msgs::{
         execute::execute_msg::ExecuteMsg, instantiate::InstantiateMsg,
-        invoke::invoke_msg::InvokeMsg, query::query_msg::QueryMsg,
+        invoke::invoke_msg::InvokeMsg, migrate::MigrateMsg, query::query_msg::QueryMsg,
     },
     responses::{execute::execute_response::ExecuteResponse, query::query_response::QueryResponse},
 };
@@ -22,4 +22,5 @@ fn main() {
     export_schema(&schema_for!(QueryMsg), &out_dir);
     export_schema(&schema_for!(QueryResponse), &out_dir);
     export_schema(&schema_for!(InvokeMsg), &out_dir);
+    export_schema(&schema_for!(MigrateMsg), &out_dir);
 }
@@ -1,14 +1,63 @@
-use cosmwasm_std::{DepsMut, MessageInfo, Response, StdResult, Uint128};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, StdResult};
 use sp_secret_toolkit::snip20::Snip20;
 
-use crate::data::state::FeePercent;
+use crate::data::fee_recipients::FeeRecipient;
+use crate::data::roles::Role;
+use crate::data::state::{BetLimits, FeePercent, PendingFeeChange};
+use crate::error::currency_error::CurrencyError;
+use crate::error::state_error::StateError;
+use crate::msgs::execute::commands::accept_ownership::AcceptOwnership;
+use crate::msgs::execute::commands::add_accepted_token::AddAcceptedToken;
+use crate::msgs::execute::commands::add_ticker::AddTicker;
+use crate::msgs::execute::commands::cancel_ownership_transfer::CancelOwnershipTransfer;
+use crate::msgs::execute::commands::claim_fees::ClaimFees;
+use crate::msgs::execute::commands::create_contest::CreateContest;
+use crate::msgs::execute::commands::distribute_fees::DistributeFees;
+use crate::msgs::execute::commands::fail_safe::FailSafe;
+use crate::msgs::execute::commands::grant_role::GrantRole;
+use crate::msgs::execute::commands::pause_ticker::PauseTicker;
+use crate::msgs::execute::commands::propose_new_owner::ProposeNewOwner;
+use crate::msgs::execute::commands::remove_accepted_token::RemoveAcceptedToken;
+use crate::msgs::execute::commands::remove_ticker::RemoveTicker;
+use crate::msgs::execute::commands::resolve_contest_manually::ResolveContestManually;
+use crate::msgs::execute::commands::revoke_role::RevokeRole;
+use crate::msgs::execute::commands::schedule_fee_change::ScheduleFeeChange;
+use crate::msgs::execute::commands::set_bet_limits::SetBetLimits;
+use crate::msgs::execute::commands::set_betting_cutoff::SetBettingCutoff;
+use crate::msgs::execute::commands::set_cancellation_fee::SetCancellationFee;
+use crate::msgs::execute::commands::set_expiration_window::SetExpirationWindow;
 use crate::msgs::execute::commands::set_fee::SetFee;
+use crate::msgs::execute::commands::set_fee_recipients::SetFeeRecipients;
+use crate::msgs::execute::commands::set_keeper_reward::SetKeeperReward;
+use crate::msgs::execute::commands::set_max_fee::SetMaxFee;
+use crate::msgs::execute::commands::set_native_denom::SetNativeDenom;
+use crate::msgs::execute::commands::set_switching_fee::SetSwitchingFee;
+use crate::msgs::execute::commands::set_ticker_config::SetTickerConfig;
+use crate::msgs::execute::commands::set_ticker_outcomes::SetTickerOutcomes;
+use crate::msgs::execute::commands::void_contest::VoidContest;
 use crate::responses::execute::execute_response::ResponseStatus::Success;
+use crate::responses::execute::response_types::distribute_fees::{
+    DistributeFeesResponse, FeeDistribution,
+};
+use crate::services::contest_bet_summary_service::{
+    create_new_contest_bet_summary, resolve_contest_manually, void_contest,
+};
+use crate::services::contest_info_service::create_custom_contest;
+use crate::services::contests_service::add_active_contest;
+use crate::services::currency_service::{
+    add_accepted_token, create_send_msg, create_set_viewing_key_submsg, remove_accepted_token,
+    set_bet_limits_in, set_minimum_bet_in,
+};
+use crate::services::fee_service::{distribute_fees, set_fee_recipients, withdraw_fees};
+use crate::services::ticker_service::{
+    add_ticker, assert_ticker_enabled, remove_ticker, set_ticker_config, set_ticker_outcome_bands,
+    set_ticker_paused,
+};
 use crate::{
     data::state::State,
     msgs::execute::commands::set_minimum_bet::SetMinimumBet,
     responses::execute::{execute_response::ExecuteResponse, response_types::claim::ClaimResponse},
-    services::state_service::get_claimable_fees,
+    services::state_service::{assert_role, grant_role, revoke_role},
 };
 
 pub fn handle_set_minimum_bet(
@@ -16,38 +65,586 @@ pub fn handle_set_minimum_bet(
     info: MessageInfo,
     command: SetMinimumBet,
 ) -> StdResult<Response> {
+    assert_role(deps.storage, &info.sender, Role::FeeManager)?;
+
+    set_minimum_bet_in(
+        deps.storage,
+        &command.currency.unwrap_or_default(),
+        command.amount,
+    )?;
+    Ok(Response::default())
+}
+
+pub fn handle_add_accepted_token(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    command: AddAcceptedToken,
+) -> StdResult<Response> {
+    assert_role(deps.storage, &info.sender, Role::Treasury)?;
+
+    let snip20 = Snip20::new(&mut deps, &env, &info, &command.token, &command.entropy);
+    let is_new_token = add_accepted_token(deps.storage, snip20.clone(), command.minimum_bet)?;
+
+    let mut response = Response::default()
+        .add_attribute("action", "add_accepted_token")
+        .add_attribute("token", command.token.address.as_str());
+    // Tokens re-added after removal already send Receive to this contract
+    if is_new_token {
+        response = response.add_message(snip20.create_register_receive_msg(&env)?);
+    }
+    // Re-adding a token retries a viewing key that failed to set
+    let address = command.token.address.into_string();
+    if let Some(submsg) = create_set_viewing_key_submsg(deps.storage, &address)? {
+        response = response.add_submessage(submsg);
+    }
+    Ok(response)
+}
+
+pub fn handle_remove_accepted_token(
+    deps: DepsMut,
+    info: MessageInfo,
+    command: RemoveAcceptedToken,
+) -> StdResult<Response> {
+    assert_role(deps.storage, &info.sender, Role::Treasury)?;
+
+    remove_accepted_token(deps.storage, &command.address)?;
+    Ok(Response::default()
+        .add_attribute("action", "remove_accepted_token")
+        .add_attribute("token", command.address))
+}
+
+pub fn handle_set_native_denom(
+    deps: DepsMut,
+    info: MessageInfo,
+    command: SetNativeDenom,
+) -> StdResult<Response> {
+    assert_role(deps.storage, &info.sender, Role::Treasury)?;
     let mut state = State::singleton_load(deps.storage)?;
-    state.assert_owner(&info.sender)?;
 
-    state.set_minimum_bet(command.amount);
+    // Enabling native bets needs a minimum, disabling them keeps the last one
+    match (&command.denom, command.minimum_bet) {
+        (_, Some(minimum_bet)) => {
+            state.set_native_minimum_bet(minimum_bet);
+        }
+        (Some(denom), None) => {
+            return Err(CurrencyError::NativeMinimumBetRequired(denom.clone()).into());
+        }
+        (None, None) => {}
+    }
+    state.set_native_denom(command.denom.clone());
     state.singleton_save(deps.storage)?;
-    Ok(Response::default())
+    Ok(Response::default()
+        .add_attribute("action", "set_native_denom")
+        .add_attribute("denom", command.denom.unwrap_or_default()))
+}
+
+pub fn handle_set_bet_limits(
+    deps: DepsMut,
+    info: MessageInfo,
+    command: SetBetLimits,
+) -> StdResult<Response> {
+    assert_role(deps.storage, &info.sender, Role::FeeManager)?;
+
+    // Bets already placed above a new limit are kept, only new stakes are checked
+    set_bet_limits_in(
+        deps.storage,
+        &command.currency.unwrap_or_default(),
+        BetLimits::new(
+            command.max_user_bet,
+            command.max_pool,
+            command.max_user_exposure,
+        ),
+    )?;
+    Ok(Response::default().add_attribute("action", "set_bet_limits"))
 }
 
-pub fn handle_set_fee(deps: DepsMut, info: MessageInfo, command: SetFee) -> StdResult<Response> {
+pub fn handle_set_betting_cutoff(
+    deps: DepsMut,
+    info: MessageInfo,
+    command: SetBettingCutoff,
+) -> StdResult<Response> {
     let mut state = State::singleton_load(deps.storage)?;
-    state.assert_owner(&info.sender)?;
+    assert_role(deps.storage, &info.sender, Role::TickerManager)?;
+
+    state.set_betting_cutoff_seconds(command.seconds);
+    state.singleton_save(deps.storage)?;
+    Ok(Response::default()
+        .add_attribute("action", "set_betting_cutoff")
+        .add_attribute("seconds", command.seconds.to_string()))
+}
+
+pub fn handle_set_fee(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    command: SetFee,
+) -> StdResult<Response> {
+    let mut state = State::singleton_load(deps.storage)?;
+    assert_role(deps.storage, &info.sender, Role::FeeManager)?;
     let fee_percent = FeePercent::new(command.numerator as u128, command.denominator as u128);
+    state.assert_fee_allowed(&fee_percent)?;
+    // A change that is already due is superseded, one still in the future stays scheduled
+    state.apply_pending_fee_change(env.block.time.seconds());
     state.set_fee_percent(fee_percent);
     state.singleton_save(deps.storage)?;
     Ok(Response::default())
 }
 
-pub fn handle_claim_fees(deps: DepsMut, info: MessageInfo) -> StdResult<Response> {
+pub fn handle_schedule_fee_change(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    command: ScheduleFeeChange,
+) -> StdResult<Response> {
     let mut state = State::singleton_load(deps.storage)?;
-    let snip20 = Snip20::singleton_load(deps.storage)?;
+    assert_role(deps.storage, &info.sender, Role::FeeManager)?;
+
+    let now = env.block.time.seconds();
+    if command.effective_at <= now {
+        return Err(StateError::FeeChangeNotInFuture {
+            effective_at: command.effective_at,
+            now,
+        }
+        .into());
+    }
+
+    let fee_percent = FeePercent::new(command.numerator as u128, command.denominator as u128);
+    state.assert_fee_allowed(&fee_percent)?;
+
+    state.apply_pending_fee_change(now);
+    state.set_pending_fee_change(Some(PendingFeeChange::new(
+        fee_percent,
+        command.effective_at,
+    )));
+    state.singleton_save(deps.storage)?;
 
+    Ok(Response::default()
+        .add_attribute("action", "schedule_fee_change")
+        .add_attribute("fee_numerator", command.numerator.to_string())
+        .add_attribute("fee_denominator", command.denominator.to_string())
+        .add_attribute("effective_at", command.effective_at.to_string()))
+}
+
+pub fn handle_set_max_fee(
+    deps: DepsMut,
+    info: MessageInfo,
+    command: SetMaxFee,
+) -> StdResult<Response> {
+    let mut state = State::singleton_load(deps.storage)?;
+    // Owner only, the cap is what bounds the fee manager
     state.assert_owner(&info.sender)?;
 
-    let claimable_amount = get_claimable_fees(deps.storage).unwrap();
+    let max_fee_percent = FeePercent::new(command.numerator as u128, command.denominator as u128);
+    max_fee_percent.validate()?;
+    // The current and any scheduled fee have to fit under the new cap
+    state.set_max_fee_percent(max_fee_percent);
+    state.assert_fee_allowed(state.fee_percent())?;
+    if let Some(change) = state.pending_fee_change() {
+        state.assert_fee_allowed(change.fee_percent())?;
+    }
+    state.assert_fee_allowed(state.cancellation_fee_percent())?;
+    state.assert_fee_allowed(state.switching_fee_percent())?;
 
-    state.set_claimable_fees(Uint128::zero());
+    state.singleton_save(deps.storage)?;
+    Ok(Response::default())
+}
+
+pub fn handle_set_cancellation_fee(
+    deps: DepsMut,
+    info: MessageInfo,
+    command: SetCancellationFee,
+) -> StdResult<Response> {
+    let mut state = State::singleton_load(deps.storage)?;
+    assert_role(deps.storage, &info.sender, Role::FeeManager)?;
+
+    let fee_percent = FeePercent::new(command.numerator as u128, command.denominator as u128);
+    state.assert_fee_allowed(&fee_percent)?;
+    state.set_cancellation_fee_percent(fee_percent);
     state.singleton_save(deps.storage)?;
 
     Ok(Response::default()
-        .add_message(snip20.create_send_msg(&info.sender.into_string(), &claimable_amount)?)
+        .add_attribute("action", "set_cancellation_fee")
+        .add_attribute("fee_numerator", command.numerator.to_string())
+        .add_attribute("fee_denominator", command.denominator.to_string()))
+}
+
+pub fn handle_set_switching_fee(
+    deps: DepsMut,
+    info: MessageInfo,
+    command: SetSwitchingFee,
+) -> StdResult<Response> {
+    let mut state = State::singleton_load(deps.storage)?;
+    assert_role(deps.storage, &info.sender, Role::FeeManager)?;
+
+    let fee_percent = FeePercent::new(command.numerator as u128, command.denominator as u128);
+    state.assert_fee_allowed(&fee_percent)?;
+    state.set_switching_fee_percent(fee_percent);
+    state.singleton_save(deps.storage)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "set_switching_fee")
+        .add_attribute("fee_numerator", command.numerator.to_string())
+        .add_attribute("fee_denominator", command.denominator.to_string()))
+}
+
+pub fn handle_set_keeper_reward(
+    deps: DepsMut,
+    info: MessageInfo,
+    command: SetKeeperReward,
+) -> StdResult<Response> {
+    let mut state = State::singleton_load(deps.storage)?;
+    assert_role(deps.storage, &info.sender, Role::FeeManager)?;
+
+    // A share of the contest fee rather than of the stake, so the fee cap does not apply
+    let reward_percent = FeePercent::new(command.numerator as u128, command.denominator as u128);
+    reward_percent.validate()?;
+    state.set_keeper_reward_percent(reward_percent);
+    state.singleton_save(deps.storage)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "set_keeper_reward")
+        .add_attribute("reward_numerator", command.numerator.to_string())
+        .add_attribute("reward_denominator", command.denominator.to_string()))
+}
+
+pub fn handle_claim_fees(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    command: ClaimFees,
+) -> StdResult<Response> {
+    assert_role(deps.storage, &info.sender, Role::Treasury)?;
+
+    let recipient = match command.recipient {
+        Some(recipient) => deps.api.addr_validate(recipient.as_str())?,
+        None => info.sender,
+    };
+    let currency = command.currency.unwrap_or_default();
+    let claimed_amount = withdraw_fees(deps.storage, &env, &currency, command.amount, &recipient)?;
+
+    Ok(Response::default()
+        .add_message(create_send_msg(
+            deps.storage,
+            &currency,
+            &recipient.into_string(),
+            &claimed_amount,
+        )?)
         .set_data(ExecuteResponse::Claim(ClaimResponse {
             status: Success,
-            amount: claimable_amount,
+            amount: claimed_amount,
         })))
 }
+
+pub fn handle_set_fee_recipients(
+    deps: DepsMut,
+    info: MessageInfo,
+    command: SetFeeRecipients,
+) -> StdResult<Response> {
+    assert_role(deps.storage, &info.sender, Role::Treasury)?;
+
+    let mut recipients = Vec::new();
+    for recipient in command.recipients.iter() {
+        let address = deps.api.addr_validate(recipient.address().as_str())?;
+        recipients.push(FeeRecipient::new(address, *recipient.share_bps()));
+    }
+
+    set_fee_recipients(deps.storage, &recipients)?;
+    Ok(Response::default())
+}
+
+pub fn handle_distribute_fees(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    command: DistributeFees,
+) -> StdResult<Response> {
+    assert_role(deps.storage, &info.sender, Role::Treasury)?;
+
+    let currency = command.currency.unwrap_or_default();
+    let payouts = distribute_fees(deps.storage, &env, &currency)?;
+
+    let mut response = Response::default();
+    let mut distributions = Vec::new();
+    for (recipient, amount) in payouts {
+        response = response.add_message(create_send_msg(
+            deps.storage,
+            &currency,
+            &recipient.to_string(),
+            &amount,
+        )?);
+        distributions.push(FeeDistribution { recipient, amount });
+    }
+
+    Ok(
+        response.set_data(ExecuteResponse::DistributeFees(DistributeFeesResponse {
+            status: Success,
+            distributions,
+        })),
+    )
+}
+
+pub fn handle_add_ticker(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    command: AddTicker,
+) -> StdResult<Response> {
+    assert_role(deps.storage, &info.sender, Role::TickerManager)?;
+
+    add_ticker(deps.storage, &env, &command.ticker)?;
+    Ok(Response::default())
+}
+
+pub fn handle_remove_ticker(
+    deps: DepsMut,
+    info: MessageInfo,
+    command: RemoveTicker,
+) -> StdResult<Response> {
+    assert_role(deps.storage, &info.sender, Role::TickerManager)?;
+
+    remove_ticker(deps.storage, &command.ticker)?;
+    Ok(Response::default())
+}
+
+pub fn handle_pause_ticker(
+    deps: DepsMut,
+    info: MessageInfo,
+    command: PauseTicker,
+) -> StdResult<Response> {
+    assert_role(deps.storage, &info.sender, Role::TickerManager)?;
+
+    set_ticker_paused(deps.storage, &command.ticker, command.paused)?;
+    Ok(Response::default())
+}
+
+pub fn handle_set_ticker_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    command: SetTickerConfig,
+) -> StdResult<Response> {
+    assert_role(deps.storage, &info.sender, Role::TickerManager)?;
+
+    set_ticker_config(
+        deps.storage,
+        &command.ticker,
+        command.interval,
+        command.price_feed,
+    )?;
+    Ok(Response::default())
+}
+
+pub fn handle_set_ticker_outcomes(
+    deps: DepsMut,
+    info: MessageInfo,
+    command: SetTickerOutcomes,
+) -> StdResult<Response> {
+    assert_role(deps.storage, &info.sender, Role::TickerManager)?;
+
+    set_ticker_outcome_bands(deps.storage, &command.ticker, command.outcome_bands)?;
+    Ok(Response::default())
+}
+
+pub fn handle_create_contest(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    command: CreateContest,
+) -> StdResult<Response> {
+    let state = State::singleton_load(deps.storage)?;
+    state.assert_owner(&info.sender)?;
+    assert_ticker_enabled(deps.storage, &command.ticker)?;
+
+    let contest_info = create_custom_contest(
+        &mut deps,
+        &env,
+        &command.ticker,
+        command.time_of_close,
+        command.time_of_resolve,
+        command.options,
+        command.currency.unwrap_or_default(),
+    )?;
+    create_new_contest_bet_summary(deps.storage, &env, &contest_info)?;
+    add_active_contest(deps.storage, &contest_info.get_id())?;
+
+    Ok(Response::default()
+        .add_attribute("action", "create_contest")
+        .add_attribute("contest_id", contest_info.get_id().to_string())
+        .add_attribute("time_of_close", command.time_of_close.to_string())
+        .add_attribute("time_of_resolve", command.time_of_resolve.to_string()))
+}
+
+pub fn handle_resolve_contest_manually(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    command: ResolveContestManually,
+) -> StdResult<Response> {
+    let state = State::singleton_load(deps.storage)?;
+    // Owner only, picking a winning outcome is not delegated to any role
+    state.assert_owner(&info.sender)?;
+
+    let ResolveContestManually {
+        contest_id,
+        close_price,
+        resolve_price,
+        reason,
+    } = command;
+    let outcome = resolve_contest_manually(
+        deps.storage,
+        &env,
+        &contest_id,
+        close_price,
+        resolve_price,
+        reason.clone(),
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("action", "resolve_contest_manually")
+        .add_attribute("contest_id", contest_id.to_string())
+        .add_attribute("outcome_id", outcome.get_id().to_string())
+        .add_attribute("reason", reason))
+}
+
+pub fn handle_void_contest(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    command: VoidContest,
+) -> StdResult<Response> {
+    let state = State::singleton_load(deps.storage)?;
+    // Owner only, like manual resolution, voiding erases the winners' payout
+    state.assert_owner(&info.sender)?;
+
+    let VoidContest { contest_id, reason } = command;
+    void_contest(deps.storage, &env, &contest_id, reason.clone())?;
+
+    Ok(Response::default()
+        .add_attribute("action", "void_contest")
+        .add_attribute("contest_id", contest_id.to_string())
+        .add_attribute("reason", reason))
+}
+
+pub fn handle_set_expiration_window(
+    deps: DepsMut,
+    info: MessageInfo,
+    command: SetExpirationWindow,
+) -> StdResult<Response> {
+    assert_role(deps.storage, &info.sender, Role::TickerManager)?;
+    let mut state = State::singleton_load(deps.storage)?;
+
+    state.set_expiration_window_seconds(command.seconds);
+    state.singleton_save(deps.storage)?;
+    Ok(Response::default()
+        .add_attribute("action", "set_expiration_window")
+        .add_attribute("seconds", command.seconds.to_string()))
+}
+
+pub fn handle_fail_safe(
+    deps: DepsMut,
+    info: MessageInfo,
+    command: FailSafe,
+) -> StdResult<Response> {
+    let mut state = State::singleton_load(deps.storage)?;
+    assert_role(deps.storage, &info.sender, Role::Pauser)?;
+
+    state.set_status(command.status);
+    state.singleton_save(deps.storage)?;
+    Ok(Response::default())
+}
+
+pub fn handle_propose_new_owner(
+    deps: DepsMut,
+    info: MessageInfo,
+    command: ProposeNewOwner,
+) -> StdResult<Response> {
+    let mut state = State::singleton_load(deps.storage)?;
+    // Owner only, roles never grant control of ownership
+    state.assert_owner(&info.sender)?;
+
+    let new_owner = deps.api.addr_validate(command.new_owner.as_str())?;
+    state.set_pending_owner(Some(new_owner.clone()));
+    state.singleton_save(deps.storage)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "propose_new_owner")
+        .add_attribute("owner", state.owner().to_string())
+        .add_attribute("pending_owner", new_owner.to_string()))
+}
+
+pub fn handle_accept_ownership(
+    deps: DepsMut,
+    info: MessageInfo,
+    _command: AcceptOwnership,
+) -> StdResult<Response> {
+    let mut state = State::singleton_load(deps.storage)?;
+    state.assert_pending_owner(&info.sender)?;
+
+    let previous_owner = state.owner().to_owned();
+    state.set_owner(info.sender.clone());
+    state.set_pending_owner(None);
+    state.singleton_save(deps.storage)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "accept_ownership")
+        .add_attribute("previous_owner", previous_owner.to_string())
+        .add_attribute("owner", info.sender.to_string()))
+}
+
+pub fn handle_cancel_ownership_transfer(
+    deps: DepsMut,
+    info: MessageInfo,
+    _command: CancelOwnershipTransfer,
+) -> StdResult<Response> {
+    let mut state = State::singleton_load(deps.storage)?;
+    // Owner only, roles never grant control of ownership
+    state.assert_owner(&info.sender)?;
+
+    let pending_owner = state
+        .pending_owner()
+        .to_owned()
+        .ok_or(StateError::NoPendingOwner)?;
+    state.set_pending_owner(None);
+    state.singleton_save(deps.storage)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "cancel_ownership_transfer")
+        .add_attribute("owner", state.owner().to_string())
+        .add_attribute("cancelled_pending_owner", pending_owner.to_string()))
+}
+
+pub fn handle_grant_role(
+    deps: DepsMut,
+    info: MessageInfo,
+    command: GrantRole,
+) -> StdResult<Response> {
+    let state = State::singleton_load(deps.storage)?;
+    // Owner only, role holders cannot hand out or take away roles
+    state.assert_owner(&info.sender)?;
+
+    let address = deps.api.addr_validate(command.address.as_str())?;
+    grant_role(deps.storage, &address, command.role)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "grant_role")
+        .add_attribute("address", address.to_string())
+        .add_attribute("role", format!("{:?}", command.role)))
+}
+
+pub fn handle_revoke_role(
+    deps: DepsMut,
+    info: MessageInfo,
+    command: RevokeRole,
+) -> StdResult<Response> {
+    let state = State::singleton_load(deps.storage)?;
+    // Owner only, role holders cannot hand out or take away roles
+    state.assert_owner(&info.sender)?;
+
+    let address = deps.api.addr_validate(command.address.as_str())?;
+    revoke_role(deps.storage, &address, command.role)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "revoke_role")
+        .add_attribute("address", address.to_string())
+        .add_attribute("role", format!("{:?}", command.role)))
+}
@@ -1,21 +1,53 @@
 use cosmwasm_std::{from_binary, DepsMut, Env, MessageInfo, Response, StdResult, Uint128};
-use sp_secret_toolkit::snip20::Snip20;
 
 use crate::{
+    command_handlers::invoke_handlers::handle_bet_on_contest,
+    constants::MAX_CLAIM_ALL_CONTESTS,
     contract::invoke,
-    data::contest_info::ContestId,
+    data::{bets::UserContest, contest_info::ContestId, currency::Currency, state::ContractStatus},
+    error::{bet_error::BetError, currency_error::CurrencyError},
     msgs::{
-        execute::commands::{claim::Claim, claim_multiple::ClaimMultiple, receive::Receive},
-        invoke::invoke_msg::InvokeMsg,
+        execute::commands::{
+            bet_contest::BetContest, cancel_bet::CancelBet, claim::Claim, claim_all::ClaimAll,
+            claim_multiple::ClaimMultiple, receive::Receive, resolve_contests::ResolveContests,
+            switch_side::SwitchSide,
+        },
+        invoke::{commands::bet_contest::BetContest as InvokeBetContest, invoke_msg::InvokeMsg},
     },
     responses::execute::{
         execute_response::{ExecuteResponse, ResponseStatus::Success},
-        response_types::claim::ClaimResponse,
+        response_types::{
+            cancel_bet::CancelBetResponse,
+            claim::ClaimResponse,
+            claim_all::{ClaimAllResponse, ContestClaim},
+            resolve_contests::{ResolveContestsResponse, ResolvedContest},
+            switch_side::SwitchSideResponse,
+        },
     },
     services::{
-        bet_service::user_claims_bet, contest_bet_summary_service::finalize_contest_outcome,
-        contest_info_service::assert_contest_ready_to_be_claimed,
-        state_service::assert_snip20_address, user_info_service::advance_index,
+        bet_service::{
+            cancel_bet, get_user_bet, is_bet_cancelled, switch_bet_side, user_claims_bet,
+        },
+        contest_bet_summary_service::{
+            finalize_contest_outcome, nullify_unresolved_contest, remove_bet_from_contest_summary,
+            switch_bet_in_contest_summary, take_keeper_reward,
+        },
+        contest_info_service::{
+            assert_contest_open, assert_contest_ready_to_be_claimed, assert_outcome_is_on_contest,
+            get_contest_info,
+        },
+        currency_service::{
+            add_claimable_fees_in, assert_native_funds, create_send_msg, get_received_currency,
+        },
+        integrations::price_feed_service::PriceSource,
+        state_service::{
+            assert_bets_allowed, assert_claims_allowed, get_betting_cutoff_seconds,
+            get_cancellation_fee_percent, get_keeper_reward_percent, get_switching_fee_percent,
+        },
+        user_info_service::{
+            advance_index, get_last_claimed_index, get_unchecked_contests_for_user_with_index,
+            get_users_contest_count, set_last_claimed_index,
+        },
     },
 };
 
@@ -24,13 +56,19 @@ pub fn handle_claim(
     env: Env,
     info: MessageInfo,
     command: Claim,
+    price_source: &dyn PriceSource,
 ) -> StdResult<Response> {
     let Claim { contest_id } = command;
-    let claimable_amount = process_claim(&mut deps, &env, &info, &contest_id)?;
-    let snip20 = Snip20::singleton_load(deps.storage)?;
+    let claimable_amount = process_claim(&mut deps, &env, price_source, &info, &contest_id)?;
+    let currency = contest_id.currency().clone();
 
     Ok(Response::default()
-        .add_message(snip20.create_send_msg(&info.sender.into_string(), &claimable_amount)?)
+        .add_message(create_send_msg(
+            deps.storage,
+            &currency,
+            &info.sender.into_string(),
+            &claimable_amount,
+        )?)
         .set_data(ExecuteResponse::Claim(ClaimResponse {
             status: Success,
             amount: claimable_amount,
@@ -42,15 +80,28 @@ pub fn handle_claim_multiple(
     env: Env,
     info: MessageInfo,
     command: ClaimMultiple,
+    price_source: &dyn PriceSource,
 ) -> StdResult<Response> {
     let ClaimMultiple { mut contest_ids } = command;
 
     contest_ids.sort_by_key(|contest| *contest.time_of_close());
 
+    // The total is paid out in one transfer so every contest has to share a currency
+    let currency = match contest_ids.first() {
+        Some(contest_id) => contest_id.currency().clone(),
+        None => Default::default(),
+    };
+    if contest_ids
+        .iter()
+        .any(|contest_id| contest_id.currency().clone() != currency)
+    {
+        return Err(CurrencyError::MixedCurrencies.into());
+    }
+
     let mut total_claimable_amount = Uint128::zero();
 
     for (index, contest_id) in contest_ids.iter().enumerate() {
-        let claimable_amount = process_claim(&mut deps, &env, &info, contest_id)?;
+        let claimable_amount = process_claim(&mut deps, &env, price_source, &info, contest_id)?;
         total_claimable_amount += claimable_amount;
 
         // Check if this is the last item
@@ -59,39 +110,379 @@ pub fn handle_claim_multiple(
         }
     }
 
-    let snip20 = Snip20::singleton_load(deps.storage)?;
     // reset_unchecked_contests_for_user(deps.storage, &info.sender);
 
     Ok(Response::default()
-        .add_message(snip20.create_send_msg(&info.sender.into_string(), &total_claimable_amount)?)
+        .add_message(create_send_msg(
+            deps.storage,
+            &currency,
+            &info.sender.into_string(),
+            &total_claimable_amount,
+        )?)
         .set_data(ExecuteResponse::Claim(ClaimResponse {
             status: Success,
             amount: total_claimable_amount,
         })))
 }
 
+pub fn handle_claim_all(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    command: ClaimAll,
+    price_source: &dyn PriceSource,
+) -> StdResult<Response> {
+    let ClaimAll { max_contests } = command;
+    let status = assert_claims_allowed(deps.storage)?;
+
+    let unchecked_contests = get_unchecked_contests_for_user_with_index(
+        deps.storage,
+        &info.sender,
+        max_contests.min(MAX_CLAIM_ALL_CONTESTS),
+    )?;
+    let total_contests = get_users_contest_count(deps.storage, &info.sender)?;
+
+    let mut claims: Vec<ContestClaim> = vec![];
+    let mut first_pending_index: Option<u32> = None;
+    let mut pending = 0;
+    for (index, contest_id) in unchecked_contests.iter() {
+        match process_claim_all_contest(&mut deps, &env, price_source, &info, &status, contest_id)?
+        {
+            Some(amount) if !amount.is_zero() => claims.push(ContestClaim {
+                contest_id: contest_id.clone(),
+                currency: contest_id.currency().clone(),
+                amount,
+            }),
+            Some(_) => {}
+            None => {
+                first_pending_index.get_or_insert(*index);
+                pending += 1;
+            }
+        }
+    }
+
+    // The index only moves past contests that have nothing left to claim
+    let scanned_up_to = match unchecked_contests.last() {
+        Some((index, _)) => index + 1,
+        None => get_last_claimed_index(deps.storage, &info.sender),
+    };
+    set_last_claimed_index(
+        deps.storage,
+        &info.sender,
+        first_pending_index.unwrap_or(scanned_up_to),
+    )?;
+    let remaining = pending + total_contests.saturating_sub(scanned_up_to);
+
+    // One transfer per currency claimed in
+    let mut totals: Vec<(Currency, Uint128)> = vec![];
+    for claim in claims.iter() {
+        match totals
+            .iter_mut()
+            .find(|(currency, _)| currency == &claim.currency)
+        {
+            Some((_, total)) => *total += claim.amount,
+            None => totals.push((claim.currency.clone(), claim.amount)),
+        }
+    }
+    let mut response = Response::default()
+        .add_attribute("action", "claim_all")
+        .add_attribute("claimed", claims.len().to_string())
+        .add_attribute("remaining", remaining.to_string());
+    for (currency, total) in totals.iter() {
+        response = response.add_message(create_send_msg(
+            deps.storage,
+            currency,
+            &info.sender.to_string(),
+            total,
+        )?);
+    }
+
+    Ok(
+        response.set_data(ExecuteResponse::ClaimAll(ClaimAllResponse {
+            status: Success,
+            claims,
+            remaining,
+        })),
+    )
+}
+
+pub fn handle_resolve_contests(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    command: ResolveContests,
+    price_source: &dyn PriceSource,
+) -> StdResult<Response> {
+    let ResolveContests { contest_ids } = command;
+    let status = assert_claims_allowed(deps.storage)?;
+    let keeper_reward_percent = get_keeper_reward_percent(deps.storage)?;
+
+    let mut resolved: Vec<ResolvedContest> = vec![];
+    // Under fail safe unresolved contests are nullified when claimed instead
+    if status != ContractStatus::FailSafe {
+        for contest_id in contest_ids {
+            let contest_info =
+                match assert_contest_ready_to_be_claimed(deps.storage, &env, &contest_id) {
+                    Ok(contest_info) => contest_info,
+                    Err(_) => continue,
+                };
+            // Skips contests already resolved or still waiting on the price feed
+            let contest_bet_summary =
+                match finalize_contest_outcome(&mut deps, &env, price_source, &contest_info) {
+                    Ok((contest_bet_summary, true)) => contest_bet_summary,
+                    _ => continue,
+                };
+            let outcome = match contest_bet_summary.get_outcome() {
+                Some(outcome) => outcome.clone(),
+                None => continue,
+            };
+            let keeper_reward =
+                take_keeper_reward(deps.storage, &contest_bet_summary, &keeper_reward_percent)?;
+            resolved.push(ResolvedContest {
+                currency: contest_id.currency().clone(),
+                contest_id,
+                outcome,
+                keeper_reward,
+            });
+        }
+    }
+
+    // One transfer per currency the rewards were earned in
+    let mut totals: Vec<(Currency, Uint128)> = vec![];
+    for resolved_contest in resolved.iter() {
+        if resolved_contest.keeper_reward.is_zero() {
+            continue;
+        }
+        match totals
+            .iter_mut()
+            .find(|(currency, _)| currency == &resolved_contest.currency)
+        {
+            Some((_, total)) => *total += resolved_contest.keeper_reward,
+            None => totals.push((
+                resolved_contest.currency.clone(),
+                resolved_contest.keeper_reward,
+            )),
+        }
+    }
+    let mut response = Response::default()
+        .add_attribute("action", "resolve_contests")
+        .add_attribute("resolved", resolved.len().to_string());
+    for (currency, total) in totals.iter() {
+        response = response.add_message(create_send_msg(
+            deps.storage,
+            currency,
+            &info.sender.to_string(),
+            total,
+        )?);
+    }
+
+    Ok(
+        response.set_data(ExecuteResponse::ResolveContests(ResolveContestsResponse {
+            status: Success,
+            resolved,
+        })),
+    )
+}
+
+pub fn handle_cancel_bet(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    command: CancelBet,
+) -> StdResult<Response> {
+    let CancelBet { contest_id } = command;
+    assert_bets_allowed(deps.storage)?;
+
+    let contest_info = get_contest_info(deps.storage, &contest_id)?;
+    let betting_cutoff_seconds = get_betting_cutoff_seconds(deps.storage)?;
+    assert_contest_open(&env, &contest_info, betting_cutoff_seconds)?;
+
+    let fee_percent = get_cancellation_fee_percent(deps.storage)?;
+    let (bet, cancellation) = cancel_bet(
+        deps.storage,
+        &info.sender,
+        &contest_id,
+        &fee_percent,
+        env.block.time.seconds(),
+    )?;
+    remove_bet_from_contest_summary(
+        deps.storage,
+        &contest_id,
+        bet.get_outcome_id(),
+        bet.get_amount(),
+    )?;
+    let currency = contest_id.currency().clone();
+    add_claimable_fees_in(deps.storage, &currency, cancellation.fee())?;
+
+    let mut response = Response::default()
+        .add_attribute("action", "cancel_bet")
+        .add_attribute("contest_id", contest_id.to_string())
+        .add_attribute("refunded", cancellation.refunded().to_string())
+        .add_attribute("fee", cancellation.fee().to_string());
+    if !cancellation.refunded().is_zero() {
+        response = response.add_message(create_send_msg(
+            deps.storage,
+            &currency,
+            &info.sender.into_string(),
+            cancellation.refunded(),
+        )?);
+    }
+
+    Ok(
+        response.set_data(ExecuteResponse::CancelBet(CancelBetResponse {
+            status: Success,
+            refunded: *cancellation.refunded(),
+            fee: *cancellation.fee(),
+        })),
+    )
+}
+
+pub fn handle_switch_side(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    command: SwitchSide,
+) -> StdResult<Response> {
+    let SwitchSide {
+        contest_id,
+        outcome_id,
+    } = command;
+    assert_bets_allowed(deps.storage)?;
+
+    let contest_info = get_contest_info(deps.storage, &contest_id)?;
+    let betting_cutoff_seconds = get_betting_cutoff_seconds(deps.storage)?;
+    assert_contest_open(&env, &contest_info, betting_cutoff_seconds)?;
+    assert_outcome_is_on_contest(&contest_info, &outcome_id)?;
+
+    let fee_percent = get_switching_fee_percent(deps.storage)?;
+    let (previous_outcome_id, bet, fee) = switch_bet_side(
+        deps.storage,
+        &info.sender,
+        &contest_id,
+        &outcome_id,
+        &fee_percent,
+    )?;
+    switch_bet_in_contest_summary(
+        deps.storage,
+        &contest_id,
+        &previous_outcome_id,
+        &outcome_id,
+        &(*bet.get_amount() + fee),
+        &fee,
+    )?;
+    let currency = contest_id.currency().clone();
+    add_claimable_fees_in(deps.storage, &currency, &fee)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "switch_side")
+        .add_attribute("contest_id", contest_id.to_string())
+        .add_attribute("outcome_id", outcome_id.to_string())
+        .add_attribute("fee", fee.to_string())
+        .set_data(ExecuteResponse::SwitchSide(SwitchSideResponse {
+            status: Success,
+            outcome_id,
+            amount: *bet.get_amount(),
+            fee,
+        })))
+}
+
+pub fn handle_bet_contest(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    command: BetContest,
+) -> StdResult<Response> {
+    let (currency, amount) = assert_native_funds(deps.storage, &info.funds)?;
+
+    let BetContest {
+        ticker,
+        outcome_id,
+        contest_id,
+        roll_over,
+        refund_on_failure,
+    } = command;
+    let command = InvokeBetContest {
+        ticker,
+        outcome_id,
+        user: info.sender,
+        contest_id,
+        roll_over,
+        refund_on_failure,
+    };
+
+    handle_bet_on_contest(deps, env, command, amount, currency)
+}
+
 pub fn handle_receive(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     command: Receive,
 ) -> StdResult<Response> {
-    assert_snip20_address(deps.storage, &info.sender)?;
+    let currency = get_received_currency(deps.storage, &info.sender)?;
 
     let msg: InvokeMsg = from_binary(&command.msg)?;
 
-    invoke(deps, env, info, msg, command.amount)
+    invoke(deps, env, info, msg, command.amount, currency)
 }
 
 fn process_claim(
     deps: &mut DepsMut,
     env: &Env,
+    price_source: &dyn PriceSource,
     info: &MessageInfo,
     contest_id: &ContestId,
 ) -> StdResult<Uint128> {
-    let contest_info = assert_contest_ready_to_be_claimed(deps.storage, env, contest_id)?;
+    let status = assert_claims_allowed(deps.storage)?;
 
-    let (contest_bet_summary, _was_finalized) = finalize_contest_outcome(deps, env, &contest_info)?;
+    let contest_bet_summary = if status == ContractStatus::FailSafe {
+        // Closed unresolved contests are voided so every bet on them is refunded at face value
+        nullify_unresolved_contest(deps.storage, env, contest_id)?
+    } else {
+        let contest_info = assert_contest_ready_to_be_claimed(deps.storage, env, contest_id)?;
+        let (contest_bet_summary, _was_finalized) =
+            finalize_contest_outcome(deps, env, price_source, &contest_info)?;
+        contest_bet_summary
+    };
     let claimable_amount = user_claims_bet(deps.storage, &info.sender, &contest_bet_summary)?;
     Ok(claimable_amount)
 }
+
+// Claims a single contest for ClaimAll. Returns none while the contest is waiting on a result,
+// otherwise the amount paid out, which is zero for lost, cancelled and already paid bets.
+fn process_claim_all_contest(
+    deps: &mut DepsMut,
+    env: &Env,
+    price_source: &dyn PriceSource,
+    info: &MessageInfo,
+    status: &ContractStatus,
+    contest_id: &ContestId,
+) -> StdResult<Option<Uint128>> {
+    let user_contest = UserContest::new(info.sender.clone(), contest_id.clone());
+    match get_user_bet(deps.storage, user_contest.clone()) {
+        Ok(bet) if !bet.has_been_paid() && !is_bet_cancelled(deps.storage, &user_contest) => {}
+        _ => return Ok(Some(Uint128::zero())),
+    }
+
+    let contest_bet_summary = if *status == ContractStatus::FailSafe {
+        match nullify_unresolved_contest(deps.storage, env, contest_id) {
+            Ok(contest_bet_summary) => contest_bet_summary,
+            Err(_) => return Ok(None),
+        }
+    } else {
+        let contest_info = match assert_contest_ready_to_be_claimed(deps.storage, env, contest_id) {
+            Ok(contest_info) => contest_info,
+            Err(_) => return Ok(None),
+        };
+        match finalize_contest_outcome(deps, env, price_source, &contest_info) {
+            Ok((contest_bet_summary, _was_finalized)) => contest_bet_summary,
+            Err(_) => return Ok(None),
+        }
+    };
+
+    match user_claims_bet(deps.storage, &info.sender, &contest_bet_summary) {
+        Ok(amount) => Ok(Some(amount)),
+        Err(BetError::CannotClaimOnLostContest) => Ok(Some(Uint128::zero())),
+        Err(error) => Err(error.into()),
+    }
+}
@@ -1,66 +1,228 @@
-use cosmwasm_std::{DepsMut, Env, Response, StdResult, Uint128};
+use cosmwasm_std::{DepsMut, Env, Response, StdError, StdResult, Uint128};
 
 use crate::{
-    data::contest_info::ContestId, msgs::invoke::commands::bet_contest::BetContest, responses::execute::{
-        execute_response::{ExecuteResponse, ResponseStatus::Success},
-        response_types::bet::BetResonse,
-    }, services::{
-        bet_service::place_or_update_bet,
-        contest_bet_summary_service::{add_bet_to_contest_summary, create_new_contest_bet_summary},
+    data::{contest_info::ContestId, currency::Currency},
+    error::{bet_error::BetError, contest_info_error::ContestInfoError},
+    msgs::invoke::commands::bet_contest::BetContest,
+    responses::execute::{
+        execute_response::{
+            ExecuteResponse,
+            ResponseStatus::{Failure, Success},
+        },
+        response_types::bet::{BetErrorCode, BetResonse},
+    },
+    services::{
+        bet_service::{add_open_position, assert_bet_within_limits, place_or_update_bet},
+        contest_bet_summary_service::{
+            add_bet_to_contest_summary, assert_contest_has_no_outcome,
+            create_new_contest_bet_summary,
+        },
         contest_info_service::{
-            assert_outcome_is_on_contest, assert_ticker_valid, create_new_contest, create_new_contest_info, get_contest_info, get_current_close
+            assert_contest_open, assert_outcome_is_on_contest, create_new_contest,
+            create_new_contest_info, get_betting_close, get_contest_info,
         },
         contests_service::add_active_contest,
-        state_service::assert_amount_is_greater_than_minimum_bet,
+        currency_service::{
+            assert_amount_is_above_minimum_bet_in, assert_contest_currency, create_send_msg,
+        },
+        state_service::{assert_bets_allowed, get_betting_cutoff_seconds},
+        ticker_service::assert_ticker_enabled,
         user_info_service::add_contest_to_user,
-    }
+    },
 };
 
+// A failed bet, with the code reported to the bettor when the bet can be refunded.
+// Bets are only rejected with a code before anything is written.
+struct BetRejection {
+    code: Option<BetErrorCode>,
+    error: StdError,
+}
+
+impl BetRejection {
+    fn new(code: BetErrorCode, error: impl Into<StdError>) -> Self {
+        BetRejection {
+            code: Some(code),
+            error: error.into(),
+        }
+    }
+
+    fn internal(error: impl Into<StdError>) -> Self {
+        BetRejection {
+            code: None,
+            error: error.into(),
+        }
+    }
+}
+
 pub fn handle_bet_on_contest(
     mut deps: DepsMut,
     env: Env,
     command: BetContest,
     amount_bet: Uint128,
+    currency: Currency,
 ) -> StdResult<Response> {
+    let refund_on_failure = command.refund_on_failure.unwrap_or(false);
+    let user = command.user.clone();
+
+    match place_bet(deps.branch(), &env, command, &amount_bet, &currency) {
+        Ok(()) => Ok(
+            Response::default().set_data(ExecuteResponse::Bet(BetResonse {
+                status: Success,
+                error_code: None,
+            })),
+        ),
+        Err(BetRejection {
+            code: Some(error_code),
+            ..
+        }) if refund_on_failure => {
+            // Accept the tokens and send them straight back instead of reverting the send
+            Ok(Response::default()
+                .add_message(create_send_msg(
+                    deps.storage,
+                    &currency,
+                    &user.into_string(),
+                    &amount_bet,
+                )?)
+                .add_attribute("action", "refund_bet")
+                .set_data(ExecuteResponse::Bet(BetResonse {
+                    status: Failure,
+                    error_code: Some(error_code),
+                })))
+        }
+        Err(rejection) => Err(rejection.error),
+    }
+}
+
+fn place_bet(
+    mut deps: DepsMut,
+    env: &Env,
+    command: BetContest,
+    amount_bet: &Uint128,
+    currency: &Currency,
+) -> Result<(), BetRejection> {
     let BetContest {
         ticker,
         outcome_id,
         user,
+        contest_id,
+        roll_over,
         ..
     } = command;
 
-    // Load state and assert minimum bet
-    assert_amount_is_greater_than_minimum_bet(deps.storage, &amount_bet)?;
-
-    // Generate current close time
-    let current_close = get_current_close(deps.storage, &env);
-    // Generate ContestId from ticker and close time
-    let contest_id = ContestId::new(ticker.clone(), current_close);
-    // Attempt to load contest info
-    let contest_info_result = get_contest_info(deps.storage, &contest_id);
-
-    // Handle the case where the contest does not exist
-    let contest_info = match contest_info_result {
-        Ok(info) => info,
-        Err(_e) => {
-            assert_ticker_valid(&ticker)?;
-            // Initialize new ContestInfo here if needed
-            let info = create_new_contest_info(deps.storage, &ticker, &current_close);
-            create_new_contest(&mut deps, &info)?;
-            create_new_contest_bet_summary(deps.storage, &info)?;
-            add_active_contest(deps.storage, &contest_id)?;
-
-            info
+    assert_bets_allowed(deps.storage)
+        .map_err(|e| BetRejection::new(BetErrorCode::BetsNotAllowed, e))?;
+
+    // Each token has its own minimum bet
+    assert_amount_is_above_minimum_bet_in(deps.storage, currency, amount_bet)
+        .map_err(|e| BetRejection::new(BetErrorCode::BetBelowMinimum, e))?;
+    // Paused or removed tickers stop taking bets, existing contests remain claimable
+    assert_ticker_enabled(deps.storage, &ticker)
+        .map_err(|e| BetRejection::new(BetErrorCode::TickerUnavailable, e))?;
+
+    let betting_cutoff_seconds =
+        get_betting_cutoff_seconds(deps.storage).map_err(BetRejection::internal)?;
+    let (contest_info, is_new_contest) = match contest_id {
+        // Custom contests already exist and stay open until their own time of close
+        Some(contest_id) => {
+            if contest_id.ticker() != &ticker {
+                return Err(BetRejection::new(
+                    BetErrorCode::ContestNotFound,
+                    ContestInfoError::ContestNotFound(contest_id),
+                ));
+            }
+            let info = get_contest_info(deps.storage, &contest_id)
+                .map_err(|e| BetRejection::new(BetErrorCode::ContestNotFound, e))?;
+            assert_contest_open(env, &info, betting_cutoff_seconds)
+                .map_err(|e| BetRejection::new(BetErrorCode::ContestClosed, e))?;
+            (info, false)
+        }
+        None => {
+            // Generate the close time of the contest still taking bets
+            let current_close = get_betting_close(
+                deps.storage,
+                env,
+                &ticker,
+                currency,
+                betting_cutoff_seconds,
+                roll_over.unwrap_or(false),
+            )
+            .map_err(|e| BetRejection::new(BetErrorCode::ContestClosed, e))?;
+            // Generate ContestId from the ticker, currency and close time
+            let contest_id = ContestId::new(ticker.clone(), current_close, currency.clone(), false);
+            // Attempt to load contest info, the contest is created once the bet is accepted
+            match get_contest_info(deps.storage, &contest_id) {
+                Ok(info) => (info, false),
+                Err(_e) => (
+                    create_new_contest_info(deps.storage, &ticker, &current_close, currency)
+                        .map_err(BetRejection::internal)?,
+                    true,
+                ),
+            }
         }
     };
+    let contest_id = contest_info.get_id();
+
+    if !is_new_contest {
+        // Contests nullified or resolved early stop taking bets even if still before close
+        assert_contest_has_no_outcome(deps.storage, &contest_id)
+            .map_err(|e| BetRejection::new(BetErrorCode::ContestClosed, e))?;
+        // Contests bet on by id only take the currency their pool is held in
+        assert_contest_currency(&contest_id, currency)
+            .map_err(|e| BetRejection::new(BetErrorCode::CurrencyMismatch, e))?;
+    }
+
+    assert_outcome_is_on_contest(&contest_info, &outcome_id)
+        .map_err(|e| BetRejection::new(BetErrorCode::OutcomeNotFound, e))?;
+
+    assert_bet_within_limits(
+        deps.storage,
+        env.block.time.seconds(),
+        &user,
+        &contest_id,
+        currency,
+        amount_bet,
+    )
+    .map_err(|e| match e {
+        BetError::UserBetLimitExceeded { .. } => {
+            BetRejection::new(BetErrorCode::UserBetLimitExceeded, e)
+        }
+        BetError::PoolLimitExceeded { .. } => BetRejection::new(BetErrorCode::PoolLimitExceeded, e),
+        BetError::ExposureLimitExceeded { .. } => {
+            BetRejection::new(BetErrorCode::ExposureLimitExceeded, e)
+        }
+        e => BetRejection::internal(e),
+    })?;
+
+    if is_new_contest {
+        create_new_contest(&mut deps, &contest_info).map_err(BetRejection::internal)?;
+        create_new_contest_bet_summary(deps.storage, env, &contest_info)
+            .map_err(BetRejection::internal)?;
+        add_active_contest(deps.storage, &contest_id).map_err(BetRejection::internal)?;
+    }
 
-    assert_outcome_is_on_contest(&contest_info, &outcome_id)?;
-    let new_bet = place_or_update_bet(deps.storage, &user, &contest_id, &outcome_id, &amount_bet)?;
+    // Rejects before writing when the user already backs another outcome
+    let new_bet = place_or_update_bet(deps.storage, &user, &contest_id, &outcome_id, amount_bet)
+        .map_err(|e| match e {
+            BetError::CannotBetOnBothSides => {
+                BetRejection::new(BetErrorCode::CannotBetOnBothSides, e)
+            }
+            BetError::BetCancelled { .. } => BetRejection::new(BetErrorCode::BetCancelled, e),
+            e => BetRejection::internal(e),
+        })?;
     if new_bet {
-        add_contest_to_user(deps.storage, &user, &contest_id)?;
+        add_contest_to_user(deps.storage, &user, &contest_id).map_err(BetRejection::internal)?;
     }
+    add_open_position(
+        deps.storage,
+        env.block.time.seconds(),
+        &user,
+        &contest_info,
+        currency,
+    )
+    .map_err(BetRejection::internal)?;
 
-    add_bet_to_contest_summary(deps.storage, &contest_id, &outcome_id, &amount_bet)?;
+    add_bet_to_contest_summary(deps.storage, &contest_id, &outcome_id, amount_bet)
+        .map_err(BetRejection::internal)?;
 
-    Ok(Response::default().set_data(ExecuteResponse::Bet(BetResonse { status: Success })))
+    Ok(())
 }
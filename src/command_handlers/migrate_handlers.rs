@@ -0,0 +1,14 @@
+use cosmwasm_std::{DepsMut, Env, Response, StdResult};
+
+use crate::{
+    constants::CONTRACT_VERSION, msgs::migrate::MigrateMsg,
+    services::migration_service::migrate_contract,
+};
+
+pub fn handle_migrate(deps: DepsMut, env: Env, _command: MigrateMsg) -> StdResult<Response> {
+    let from = migrate_contract(deps.storage, &env)?;
+
+    Ok(Response::default()
+        .add_attribute("migrated_from", from)
+        .add_attribute("migrated_to", CONTRACT_VERSION))
+}
@@ -1,4 +1,6 @@
 pub mod admin_execute_handlers;
 pub mod execute_handlers;
 pub mod invoke_handlers;
+pub mod migrate_handlers;
 pub mod query_handlers;
+pub mod reply_handlers;
@@ -2,54 +2,88 @@ use cosmwasm_std::{to_binary, Binary, Deps, Env, StdResult};
 use sp_secret_toolkit::{contract::contract::Contract, snip20::Snip20};
 
 use crate::{
-    data::{
-        bets::{Bet, UserContest},
-        contest_bet_summary::ContestBetSummary,
-        contest_info::ContestInfo,
-    },
+    constants::DEFAULT_PAGE_SIZE,
+    data::{bets::UserContest, currency::Currency, state::State},
     msgs::query::commands::{
-        get_claimable_contests::GetClaimableContests, get_contest_by_id::GetContestById,
-        get_contests_by_ids::GetContestsByIds, get_times_to_resolve::GetTimesToResolve,
-        get_user_bet::GetUserBet, get_users_last_ten_bets::GetUsersLastTenBets,
-        get_users_list_of_bets::GetUsersListOfBets, get_users_number_of_bets::GetUsersNumberOfBets,
+        get_bet_limits::GetBetLimits, get_claimable_contests::GetClaimableContests,
+        get_contest_by_id::GetContestById, get_contests::GetContests,
+        get_contests_by_ids::GetContestsByIds, get_fee_payouts::GetFeePayouts,
+        get_fee_withdrawals::GetFeeWithdrawals, get_times_to_resolve::GetTimesToResolve,
+        get_user_bet::GetUserBet, get_user_bets::GetUserBets,
+        get_users_last_ten_bets::GetUsersLastTenBets, get_users_list_of_bets::GetUsersListOfBets,
+        get_users_number_of_bets::GetUsersNumberOfBets,
     },
     responses::query::{
         query_response::QueryResponse,
         response_types::{
+            accepted_tokens::{AcceptedTokenResponse, AcceptedTokensResponse},
             bet::UserBetResponse,
-            claimable_fees::ClaimableFeesResponse,
+            bet_limits::BetLimitsResponse,
+            betting_cutoff::BettingCutoffResponse,
+            claimable_fees::{ClaimableFeesResponse, NativeClaimableFee},
             contest_data::ContestDataResponse,
             contest_data_list::ContestDataListResponse,
+            contests_page::ContestsPageResponse,
+            contract_status::ContractStatusResponse,
+            expiration_window::ExpirationWindowResponse,
+            fee_payouts::FeePayoutsResponse,
             fee_percent::FeePercentResponse,
+            fee_recipients::FeeRecipientsResponse,
+            fee_withdrawals::FeeWithdrawalsResponse,
             get_snip20::GetSnip20Response,
             minimum_bet::MinimumBetResponse,
+            native_denom::NativeDenomResponse,
+            owner::OwnerResponse,
+            pending_fee_change::PendingFeeChangeResponse,
+            roles::{RoleAssignment, RolesResponse},
             stats::StatsResponse,
+            tickers::TickersResponse,
             times_to_resolve::TimesToResolveResponse,
             total_number_of_bets::TotalNumberOfBetsResponse,
             total_number_of_contests::TotalNumberOfContestsResponse,
             total_number_of_users::TotalNumberOfUsersResponse,
             total_users_number_of_bets::TotalUsersNumberOfBetsResponse,
-            total_value::TotalValueResponse,
-            total_volume::TotalVolumeResponse,
+            total_value::{CurrencyValue, TotalValueResponse},
+            total_volume::{CurrencyVolume, TotalVolumeResponse},
+            user_bets_page::UserBetsPageResponse,
             users_bets::{UserContestBetInfo, UsersBetsResponse},
         },
     },
     services::{
         bet_service::{
-            get_total_bets, get_total_volume, get_user_bet, get_users_map_bets,
-            get_users_number_of_bets, map_to_user_contest_bet_infos,
+            get_bet_cancellation, get_currency_volumes, get_total_bets, get_total_volume,
+            get_user_bet, get_user_bets_page, get_user_contest_bets, get_users_map_bets,
+            get_users_number_of_bets, map_to_user_contest_bet_infos, UserBetFilter,
         },
         contest_bet_summary_service::{
             get_contest_bet_summaries_ignore_missing, get_contest_bet_summary,
-            update_contest_bet_summaries_with_results,
+            get_manual_resolution, update_contest_bet_summaries_with_results,
+        },
+        contest_info_service::{
+            get_contest_info, get_contest_infos_for_ids_ignore_missing, get_contest_price_feed,
         },
-        contest_info_service::{get_contest_info, get_contest_infos_for_ids_ignore_missing},
         contests_service::{
-            get_last_ten_contest_ids, get_times_to_resolve_from_contest_infos,
-            get_total_number_of_contests,
+            get_contests_page, get_last_ten_contest_ids, get_times_to_resolve_from_contest_infos,
+            get_total_number_of_contests, ContestFilter,
+        },
+        currency_service::{
+            get_accepted_tokens, get_bet_limits_in, get_contract_balance_in,
+            get_native_claimable_fees,
+        },
+        fee_service::{
+            get_fee_payouts, get_fee_recipients, get_fee_withdrawals, get_total_fee_payouts,
+            get_total_fee_withdrawals,
+        },
+        integrations::{
+            master_viewing_key_service::ViewingKeyVerifier, price_feed_service::PriceSource,
         },
-        integrations::master_viewing_key_service::viewing_keys::assert_valid_viewing_key,
-        state_service::{get_claimable_fees, get_fee_percent, get_minimum_bet, get_snip20},
+        state_service::{
+            get_all_roles, get_betting_cutoff_seconds, get_cancellation_fee_percent,
+            get_claimable_fees, get_contract_status, get_current_fee_percent,
+            get_expiration_window_seconds, get_keeper_reward_percent, get_max_fee_percent,
+            get_minimum_bet, get_pending_fee_change, get_snip20, get_switching_fee_percent,
+        },
+        ticker_service::get_tickers,
         user_info_service::{
             get_last_ten_bet_on, get_total_users, get_users_contest_bets_by_index,
         },
@@ -60,16 +94,37 @@ pub fn handle_get_contest_by_id(deps: Deps, command: GetContestById) -> StdResul
     let contest_info = get_contest_info(deps.storage, &command.contest_id)?;
     let contest_bet_summary = get_contest_bet_summary(deps.storage, &command.contest_id)?;
 
-    let response = QueryResponse::ContestData(ContestDataResponse {
+    let price_feed = get_contest_price_feed(deps.storage, &command.contest_id)?;
+    let manual_resolution = get_manual_resolution(deps.storage, &command.contest_id);
+
+    let response = QueryResponse::ContestData(Box::new(ContestDataResponse {
         contest_info,
         contest_bet_summary,
-    });
+        price_feed,
+        manual_resolution,
+    }));
     to_binary(&response)
 }
 
-pub fn handle_get_fee_percent(deps: Deps) -> StdResult<Binary> {
-    let fee_percent = get_fee_percent(deps.storage)?;
-    let response = QueryResponse::FeePercent(FeePercentResponse { fee_percent });
+pub fn handle_get_fee_percent(deps: Deps, env: Env) -> StdResult<Binary> {
+    let fee_percent = get_current_fee_percent(deps.storage, &env)?;
+    let max_fee_percent = get_max_fee_percent(deps.storage)?;
+    let cancellation_fee_percent = get_cancellation_fee_percent(deps.storage)?;
+    let switching_fee_percent = get_switching_fee_percent(deps.storage)?;
+    let keeper_reward_percent = get_keeper_reward_percent(deps.storage)?;
+    let response = QueryResponse::FeePercent(FeePercentResponse {
+        fee_percent,
+        max_fee_percent,
+        cancellation_fee_percent,
+        switching_fee_percent,
+        keeper_reward_percent,
+    });
+    return to_binary(&response);
+}
+
+pub fn handle_get_pending_fee_change(deps: Deps, env: Env) -> StdResult<Binary> {
+    let pending_fee_change = get_pending_fee_change(deps.storage, &env)?;
+    let response = QueryResponse::PendingFeeChange(PendingFeeChangeResponse { pending_fee_change });
     return to_binary(&response);
 }
 
@@ -79,9 +134,32 @@ pub fn handle_get_minimum_bet(deps: Deps) -> StdResult<Binary> {
     return to_binary(&response);
 }
 
+pub fn handle_get_betting_cutoff(deps: Deps) -> StdResult<Binary> {
+    let betting_cutoff_seconds = get_betting_cutoff_seconds(deps.storage)?;
+    let response = QueryResponse::BettingCutoff(BettingCutoffResponse {
+        betting_cutoff_seconds,
+    });
+    return to_binary(&response);
+}
+
+pub fn handle_get_expiration_window(deps: Deps) -> StdResult<Binary> {
+    let expiration_window_seconds = get_expiration_window_seconds(deps.storage)?;
+    let response = QueryResponse::ExpirationWindow(ExpirationWindowResponse {
+        expiration_window_seconds,
+    });
+    return to_binary(&response);
+}
+
 pub fn handle_get_claimable_fees(deps: Deps) -> StdResult<Binary> {
     let claimable_fees = get_claimable_fees(deps.storage)?;
-    let response = QueryResponse::ClaimableFees(ClaimableFeesResponse { claimable_fees });
+    let native_claimable_fees = get_native_claimable_fees(deps.storage)?
+        .into_iter()
+        .map(|(denom, amount)| NativeClaimableFee { denom, amount })
+        .collect();
+    let response = QueryResponse::ClaimableFees(ClaimableFeesResponse {
+        claimable_fees,
+        native_claimable_fees,
+    });
     return to_binary(&response);
 }
 
@@ -94,15 +172,27 @@ pub fn handle_get_total_value(deps: Deps, env: Env) -> StdResult<Binary> {
 
     let total_value = balance.amount;
 
-    let response = QueryResponse::TotalValue(TotalValueResponse { total_value });
+    // Every other currency that has taken bets, each token's balance is reported on its own
+    let mut currency_values = vec![];
+    for (currency, _) in get_currency_volumes(storage)? {
+        if let Some(value) = get_contract_balance_in(deps, &env, &currency)? {
+            currency_values.push(CurrencyValue { currency, value });
+        }
+    }
+
+    let response = QueryResponse::TotalValue(TotalValueResponse {
+        total_value,
+        currency_values,
+    });
     return to_binary(&response);
 }
 
 pub fn handle_get_users_number_of_bets(
     deps: Deps,
     command: GetUsersNumberOfBets,
+    viewing_key_verifier: &dyn ViewingKeyVerifier,
 ) -> StdResult<Binary> {
-    assert_valid_viewing_key(
+    viewing_key_verifier.assert_valid_viewing_key(
         deps.storage,
         &deps.querier,
         &command.user,
@@ -121,8 +211,10 @@ pub fn handle_get_users_list_of_bets(
     deps: Deps,
     env: Env,
     command: GetUsersListOfBets,
+    price_source: &dyn PriceSource,
+    viewing_key_verifier: &dyn ViewingKeyVerifier,
 ) -> StdResult<Binary> {
-    assert_valid_viewing_key(
+    viewing_key_verifier.assert_valid_viewing_key(
         deps.storage,
         &deps.querier,
         &command.user,
@@ -132,44 +224,22 @@ pub fn handle_get_users_list_of_bets(
     let contest_ids =
         get_users_contest_bets_by_index(deps.storage, &command.contest_ids, &command.user)?;
 
-    let contest_infos = get_contest_infos_for_ids_ignore_missing(deps.storage, &contest_ids);
-    let mut bets: Vec<Bet> = vec![];
-    let mut contest_bet_summaries =
-        get_contest_bet_summaries_ignore_missing(deps.storage, &contest_ids);
-    update_contest_bet_summaries_with_results(
-        deps.storage,
-        &deps.querier,
-        &env,
-        &contest_infos,
-        &mut contest_bet_summaries,
-    );
+    let collected_results =
+        get_user_contest_bets(&deps, price_source, &env, &command.user, &contest_ids);
 
-    for id in contest_ids {
-        bets.push(get_user_bet(
-            deps.storage,
-            UserContest::new(command.user.clone(), id),
-        )?);
-    }
-
-    // Ensure all vectors are the same length
-    assert_eq!(contest_infos.len(), contest_bet_summaries.len());
-    assert_eq!(contest_bet_summaries.len(), bets.len());
-
-    let collected_results: Vec<(ContestInfo, ContestBetSummary, Bet)> = contest_infos
-        .into_iter()
-        .zip(contest_bet_summaries.into_iter())
-        .zip(bets.into_iter())
-        .map(|((contest_info, contest_bet_summary), bet)| (contest_info, contest_bet_summary, bet))
-        .collect();
-
-    let contests_bets: Vec<UserContestBetInfo> = map_to_user_contest_bet_infos(collected_results);
+    let contests_bets: Vec<UserContestBetInfo> =
+        map_to_user_contest_bet_infos(deps.storage, collected_results);
 
     let response = QueryResponse::UsersBets(UsersBetsResponse { contests_bets });
     return to_binary(&response);
 }
 
-pub fn handle_user_bet(deps: Deps, command: GetUserBet) -> StdResult<Binary> {
-    assert_valid_viewing_key(
+pub fn handle_user_bet(
+    deps: Deps,
+    command: GetUserBet,
+    viewing_key_verifier: &dyn ViewingKeyVerifier,
+) -> StdResult<Binary> {
+    viewing_key_verifier.assert_valid_viewing_key(
         deps.storage,
         &deps.querier,
         &command.user,
@@ -177,8 +247,9 @@ pub fn handle_user_bet(deps: Deps, command: GetUserBet) -> StdResult<Binary> {
     )?;
 
     let user_contest = UserContest::new(command.user, command.contest_id);
+    let cancellation = get_bet_cancellation(deps.storage, &user_contest);
     let bet = get_user_bet(deps.storage, user_contest)?;
-    let response = QueryResponse::UserBet(UserBetResponse { bet });
+    let response = QueryResponse::UserBet(UserBetResponse { bet, cancellation });
     return to_binary(&response);
 }
 
@@ -186,8 +257,10 @@ pub fn handle_users_last_ten_bets(
     deps: Deps,
     env: Env,
     command: GetUsersLastTenBets,
+    price_source: &dyn PriceSource,
+    viewing_key_verifier: &dyn ViewingKeyVerifier,
 ) -> StdResult<Binary> {
-    assert_valid_viewing_key(
+    viewing_key_verifier.assert_valid_viewing_key(
         deps.storage,
         &deps.querier,
         &command.user,
@@ -196,39 +269,51 @@ pub fn handle_users_last_ten_bets(
 
     let last_10 = get_last_ten_bet_on(deps.storage, &command.user)?;
 
-    let contest_infos = get_contest_infos_for_ids_ignore_missing(deps.storage, &last_10);
-    let mut bets: Vec<Bet> = vec![];
-    let mut contest_bet_summaries =
-        get_contest_bet_summaries_ignore_missing(deps.storage, &last_10);
-    update_contest_bet_summaries_with_results(
-        deps.storage,
-        &deps.querier,
-        &env,
-        &contest_infos,
-        &mut contest_bet_summaries,
-    );
+    let collected_results =
+        get_user_contest_bets(&deps, price_source, &env, &command.user, &last_10);
 
-    for id in last_10 {
-        bets.push(get_user_bet(
-            deps.storage,
-            UserContest::new(command.user.clone(), id),
-        )?);
-    }
+    let contests_bets: Vec<UserContestBetInfo> =
+        map_to_user_contest_bet_infos(deps.storage, collected_results);
 
-    // Ensure all vectors are the same length
-    assert_eq!(contest_infos.len(), contest_bet_summaries.len());
-    assert_eq!(contest_bet_summaries.len(), bets.len());
+    let response = QueryResponse::UsersBets(UsersBetsResponse { contests_bets });
+    return to_binary(&response);
+}
 
-    let collected_results: Vec<(ContestInfo, ContestBetSummary, Bet)> = contest_infos
-        .into_iter()
-        .zip(contest_bet_summaries.into_iter())
-        .zip(bets.into_iter())
-        .map(|((contest_info, contest_bet_summary), bet)| (contest_info, contest_bet_summary, bet))
-        .collect();
+pub fn handle_get_user_bets(
+    deps: Deps,
+    env: Env,
+    command: GetUserBets,
+    price_source: &dyn PriceSource,
+    viewing_key_verifier: &dyn ViewingKeyVerifier,
+) -> StdResult<Binary> {
+    viewing_key_verifier.assert_valid_viewing_key(
+        deps.storage,
+        &deps.querier,
+        &command.user,
+        &command.viewing_key,
+    )?;
+
+    let filter = UserBetFilter {
+        ticker: command.ticker,
+        status: command.status,
+    };
+    let (collected_results, next_cursor) = get_user_bets_page(
+        &deps,
+        price_source,
+        &env,
+        &command.user,
+        &filter,
+        command.start_after,
+        command.limit.unwrap_or(DEFAULT_PAGE_SIZE),
+    )?;
 
-    let contests_bets: Vec<UserContestBetInfo> = map_to_user_contest_bet_infos(collected_results);
+    let contests_bets: Vec<UserContestBetInfo> =
+        map_to_user_contest_bet_infos(deps.storage, collected_results);
 
-    let response = QueryResponse::UsersBets(UsersBetsResponse { contests_bets });
+    let response = QueryResponse::UserBetsPage(UserBetsPageResponse {
+        contests_bets,
+        next_cursor,
+    });
     return to_binary(&response);
 }
 
@@ -240,20 +325,73 @@ pub fn handle_get_snip20(deps: Deps) -> StdResult<Binary> {
     return to_binary(&response);
 }
 
+pub fn handle_get_accepted_tokens(deps: Deps) -> StdResult<Binary> {
+    let snip20 = get_snip20(deps.storage)?;
+    // The token registered at instantiation always takes bets
+    let mut tokens = vec![AcceptedTokenResponse {
+        token: Contract::get_contract_info(&snip20),
+        currency: Currency::Snip20,
+        minimum_bet: get_minimum_bet(deps.storage)?,
+        claimable_fees: get_claimable_fees(deps.storage)?,
+        accepting_bets: true,
+        // Set at instantiation, which fails if the token rejects it
+        viewing_key_set: true,
+    }];
+    for (address, token) in get_accepted_tokens(deps.storage)? {
+        tokens.push(AcceptedTokenResponse {
+            token: Contract::get_contract_info(token.snip20()),
+            currency: Currency::Token { address },
+            minimum_bet: *token.minimum_bet(),
+            claimable_fees: *token.claimable_fees(),
+            accepting_bets: *token.accepting_bets(),
+            viewing_key_set: *token.viewing_key_set(),
+        });
+    }
+    let response = QueryResponse::AcceptedTokens(AcceptedTokensResponse { tokens });
+    return to_binary(&response);
+}
+
+pub fn handle_get_bet_limits(deps: Deps, command: GetBetLimits) -> StdResult<Binary> {
+    let bet_limits = get_bet_limits_in(deps.storage, &command.currency.unwrap_or_default())?;
+    let response = QueryResponse::BetLimits(BetLimitsResponse {
+        max_user_bet: *bet_limits.max_user_bet(),
+        max_pool: *bet_limits.max_pool(),
+        max_user_exposure: *bet_limits.max_user_exposure(),
+    });
+    return to_binary(&response);
+}
+
+pub fn handle_get_native_denom(deps: Deps) -> StdResult<Binary> {
+    let state = State::singleton_load(deps.storage)?;
+    let response = QueryResponse::NativeDenom(NativeDenomResponse {
+        denom: state.native_denom().clone(),
+        minimum_bet: *state.native_minimum_bet(),
+    });
+    return to_binary(&response);
+}
+
 pub fn handle_get_claimable_contests(
     deps: Deps,
     env: Env,
     command: GetClaimableContests,
+    price_source: &dyn PriceSource,
+    viewing_key_verifier: &dyn ViewingKeyVerifier,
 ) -> StdResult<Binary> {
     let GetClaimableContests { user, viewing_key } = command;
 
-    assert_valid_viewing_key(deps.storage, &deps.querier, &user, &viewing_key)?;
+    viewing_key_verifier.assert_valid_viewing_key(
+        deps.storage,
+        &deps.querier,
+        &user,
+        &viewing_key,
+    )?;
 
     // Filter contests, bet summaries, and bets based on the provided filters
-    let filtered_results = get_users_map_bets(deps, env, user)?;
+    let filtered_results = get_users_map_bets(deps, price_source, env, user)?;
 
     // Construct UserContestBetInfo
-    let contests_bets: Vec<UserContestBetInfo> = map_to_user_contest_bet_infos(filtered_results);
+    let contests_bets: Vec<UserContestBetInfo> =
+        map_to_user_contest_bet_infos(deps.storage, filtered_results);
 
     let response = QueryResponse::UsersBets(UsersBetsResponse { contests_bets });
 
@@ -264,6 +402,7 @@ pub fn handle_get_contests_by_ids(
     deps: Deps,
     env: Env,
     command: GetContestsByIds,
+    price_source: &dyn PriceSource,
 ) -> StdResult<Binary> {
     let contest_infos =
         get_contest_infos_for_ids_ignore_missing(deps.storage, &command.contest_ids);
@@ -273,6 +412,7 @@ pub fn handle_get_contests_by_ids(
     update_contest_bet_summaries_with_results(
         deps.storage,
         &deps.querier,
+        price_source,
         &env,
         &contest_infos,
         &mut contest_bet_summaries,
@@ -281,11 +421,15 @@ pub fn handle_get_contests_by_ids(
     let contest_infos_and_summaries: Vec<ContestDataResponse> = contest_infos
         .into_iter()
         .zip(contest_bet_summaries.into_iter())
-        .map(|(contest_info, contest_bet_summary)| ContestDataResponse {
-            contest_info,
-            contest_bet_summary,
+        .map(|(contest_info, contest_bet_summary)| {
+            Ok(ContestDataResponse {
+                price_feed: get_contest_price_feed(deps.storage, &contest_info.get_id())?,
+                manual_resolution: get_manual_resolution(deps.storage, &contest_info.get_id()),
+                contest_info,
+                contest_bet_summary,
+            })
         })
-        .collect();
+        .collect::<StdResult<Vec<ContestDataResponse>>>()?;
 
     let response = QueryResponse::ContestDataList(ContestDataListResponse {
         contests: contest_infos_and_summaries,
@@ -296,16 +440,33 @@ pub fn handle_get_contests_by_ids(
 pub fn handle_get_times_to_resolve_from_ids(
     deps: Deps,
     command: GetTimesToResolve,
+    price_source: &dyn PriceSource,
 ) -> StdResult<Binary> {
     let contest_infos =
         get_contest_infos_for_ids_ignore_missing(deps.storage, &command.contest_ids);
 
-    let times = get_times_to_resolve_from_contest_infos(&deps, contest_infos);
-    let response = QueryResponse::TimesToResolve(TimesToResolveResponse { times });
+    let price_feeds = get_times_to_resolve_from_contest_infos(&deps, price_source, contest_infos)?;
+
+    // Times across every feed, kept for clients that only use a single feed
+    let mut times: Vec<u64> = vec![];
+    for time in price_feeds
+        .iter()
+        .flat_map(|feed_times| feed_times.times.iter())
+    {
+        if !times.contains(time) {
+            times.push(*time)
+        }
+    }
+
+    let response = QueryResponse::TimesToResolve(TimesToResolveResponse { times, price_feeds });
     return to_binary(&response);
 }
 
-pub fn handle_get_last_ten_contests(deps: Deps, env: Env) -> StdResult<Binary> {
+pub fn handle_get_last_ten_contests(
+    deps: Deps,
+    env: Env,
+    price_source: &dyn PriceSource,
+) -> StdResult<Binary> {
     let contest_ids = get_last_ten_contest_ids(deps.storage);
 
     let contest_infos = get_contest_infos_for_ids_ignore_missing(deps.storage, &contest_ids);
@@ -315,6 +476,7 @@ pub fn handle_get_last_ten_contests(deps: Deps, env: Env) -> StdResult<Binary> {
     update_contest_bet_summaries_with_results(
         deps.storage,
         &deps.querier,
+        price_source,
         &env,
         &contest_infos,
         &mut contest_bet_summaries,
@@ -323,11 +485,15 @@ pub fn handle_get_last_ten_contests(deps: Deps, env: Env) -> StdResult<Binary> {
     let contest_infos_and_summaries: Vec<ContestDataResponse> = contest_infos
         .into_iter()
         .zip(contest_bet_summaries.into_iter())
-        .map(|(contest_info, contest_bet_summary)| ContestDataResponse {
-            contest_info,
-            contest_bet_summary,
+        .map(|(contest_info, contest_bet_summary)| {
+            Ok(ContestDataResponse {
+                price_feed: get_contest_price_feed(deps.storage, &contest_info.get_id())?,
+                manual_resolution: get_manual_resolution(deps.storage, &contest_info.get_id()),
+                contest_info,
+                contest_bet_summary,
+            })
         })
-        .collect();
+        .collect::<StdResult<Vec<ContestDataResponse>>>()?;
 
     let response = QueryResponse::ContestDataList(ContestDataListResponse {
         contests: contest_infos_and_summaries,
@@ -335,6 +501,47 @@ pub fn handle_get_last_ten_contests(deps: Deps, env: Env) -> StdResult<Binary> {
     return to_binary(&response);
 }
 
+pub fn handle_get_contests(
+    deps: Deps,
+    env: Env,
+    command: GetContests,
+    price_source: &dyn PriceSource,
+) -> StdResult<Binary> {
+    let filter = ContestFilter {
+        ticker: command.ticker,
+        status: command.status,
+        from_time: command.from_time,
+        to_time: command.to_time,
+    };
+    let (contests, next_cursor) = get_contests_page(
+        &deps,
+        price_source,
+        &env,
+        &filter,
+        command.start_after,
+        command.limit.unwrap_or(DEFAULT_PAGE_SIZE),
+        command.order.unwrap_or_default(),
+    )?;
+
+    let contests = contests
+        .into_iter()
+        .map(|(contest_info, contest_bet_summary)| {
+            Ok(ContestDataResponse {
+                price_feed: get_contest_price_feed(deps.storage, &contest_info.get_id())?,
+                manual_resolution: get_manual_resolution(deps.storage, &contest_info.get_id()),
+                contest_info,
+                contest_bet_summary,
+            })
+        })
+        .collect::<StdResult<Vec<ContestDataResponse>>>()?;
+
+    let response = QueryResponse::ContestsPage(ContestsPageResponse {
+        contests,
+        next_cursor,
+    });
+    return to_binary(&response);
+}
+
 pub fn handle_get_stats(deps: Deps) -> StdResult<Binary> {
     let contests = get_total_number_of_contests(deps.storage);
 
@@ -344,16 +551,77 @@ pub fn handle_get_stats(deps: Deps) -> StdResult<Binary> {
 
     let volume = get_total_volume(deps.storage);
 
+    let currency_volumes = get_currency_volumes(deps.storage)?
+        .into_iter()
+        .map(|(currency, volume)| CurrencyVolume { currency, volume })
+        .collect();
+
     let response = QueryResponse::Stats(StatsResponse {
         contests,
         bets,
         users,
         volume,
+        currency_volumes,
     });
 
     return to_binary(&response);
 }
 
+pub fn handle_get_contract_status(deps: Deps) -> StdResult<Binary> {
+    let status = get_contract_status(deps.storage)?;
+    let response = QueryResponse::ContractStatus(ContractStatusResponse { status });
+    return to_binary(&response);
+}
+
+pub fn handle_get_owner(deps: Deps) -> StdResult<Binary> {
+    let state = State::singleton_load(deps.storage)?;
+    let response = QueryResponse::Owner(OwnerResponse {
+        owner: state.owner().to_owned(),
+        pending_owner: state.pending_owner().to_owned(),
+    });
+    return to_binary(&response);
+}
+
+pub fn handle_get_roles(deps: Deps) -> StdResult<Binary> {
+    let state = State::singleton_load(deps.storage)?;
+    let assignments = get_all_roles(deps.storage)?
+        .into_iter()
+        .map(|(address, roles)| RoleAssignment { address, roles })
+        .collect();
+    let response = QueryResponse::Roles(RolesResponse {
+        owner: state.owner().to_owned(),
+        assignments,
+    });
+    return to_binary(&response);
+}
+
+pub fn handle_get_fee_recipients(deps: Deps) -> StdResult<Binary> {
+    let recipients = get_fee_recipients(deps.storage)?;
+    let response = QueryResponse::FeeRecipients(FeeRecipientsResponse { recipients });
+    return to_binary(&response);
+}
+
+pub fn handle_get_fee_payouts(deps: Deps, command: GetFeePayouts) -> StdResult<Binary> {
+    let recipient = command.recipient.as_ref();
+    let payouts = get_fee_payouts(deps.storage, recipient, command.page, command.page_size)?;
+    let total = get_total_fee_payouts(deps.storage, recipient)?;
+    let response = QueryResponse::FeePayouts(FeePayoutsResponse { payouts, total });
+    return to_binary(&response);
+}
+
+pub fn handle_get_fee_withdrawals(deps: Deps, command: GetFeeWithdrawals) -> StdResult<Binary> {
+    let withdrawals = get_fee_withdrawals(deps.storage, command.page, command.page_size)?;
+    let total = get_total_fee_withdrawals(deps.storage)?;
+    let response = QueryResponse::FeeWithdrawals(FeeWithdrawalsResponse { withdrawals, total });
+    return to_binary(&response);
+}
+
+pub fn handle_get_tickers(deps: Deps) -> StdResult<Binary> {
+    let tickers = get_tickers(deps.storage)?;
+    let response = QueryResponse::Tickers(TickersResponse { tickers });
+    return to_binary(&response);
+}
+
 pub fn handle_get_total_number_of_contests(deps: Deps) -> StdResult<Binary> {
     let total_number_of_contests = get_total_number_of_contests(deps.storage);
     let response = QueryResponse::TotalNumberOfContests(TotalNumberOfContestsResponse {
@@ -380,6 +648,13 @@ pub fn handle_get_total_users(deps: Deps) -> StdResult<Binary> {
 
 pub fn handle_get_total_volume(deps: Deps) -> StdResult<Binary> {
     let total_volume = get_total_volume(deps.storage);
-    let response = QueryResponse::TotalVolume(TotalVolumeResponse { total_volume });
+    let currency_volumes = get_currency_volumes(deps.storage)?
+        .into_iter()
+        .map(|(currency, volume)| CurrencyVolume { currency, volume })
+        .collect();
+    let response = QueryResponse::TotalVolume(TotalVolumeResponse {
+        total_volume,
+        currency_volumes,
+    });
     return to_binary(&response);
 }
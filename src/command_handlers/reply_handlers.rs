@@ -0,0 +1,13 @@
+use cosmwasm_std::{DepsMut, Response, StdResult, SubMsgResult};
+
+use crate::services::currency_service::set_viewing_key_result;
+
+pub fn handle_set_viewing_key_reply(deps: DepsMut, result: SubMsgResult) -> StdResult<Response> {
+    let viewing_key_set = result.is_ok();
+    let token = set_viewing_key_result(deps.storage, viewing_key_set)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "set_viewing_key")
+        .add_attribute("token", token)
+        .add_attribute("viewing_key_set", viewing_key_set.to_string()))
+}
@@ -1,8 +1,18 @@
 pub static FEE_PERCENTAGE: u128 = 1; // 1% fee
 pub static PERCENTAGE_BASE: u128 = 100;
-pub static TICKERS: &[&str] = &["BTC", "ETH"];
+pub const BASIS_POINTS: u128 = 10_000;
+pub const MAX_PAGE_SIZE: u32 = 100;
+pub const DEFAULT_PAGE_SIZE: u32 = 10;
+pub const MAX_CONTESTS_SCANNED: u32 = 300;
+pub const MAX_CLAIM_ALL_CONTESTS: u32 = 50;
+pub const DEFAULT_MAX_FEE_NUMERATOR: u128 = 10; // 10% fee cap
+pub const DEFAULT_MAX_FEE_DENOMINATOR: u128 = 100;
 pub const BULL: &str = "Bull";
 pub const BEAR: &str = "Bear";
 pub const SECONDS_IN_A_MINUTE: u64 = 60;
 pub const EXPIRATION_WINDOW: u64 = 86400;
+pub const STORED_TICKER_SEPARATOR: char = ':';
 pub const NULL_AND_VOID_CONTEST_RESULT: u8 = 0;
+pub const SET_VIEWING_KEY_REPLY_ID: u64 = 1;
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+pub const UNVERSIONED_CONTRACT_VERSION: &str = "2.0.0";
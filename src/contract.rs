@@ -1,27 +1,51 @@
 use crate::command_handlers::admin_execute_handlers::{
-    handle_claim_fees, handle_set_fee, handle_set_minimum_bet,
+    handle_accept_ownership, handle_add_accepted_token, handle_add_ticker,
+    handle_cancel_ownership_transfer, handle_claim_fees, handle_create_contest,
+    handle_distribute_fees, handle_fail_safe, handle_grant_role, handle_pause_ticker,
+    handle_propose_new_owner, handle_remove_accepted_token, handle_remove_ticker,
+    handle_resolve_contest_manually, handle_revoke_role, handle_schedule_fee_change,
+    handle_set_bet_limits, handle_set_betting_cutoff, handle_set_cancellation_fee,
+    handle_set_expiration_window, handle_set_fee, handle_set_fee_recipients,
+    handle_set_keeper_reward, handle_set_max_fee, handle_set_minimum_bet, handle_set_native_denom,
+    handle_set_switching_fee, handle_set_ticker_config, handle_set_ticker_outcomes,
+    handle_void_contest,
 };
 use crate::command_handlers::execute_handlers::{
-    handle_claim, handle_claim_multiple, handle_receive,
+    handle_bet_contest, handle_cancel_bet, handle_claim, handle_claim_all, handle_claim_multiple,
+    handle_receive, handle_resolve_contests, handle_switch_side,
 };
 use crate::command_handlers::invoke_handlers::handle_bet_on_contest;
+use crate::command_handlers::migrate_handlers::handle_migrate;
 use crate::command_handlers::query_handlers::{
+    handle_get_accepted_tokens, handle_get_bet_limits, handle_get_betting_cutoff,
     handle_get_claimable_contests, handle_get_claimable_fees, handle_get_contest_by_id,
-    handle_get_contests_by_ids, handle_get_fee_percent, handle_get_last_ten_contests,
-    handle_get_minimum_bet, handle_get_snip20, handle_get_stats,
-    handle_get_times_to_resolve_from_ids, handle_get_total_number_of_bets,
+    handle_get_contests, handle_get_contests_by_ids, handle_get_contract_status,
+    handle_get_expiration_window, handle_get_fee_payouts, handle_get_fee_percent,
+    handle_get_fee_recipients, handle_get_fee_withdrawals, handle_get_last_ten_contests,
+    handle_get_minimum_bet, handle_get_native_denom, handle_get_owner,
+    handle_get_pending_fee_change, handle_get_roles, handle_get_snip20, handle_get_stats,
+    handle_get_tickers, handle_get_times_to_resolve_from_ids, handle_get_total_number_of_bets,
     handle_get_total_number_of_contests, handle_get_total_users, handle_get_total_value,
-    handle_get_total_volume, handle_get_users_list_of_bets, handle_get_users_number_of_bets,
-    handle_user_bet, handle_users_last_ten_bets,
+    handle_get_total_volume, handle_get_user_bets, handle_get_users_list_of_bets,
+    handle_get_users_number_of_bets, handle_user_bet, handle_users_last_ten_bets,
 };
+use crate::command_handlers::reply_handlers::handle_set_viewing_key_reply;
+use crate::constants::SET_VIEWING_KEY_REPLY_ID;
+use crate::data::currency::Currency;
 use crate::data::state::{FeePercent, State};
 use crate::msgs::execute::execute_msg::ExecuteMsg;
 use crate::msgs::instantiate::InstantiateMsg;
 use crate::msgs::invoke::invoke_msg::InvokeMsg;
+use crate::msgs::migrate::MigrateMsg;
 use crate::msgs::query::query_msg::QueryMsg;
+use crate::services::integrations::master_viewing_key_service::MasterViewingKeyVerifier;
+use crate::services::integrations::price_feed_service::SpPriceFeed;
+use crate::services::migration_service::set_contract_version;
+use crate::services::ticker_service::add_ticker;
 
 use cosmwasm_std::{
-    entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Uint128,
+    entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdError, StdResult,
+    Uint128,
 };
 use sp_secret_toolkit::master_viewing_key::MasterViewingKey;
 use sp_secret_toolkit::price_feed::PriceFeed;
@@ -41,7 +65,9 @@ pub fn instantiate(
         Uint128::from(1u128), // Set minimum_bet to 1 SSCRT
         fee_percent,
     );
+    state.assert_fee_allowed(state.fee_percent())?;
     state.singleton_save(deps.storage)?;
+    set_contract_version(deps.storage)?;
 
     let snip_20 = Snip20::new(&mut deps, &env, &info, &msg.snip20, &msg.entropy);
     snip_20.singleton_save(deps.storage)?;
@@ -49,6 +75,10 @@ pub fn instantiate(
     MasterViewingKey::new(msg.master_viewing_key_contract).singleton_save(deps.storage)?;
     PriceFeed::new(msg.price_feed_info).singleton_save(deps.storage)?;
 
+    for ticker in msg.tickers.iter() {
+        add_ticker(deps.storage, &env, ticker)?;
+    }
+
     Ok(Response::default()
         .add_message(snip_20.create_register_receive_msg(&env)?)
         .add_message(snip_20.create_set_view_key_msg()?))
@@ -57,12 +87,73 @@ pub fn instantiate(
 #[entry_point]
 pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> StdResult<Response> {
     match msg {
-        ExecuteMsg::Claim(command) => handle_claim(deps, env, info, command),
-        ExecuteMsg::ClaimFees(_) => handle_claim_fees(deps, info),
-        ExecuteMsg::ClaimMultiple(command) => handle_claim_multiple(deps, env, info, command),
+        ExecuteMsg::Claim(command) => handle_claim(deps, env, info, command, &SpPriceFeed),
+        ExecuteMsg::ClaimFees(command) => handle_claim_fees(deps, env, info, command),
+        ExecuteMsg::ClaimMultiple(command) => {
+            handle_claim_multiple(deps, env, info, command, &SpPriceFeed)
+        }
+        ExecuteMsg::ClaimAll(command) => handle_claim_all(deps, env, info, command, &SpPriceFeed),
+        ExecuteMsg::ResolveContests(command) => {
+            handle_resolve_contests(deps, env, info, command, &SpPriceFeed)
+        }
+        ExecuteMsg::BetContest(command) => handle_bet_contest(deps, env, info, command),
+        ExecuteMsg::CancelBet(command) => handle_cancel_bet(deps, env, info, command),
+        ExecuteMsg::SwitchSide(command) => handle_switch_side(deps, env, info, command),
         ExecuteMsg::SetMinimumBet(command) => handle_set_minimum_bet(deps, info, command),
-        ExecuteMsg::SetFee(command) => handle_set_fee(deps, info, command),
+        ExecuteMsg::SetNativeDenom(command) => handle_set_native_denom(deps, info, command),
+        ExecuteMsg::SetBettingCutoff(command) => handle_set_betting_cutoff(deps, info, command),
+        ExecuteMsg::SetBetLimits(command) => handle_set_bet_limits(deps, info, command),
+        ExecuteMsg::SetFee(command) => handle_set_fee(deps, env, info, command),
+        ExecuteMsg::SetMaxFee(command) => handle_set_max_fee(deps, info, command),
+        ExecuteMsg::SetCancellationFee(command) => handle_set_cancellation_fee(deps, info, command),
+        ExecuteMsg::SetSwitchingFee(command) => handle_set_switching_fee(deps, info, command),
+        ExecuteMsg::SetKeeperReward(command) => handle_set_keeper_reward(deps, info, command),
+        ExecuteMsg::ScheduleFeeChange(command) => {
+            handle_schedule_fee_change(deps, env, info, command)
+        }
         ExecuteMsg::Receive(command) => handle_receive(deps, env, info, command),
+        ExecuteMsg::AddAcceptedToken(command) => {
+            handle_add_accepted_token(deps, env, info, command)
+        }
+        ExecuteMsg::RemoveAcceptedToken(command) => {
+            handle_remove_accepted_token(deps, info, command)
+        }
+        ExecuteMsg::AddTicker(command) => handle_add_ticker(deps, env, info, command),
+        ExecuteMsg::RemoveTicker(command) => handle_remove_ticker(deps, info, command),
+        ExecuteMsg::PauseTicker(command) => handle_pause_ticker(deps, info, command),
+        ExecuteMsg::SetTickerConfig(command) => handle_set_ticker_config(deps, info, command),
+        ExecuteMsg::SetTickerOutcomes(command) => handle_set_ticker_outcomes(deps, info, command),
+        ExecuteMsg::CreateContest(command) => handle_create_contest(deps, env, info, command),
+        ExecuteMsg::ResolveContestManually(command) => {
+            handle_resolve_contest_manually(deps, env, info, command)
+        }
+        ExecuteMsg::VoidContest(command) => handle_void_contest(deps, env, info, command),
+        ExecuteMsg::SetExpirationWindow(command) => {
+            handle_set_expiration_window(deps, info, command)
+        }
+        ExecuteMsg::FailSafe(command) => handle_fail_safe(deps, info, command),
+        ExecuteMsg::ProposeNewOwner(command) => handle_propose_new_owner(deps, info, command),
+        ExecuteMsg::AcceptOwnership(command) => handle_accept_ownership(deps, info, command),
+        ExecuteMsg::CancelOwnershipTransfer(command) => {
+            handle_cancel_ownership_transfer(deps, info, command)
+        }
+        ExecuteMsg::GrantRole(command) => handle_grant_role(deps, info, command),
+        ExecuteMsg::RevokeRole(command) => handle_revoke_role(deps, info, command),
+        ExecuteMsg::SetFeeRecipients(command) => handle_set_fee_recipients(deps, info, command),
+        ExecuteMsg::DistributeFees(command) => handle_distribute_fees(deps, env, info, command),
+    }
+}
+
+#[entry_point]
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> StdResult<Response> {
+    handle_migrate(deps, env, msg)
+}
+
+#[entry_point]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> StdResult<Response> {
+    match msg.id {
+        SET_VIEWING_KEY_REPLY_ID => handle_set_viewing_key_reply(deps, msg.result),
+        id => Err(StdError::generic_err(format!("Unknown reply id: {}", id))),
     }
 }
 
@@ -75,9 +166,12 @@ pub fn invoke(
     _info: MessageInfo,
     msg: InvokeMsg,
     amount: Uint128,
+    currency: Currency,
 ) -> StdResult<Response> {
     match msg {
-        InvokeMsg::BetContest(command) => handle_bet_on_contest(deps, env, command, amount),
+        InvokeMsg::BetContest(command) => {
+            handle_bet_on_contest(deps, env, command, amount, currency)
+        }
     }
 }
 
@@ -85,25 +179,60 @@ pub fn invoke(
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::GetContestById(command) => handle_get_contest_by_id(deps, command),
-        QueryMsg::GetContestsByIds(command) => handle_get_contests_by_ids(deps, env, command),
-        QueryMsg::GetUserBet(command) => handle_user_bet(deps, command),
-        QueryMsg::GetUsersLastTenBets(command) => handle_users_last_ten_bets(deps, env, command),
+        QueryMsg::GetContestsByIds(command) => {
+            handle_get_contests_by_ids(deps, env, command, &SpPriceFeed)
+        }
+        QueryMsg::GetContests(command) => handle_get_contests(deps, env, command, &SpPriceFeed),
+        QueryMsg::GetUserBet(command) => handle_user_bet(deps, command, &MasterViewingKeyVerifier),
+        QueryMsg::GetUserBets(command) => {
+            handle_get_user_bets(deps, env, command, &SpPriceFeed, &MasterViewingKeyVerifier)
+        }
+        QueryMsg::GetUsersLastTenBets(command) => {
+            handle_users_last_ten_bets(deps, env, command, &SpPriceFeed, &MasterViewingKeyVerifier)
+        }
         QueryMsg::GetMinBet(_) => handle_get_minimum_bet(deps),
+        QueryMsg::GetBettingCutoff(_) => handle_get_betting_cutoff(deps),
+        QueryMsg::GetExpirationWindow(_) => handle_get_expiration_window(deps),
+        QueryMsg::GetBetLimits(command) => handle_get_bet_limits(deps, command),
+        QueryMsg::GetNativeDenom(_) => handle_get_native_denom(deps),
         QueryMsg::GetTotalValue(_) => handle_get_total_value(deps, env),
         QueryMsg::GetSnip20(_) => handle_get_snip20(deps),
+        QueryMsg::GetAcceptedTokens(_) => handle_get_accepted_tokens(deps),
         QueryMsg::GetClaimableFees(_) => handle_get_claimable_fees(deps),
-        QueryMsg::GetClaimableContests(command) => {
-            handle_get_claimable_contests(deps, env, command)
+        QueryMsg::GetClaimableContests(command) => handle_get_claimable_contests(
+            deps,
+            env,
+            command,
+            &SpPriceFeed,
+            &MasterViewingKeyVerifier,
+        ),
+        QueryMsg::GetFeePercent(_) => handle_get_fee_percent(deps, env),
+        QueryMsg::GetPendingFeeChange(_) => handle_get_pending_fee_change(deps, env),
+        QueryMsg::GetTimesToResolve(command) => {
+            handle_get_times_to_resolve_from_ids(deps, command, &SpPriceFeed)
         }
-        QueryMsg::GetFeePercent(_) => handle_get_fee_percent(deps),
-        QueryMsg::GetTimesToResolve(command) => handle_get_times_to_resolve_from_ids(deps, command),
         QueryMsg::GetTotalNumberOfContests(_) => handle_get_total_number_of_contests(deps),
         QueryMsg::GetTotalNumberOfBets(_) => handle_get_total_number_of_bets(deps),
         QueryMsg::GetTotalVolume(_) => handle_get_total_volume(deps),
-        QueryMsg::GetUsersNumberOfBets(command) => handle_get_users_number_of_bets(deps, command),
-        QueryMsg::GetUsersListOfBets(command) => handle_get_users_list_of_bets(deps, env, command),
-        QueryMsg::GetLastTenContests(_) => handle_get_last_ten_contests(deps, env),
+        QueryMsg::GetUsersNumberOfBets(command) => {
+            handle_get_users_number_of_bets(deps, command, &MasterViewingKeyVerifier)
+        }
+        QueryMsg::GetUsersListOfBets(command) => handle_get_users_list_of_bets(
+            deps,
+            env,
+            command,
+            &SpPriceFeed,
+            &MasterViewingKeyVerifier,
+        ),
+        QueryMsg::GetLastTenContests(_) => handle_get_last_ten_contests(deps, env, &SpPriceFeed),
         QueryMsg::GetTotalUsers(_) => handle_get_total_users(deps),
         QueryMsg::GetStats(_) => handle_get_stats(deps),
+        QueryMsg::GetTickers(_) => handle_get_tickers(deps),
+        QueryMsg::GetContractStatus(_) => handle_get_contract_status(deps),
+        QueryMsg::GetOwner(_) => handle_get_owner(deps),
+        QueryMsg::GetRoles(_) => handle_get_roles(deps),
+        QueryMsg::GetFeeRecipients(_) => handle_get_fee_recipients(deps),
+        QueryMsg::GetFeePayouts(command) => handle_get_fee_payouts(deps, command),
+        QueryMsg::GetFeeWithdrawals(command) => handle_get_fee_withdrawals(deps, command),
     }
 }
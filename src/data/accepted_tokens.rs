@@ -0,0 +1,38 @@
+use cosmwasm_std::Uint128;
+use getset::{Getters, Setters};
+use secret_toolkit::storage::{Item, Keymap};
+use serde::{Deserialize, Serialize};
+use sp_secret_toolkit::snip20::Snip20;
+
+use super::state::BetLimits;
+
+// SNIP-20 tokens accepted besides the one registered at instantiation, keyed by contract address
+pub static ACCEPTED_TOKENS: Keymap<String, AcceptedToken> = Keymap::new(b"accepted_tokens");
+// The token whose SetViewingKey reply is outstanding
+pub static PENDING_VIEWING_KEY_TOKEN: Item<String> = Item::new(b"pending_viewing_key_token");
+
+#[derive(Getters, Setters, Serialize, Deserialize, Clone)]
+#[getset(get = "pub", set = "pub")]
+pub struct AcceptedToken {
+    snip20: Snip20,
+    minimum_bet: Uint128,
+    bet_limits: BetLimits,
+    claimable_fees: Uint128,
+    // Removed tokens stop taking bets, contests already held in them still pay out
+    accepting_bets: bool,
+    // Set from the reply to SetViewingKey, the contract cannot read its balance without it
+    viewing_key_set: bool,
+}
+
+impl AcceptedToken {
+    pub fn new(snip20: Snip20, minimum_bet: Uint128) -> Self {
+        AcceptedToken {
+            snip20,
+            minimum_bet,
+            bet_limits: BetLimits::default(),
+            claimable_fees: Uint128::zero(),
+            accepting_bets: true,
+            viewing_key_set: false,
+        }
+    }
+}
@@ -1,15 +1,30 @@
 use std::fmt;
 
 use cosmwasm_std::{Addr, Uint128};
+use getset::{Getters, Setters};
 use schemars::JsonSchema;
-use secret_toolkit::storage::Item;
+use secret_toolkit::storage::{Item, Keymap};
 use serde::{Deserialize, Serialize};
 use sp_secret_toolkit::macros::{identifiable::Identifiable, keymap::KeymapStorage};
 
-use super::contest_info::{ContestId, ContestInfo};
+use super::{
+    contest_info::{ContestId, ContestInfo},
+    currency::Currency,
+};
 
+// Volume of contests held in the SNIP-20 token
 pub static TOTAL_VOLUME: Item<Uint128> = Item::new(b"TOTAL_VOLUME");
+// Volume of contests held in accepted tokens and native denoms, kept apart per currency
+pub static CURRENCY_VOLUMES: Keymap<Currency, Uint128> = Keymap::new(b"currency_volumes");
 pub static TOTAL_BETS: Item<u64> = Item::new(b"TOTAL_BETS");
+// Cancelled bets keep their Bet so they stay in the user's history
+pub static CANCELLED_BETS: Keymap<UserContest, BetCancellation> = Keymap::new(b"cancelled_bets");
+// Contests a user has bet on that count towards their open exposure
+static OPEN_POSITIONS: Keymap<ContestId, OpenPosition> = Keymap::new(b"open_positions");
+
+pub fn get_users_open_positions(user: &Addr) -> Keymap<'static, ContestId, OpenPosition> {
+    OPEN_POSITIONS.add_suffix(user.as_bytes())
+}
 
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema, KeymapStorage)]
 pub struct Bet {
@@ -66,6 +81,12 @@ impl Bet {
     pub fn add_amount(&mut self, additional_amount: Uint128) {
         self.amount += additional_amount;
     }
+
+    // Moves the stake to another outcome, less any switching fee
+    pub fn switch_outcome(&mut self, outcome_id: u8, fee: Uint128) {
+        self.outcome_id = outcome_id;
+        self.amount -= fee;
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
@@ -100,3 +121,55 @@ impl fmt::Display for UserContest {
         write!(f, "Address: {}, Contest ID: {}", self.user, self.contest_id)
     }
 }
+
+#[derive(Getters, Setters, Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[getset(get = "pub", set = "pub")]
+pub struct BetCancellation {
+    refunded: Uint128,
+    fee: Uint128,
+    cancelled_at: u64,
+}
+
+impl BetCancellation {
+    pub fn new(refunded: Uint128, fee: Uint128, cancelled_at: u64) -> Self {
+        BetCancellation {
+            refunded,
+            fee,
+            cancelled_at,
+        }
+    }
+}
+
+// Where a user's bet stands, worked out from the contest's outcome and the bet itself
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum UserBetStatus {
+    // The contest has not resolved yet
+    Pending,
+    // Resolved in the bet's favour and not yet claimed
+    Won,
+    Lost,
+    // Cancelled, or the contest was nullified and the stake is owed back
+    Refunded,
+    Claimed,
+}
+
+#[derive(Getters, Setters, Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[getset(get = "pub", set = "pub")]
+pub struct OpenPosition {
+    time_of_resolve: u64,
+    currency: Currency,
+}
+
+impl OpenPosition {
+    pub fn new(time_of_resolve: u64, currency: Currency) -> Self {
+        OpenPosition {
+            time_of_resolve,
+            currency,
+        }
+    }
+
+    pub fn is_open(&self, now: u64) -> bool {
+        now < self.time_of_resolve
+    }
+}
@@ -68,6 +68,13 @@ impl ContestBetSummary {
         Err(ContestBetSummaryError::OutcomeDNE)
     }
 
+    pub fn count_outcomes_with_stake(&self) -> usize {
+        self.options
+            .iter()
+            .filter(|option| !option.bet_allocation.is_zero())
+            .count()
+    }
+
     pub fn add_bet_to_option(
         &mut self,
         outcome_id: &u8,
@@ -81,6 +88,20 @@ impl ContestBetSummary {
         }
         Err(ContestBetSummaryError::OutcomeDNE)
     }
+
+    pub fn remove_bet_from_option(
+        &mut self,
+        outcome_id: &u8,
+        amount: &Uint128,
+    ) -> Result<(), ContestBetSummaryError> {
+        for option_summary in &mut self.options {
+            if option_summary.option.get_id() == outcome_id {
+                option_summary.remove_bet(amount);
+                return Ok(());
+            }
+        }
+        Err(ContestBetSummaryError::OutcomeDNE)
+    }
 }
 
 impl Identifiable for ContestBetSummary {
@@ -112,4 +133,9 @@ impl OptionBetSummary {
         self.num_bets += 1;
         self.bet_allocation += amount
     }
+
+    pub fn remove_bet(&mut self, amount: &Uint128) {
+        self.num_bets = self.num_bets.saturating_sub(1);
+        self.bet_allocation = self.bet_allocation.saturating_sub(*amount)
+    }
 }
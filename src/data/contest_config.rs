@@ -0,0 +1,26 @@
+use cosmwasm_std::ContractInfo;
+use getset::{Getters, Setters};
+use schemars::JsonSchema;
+use secret_toolkit::storage::Keymap;
+use serde::{Deserialize, Serialize};
+
+use super::{contest_info::ContestId, outcome_band::OutcomeBand};
+
+// Settings captured when a contest is created so later ticker changes do not affect it
+pub static CONTEST_CONFIGS: Keymap<ContestId, ContestConfig> = Keymap::new(b"contest_configs");
+
+#[derive(Getters, Setters, Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[getset(get = "pub", set = "pub")]
+pub struct ContestConfig {
+    price_feed: ContractInfo,
+    outcome_bands: Option<Vec<OutcomeBand>>,
+}
+
+impl ContestConfig {
+    pub fn new(price_feed: ContractInfo, outcome_bands: Option<Vec<OutcomeBand>>) -> Self {
+        ContestConfig {
+            price_feed,
+            outcome_bands,
+        }
+    }
+}
@@ -2,30 +2,52 @@ use core::fmt;
 
 use getset::{Getters, Setters};
 use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
 use sp_secret_toolkit::macros::{identifiable::Identifiable, keymap::KeymapStorage};
 
-use crate::services::integrations::price_feed_service::pricefeed::NULL_AND_VOID_CONTEST_RESULT;
+use crate::{
+    constants::STORED_TICKER_SEPARATOR,
+    services::integrations::price_feed_service::NULL_AND_VOID_CONTEST_RESULT,
+};
 
-#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema, KeymapStorage)]
+use super::currency::Currency;
+
+// Segments of a stored ticker after the market ticker
+const CUSTOM_CONTEST_MARKER: &str = "custom";
+const TOKEN_MARKER: &str = "token";
+const NATIVE_MARKER: &str = "native";
+
+#[derive(Clone, Debug, Eq, PartialEq, JsonSchema, KeymapStorage)]
 pub struct ContestInfo {
     ticker: String,
     options: Vec<ContestOutcome>,
     time_of_close: u64,
     time_of_resolve: u64,
+    #[schemars(default)]
+    currency: Currency,
+    #[schemars(default)]
+    custom: bool,
 }
 
-#[derive(Getters, Setters, Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[derive(Getters, Setters, Clone, Debug, Eq, PartialEq, JsonSchema)]
 #[getset(get = "pub", set = "pub")]
 pub struct ContestId {
     ticker: String,
     time_of_close: u64,
+    // Each currency has its own pool for the same ticker and close
+    #[schemars(default)]
+    currency: Currency,
+    // Created with CreateContest, so it never shares a key with the ticker's interval contests
+    #[schemars(default)]
+    custom: bool,
 }
 impl ContestId {
-    pub fn new(ticker: String, time_of_close: u64) -> Self {
+    pub fn new(ticker: String, time_of_close: u64, currency: Currency, custom: bool) -> Self {
         ContestId {
             ticker,
             time_of_close,
+            currency,
+            custom,
         }
     }
 }
@@ -36,9 +58,197 @@ impl fmt::Display for ContestId {
             f,
             "Ticker: {}, Time of Close: {}",
             self.ticker, self.time_of_close
-        )
+        )?;
+        match &self.currency {
+            Currency::Snip20 => {}
+            Currency::Token { address } => write!(f, ", Token: {}", address)?,
+            Currency::Native { denom } => write!(f, ", Native: {}", denom)?,
+        }
+        if self.custom {
+            write!(f, ", Custom")?;
+        }
+        Ok(())
+    }
+}
+
+// Contest ids and infos are stored in their 2.0.0 binary layout, with anything 2.0.0 did not
+// have folded into the stored ticker so SNIP-20 interval contests keep their 2.0.0 keys.
+// Messages and responses carry it as fields, which default the same way when left out.
+fn to_stored_ticker(ticker: &str, currency: &Currency, custom: bool) -> String {
+    let mut segments = vec![ticker];
+    if custom {
+        segments.push(CUSTOM_CONTEST_MARKER);
+    }
+    match currency {
+        Currency::Snip20 => {}
+        Currency::Token { address } => segments.extend([TOKEN_MARKER, address.as_str()]),
+        Currency::Native { denom } => segments.extend([NATIVE_MARKER, denom.as_str()]),
+    }
+    segments.join(&STORED_TICKER_SEPARATOR.to_string())
+}
+
+fn from_stored_ticker<E: Error>(stored_ticker: &str) -> Result<(String, Currency, bool), E> {
+    let segments: Vec<&str> = stored_ticker.split(STORED_TICKER_SEPARATOR).collect();
+    let (custom, currency_segments) = match segments.get(1) {
+        Some(&CUSTOM_CONTEST_MARKER) => (true, &segments[2..]),
+        _ => (false, &segments[1..]),
+    };
+    let currency = match currency_segments {
+        [] => Currency::Snip20,
+        [TOKEN_MARKER, address] => Currency::Token {
+            address: address.to_string(),
+        },
+        [NATIVE_MARKER, denom] => Currency::Native {
+            denom: denom.to_string(),
+        },
+        _ => {
+            return Err(E::custom(format!(
+                "Invalid stored contest ticker: {}",
+                stored_ticker
+            )))
+        }
+    };
+    Ok((segments[0].to_owned(), currency, custom))
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredContestId {
+    ticker: String,
+    time_of_close: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ContestIdMessage {
+    ticker: String,
+    time_of_close: u64,
+    #[serde(default)]
+    currency: Currency,
+    #[serde(default)]
+    custom: bool,
+}
+
+impl Serialize for ContestId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            return ContestIdMessage {
+                ticker: self.ticker.clone(),
+                time_of_close: self.time_of_close,
+                currency: self.currency.clone(),
+                custom: self.custom,
+            }
+            .serialize(serializer);
+        }
+        StoredContestId {
+            ticker: to_stored_ticker(&self.ticker, &self.currency, self.custom),
+            time_of_close: self.time_of_close,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ContestId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let message = ContestIdMessage::deserialize(deserializer)?;
+            return Ok(ContestId::new(
+                message.ticker,
+                message.time_of_close,
+                message.currency,
+                message.custom,
+            ));
+        }
+        let stored = StoredContestId::deserialize(deserializer)?;
+        let (ticker, currency, custom) = from_stored_ticker(&stored.ticker)?;
+        Ok(ContestId::new(
+            ticker,
+            stored.time_of_close,
+            currency,
+            custom,
+        ))
     }
 }
+
+#[derive(Serialize, Deserialize)]
+struct StoredContestInfo {
+    ticker: String,
+    options: Vec<ContestOutcome>,
+    time_of_close: u64,
+    time_of_resolve: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ContestInfoMessage {
+    ticker: String,
+    options: Vec<ContestOutcome>,
+    time_of_close: u64,
+    time_of_resolve: u64,
+    #[serde(default)]
+    currency: Currency,
+    #[serde(default)]
+    custom: bool,
+}
+
+impl Serialize for ContestInfo {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            return ContestInfoMessage {
+                ticker: self.ticker.clone(),
+                options: self.options.clone(),
+                time_of_close: self.time_of_close,
+                time_of_resolve: self.time_of_resolve,
+                currency: self.currency.clone(),
+                custom: self.custom,
+            }
+            .serialize(serializer);
+        }
+        StoredContestInfo {
+            ticker: to_stored_ticker(&self.ticker, &self.currency, self.custom),
+            options: self.options.clone(),
+            time_of_close: self.time_of_close,
+            time_of_resolve: self.time_of_resolve,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ContestInfo {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let message = ContestInfoMessage::deserialize(deserializer)?;
+            return Ok(ContestInfo::new(
+                message.ticker,
+                message.time_of_close,
+                message.time_of_resolve,
+                message.options,
+                message.currency,
+                message.custom,
+            ));
+        }
+        let stored = StoredContestInfo::deserialize(deserializer)?;
+        let (ticker, currency, custom) = from_stored_ticker(&stored.ticker)?;
+        Ok(ContestInfo::new(
+            ticker,
+            stored.time_of_close,
+            stored.time_of_resolve,
+            stored.options,
+            currency,
+            custom,
+        ))
+    }
+}
+
+// Where a contest is in its lifecycle, worked out from the time and its bet summary
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ContestStatus {
+    Open,
+    Closed,
+    // Past its time of resolve with no result from the price feed yet
+    AwaitingResult,
+    Resolved,
+    Nullified,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 pub struct ContestOutcome {
     id: u8,
@@ -65,12 +275,16 @@ impl ContestInfo {
         time_of_close: u64,
         time_of_resolve: u64,
         options: Vec<ContestOutcome>,
+        currency: Currency,
+        custom: bool,
     ) -> ContestInfo {
         Self {
             ticker,
             time_of_close,
             time_of_resolve,
             options,
+            currency,
+            custom,
         }
     }
     pub fn get_id(&self) -> ContestId {
@@ -88,6 +302,12 @@ impl ContestInfo {
     pub fn get_options(&self) -> &Vec<ContestOutcome> {
         return &self.options;
     }
+    pub fn get_currency(&self) -> &Currency {
+        return &self.currency;
+    }
+    pub fn is_custom(&self) -> bool {
+        return self.custom;
+    }
 }
 
 impl Identifiable for ContestInfo {
@@ -97,6 +317,8 @@ impl Identifiable for ContestInfo {
         ContestId {
             ticker: self.ticker.clone(),
             time_of_close: self.time_of_close,
+            currency: self.currency.clone(),
+            custom: self.custom,
         }
     } // Or another type that implements Serialize + DeserializeOwned
 }
@@ -0,0 +1,37 @@
+use cosmwasm_std::Decimal;
+use getset::{Getters, Setters};
+use schemars::JsonSchema;
+use secret_toolkit::storage::Keymap;
+use serde::{Deserialize, Serialize};
+
+use super::contest_info::ContestId;
+
+// Contests whose outcome was set by the owner rather than the price feed
+pub static MANUAL_RESOLUTIONS: Keymap<ContestId, ManualResolution> =
+    Keymap::new(b"manual_resolutions");
+
+#[derive(Getters, Setters, Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[getset(get = "pub", set = "pub")]
+pub struct ManualResolution {
+    reason: String,
+    resolved_at: u64,
+    // Both none when the contest was voided
+    close_price: Option<Decimal>,
+    resolve_price: Option<Decimal>,
+}
+
+impl ManualResolution {
+    pub fn new(
+        reason: String,
+        resolved_at: u64,
+        close_price: Option<Decimal>,
+        resolve_price: Option<Decimal>,
+    ) -> Self {
+        ManualResolution {
+            reason,
+            resolved_at,
+            close_price,
+            resolve_price,
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use cosmwasm_std::{StdResult, Storage};
+use schemars::JsonSchema;
 use secret_toolkit::storage::AppendStore;
+use serde::{Deserialize, Serialize};
 
 use super::contest_info::ContestId;
 
@@ -16,3 +18,17 @@ pub fn add_contest_id(storage: &mut dyn Storage, contest_id: &ContestId) -> StdR
     let contest_store = get_all_contest_id_store();
     return contest_store.push(storage, contest_id);
 }
+
+// Order contests are listed in, by when they were created
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+impl Default for SortOrder {
+    fn default() -> Self {
+        SortOrder::Descending
+    }
+}
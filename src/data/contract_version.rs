@@ -0,0 +1,4 @@
+use secret_toolkit::storage::Item;
+
+// Version of the code that last wrote the storage layout, missing on 2.0.0 deployments
+pub static STORED_CONTRACT_VERSION: Item<String> = Item::new(b"contract_version");
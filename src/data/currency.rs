@@ -0,0 +1,24 @@
+use cosmwasm_std::Uint128;
+use schemars::JsonSchema;
+use secret_toolkit::storage::Keymap;
+use serde::{Deserialize, Serialize};
+
+// Fees taken on native denom contests, SNIP-20 fees stay in State.claimable_fees
+pub static NATIVE_CLAIMABLE_FEES: Keymap<String, Uint128> = Keymap::new(b"native_claimable_fees");
+
+// What a contest pool is held in, a pool never mixes currencies
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Currency {
+    // The contract's registered SNIP-20 token
+    Snip20,
+    // A SNIP-20 token added with AddAcceptedToken
+    Token { address: String },
+    Native { denom: String },
+}
+
+impl Default for Currency {
+    fn default() -> Self {
+        Currency::Snip20
+    }
+}
@@ -0,0 +1,58 @@
+use cosmwasm_std::{Addr, StdResult, Storage, Uint128};
+use getset::{Getters, Setters};
+use schemars::JsonSchema;
+use secret_toolkit::storage::{AppendStore, Item};
+use serde::{Deserialize, Serialize};
+
+use super::currency::Currency;
+
+pub static FEE_RECIPIENTS: Item<Vec<FeeRecipient>> = Item::new(b"fee_recipients");
+static FEE_PAYOUT_STORE: AppendStore<FeePayout> = AppendStore::new(b"fee_payout_history");
+
+#[derive(Getters, Setters, Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[getset(get = "pub", set = "pub")]
+pub struct FeeRecipient {
+    address: Addr,
+    share_bps: u16,
+}
+
+impl FeeRecipient {
+    pub fn new(address: Addr, share_bps: u16) -> Self {
+        FeeRecipient { address, share_bps }
+    }
+}
+
+// A single share of a fee distribution sent to a recipient
+#[derive(Getters, Setters, Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[getset(get = "pub", set = "pub")]
+pub struct FeePayout {
+    recipient: Addr,
+    amount: Uint128,
+    time: u64,
+    currency: Currency,
+}
+
+impl FeePayout {
+    pub fn new(recipient: Addr, amount: Uint128, time: u64, currency: Currency) -> Self {
+        FeePayout {
+            recipient,
+            amount,
+            time,
+            currency,
+        }
+    }
+}
+
+pub fn get_fee_payout_store() -> AppendStore<'static, FeePayout> {
+    FEE_PAYOUT_STORE.add_suffix(b"all_payouts")
+}
+
+pub fn get_recipients_fee_payout_store(recipient: &Addr) -> AppendStore<'static, FeePayout> {
+    FEE_PAYOUT_STORE.add_suffix(recipient.as_bytes())
+}
+
+// Each payout is kept in the full history and in the recipient's own history
+pub fn add_fee_payout(storage: &mut dyn Storage, payout: &FeePayout) -> StdResult<()> {
+    get_fee_payout_store().push(storage, payout)?;
+    get_recipients_fee_payout_store(payout.recipient()).push(storage, payout)
+}
@@ -0,0 +1,38 @@
+use cosmwasm_std::{Addr, StdResult, Storage, Uint128};
+use getset::{Getters, Setters};
+use schemars::JsonSchema;
+use secret_toolkit::storage::AppendStore;
+use serde::{Deserialize, Serialize};
+
+use super::currency::Currency;
+
+static FEE_WITHDRAWAL_STORE: AppendStore<FeeWithdrawal> = AppendStore::new(b"fee_withdrawals");
+
+#[derive(Getters, Setters, Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[getset(get = "pub", set = "pub")]
+pub struct FeeWithdrawal {
+    amount: Uint128,
+    recipient: Addr,
+    time: u64,
+    currency: Currency,
+}
+
+impl FeeWithdrawal {
+    pub fn new(amount: Uint128, recipient: Addr, time: u64, currency: Currency) -> Self {
+        FeeWithdrawal {
+            amount,
+            recipient,
+            time,
+            currency,
+        }
+    }
+}
+
+pub fn get_fee_withdrawal_store() -> AppendStore<'static, FeeWithdrawal> {
+    FEE_WITHDRAWAL_STORE.add_suffix(b"all_withdrawals")
+}
+
+pub fn add_fee_withdrawal(storage: &mut dyn Storage, withdrawal: &FeeWithdrawal) -> StdResult<()> {
+    let withdrawal_store = get_fee_withdrawal_store();
+    withdrawal_store.push(storage, withdrawal)
+}
@@ -1,6 +1,16 @@
+pub mod accepted_tokens;
 pub mod bets;
 pub mod contest_bet_summary;
+pub mod contest_config;
 pub mod contest_info;
+pub mod contest_resolution;
 pub mod contests;
+pub mod contract_version;
+pub mod currency;
+pub mod fee_recipients;
+pub mod fee_withdrawals;
+pub mod outcome_band;
+pub mod roles;
 pub mod state;
+pub mod tickers;
 pub mod user_info;
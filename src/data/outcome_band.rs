@@ -0,0 +1,56 @@
+use cosmwasm_std::Decimal;
+use getset::{Getters, Setters};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::constants::BASIS_POINTS;
+
+use super::contest_info::ContestOutcome;
+
+// An outcome that wins when the price change from close to resolve falls inside its band.
+// Bounds are in basis points of the close price, the lower bound is inclusive and the
+// upper bound exclusive. A missing bound is unbounded on that side.
+#[derive(Getters, Setters, Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[getset(get = "pub", set = "pub")]
+pub struct OutcomeBand {
+    id: u8,
+    name: String,
+    min_change_bps: Option<i32>,
+    max_change_bps: Option<i32>,
+}
+
+impl OutcomeBand {
+    pub fn new(
+        id: u8,
+        name: String,
+        min_change_bps: Option<i32>,
+        max_change_bps: Option<i32>,
+    ) -> Self {
+        OutcomeBand {
+            id,
+            name,
+            min_change_bps,
+            max_change_bps,
+        }
+    }
+
+    pub fn contains(&self, close_price: &Decimal, resolve_price: &Decimal) -> bool {
+        let above_min = self.min_change_bps.map_or(true, |min| {
+            resolve_price >= &price_at_change(close_price, min)
+        });
+        let below_max = self.max_change_bps.map_or(true, |max| {
+            resolve_price < &price_at_change(close_price, max)
+        });
+        above_min && below_max
+    }
+
+    pub fn to_outcome(&self) -> ContestOutcome {
+        ContestOutcome::new(self.id, self.name.clone())
+    }
+}
+
+// Bounds are validated to be no lower than -100% so the ratio is never negative
+fn price_at_change(close_price: &Decimal, change_bps: i32) -> Decimal {
+    let ratio = (BASIS_POINTS as i128 + change_bps as i128) as u128;
+    *close_price * Decimal::from_ratio(ratio, BASIS_POINTS)
+}
@@ -0,0 +1,16 @@
+use cosmwasm_std::Addr;
+use schemars::JsonSchema;
+use secret_toolkit::storage::Keymap;
+use serde::{Deserialize, Serialize};
+
+pub static ROLES: Keymap<Addr, Vec<Role>> = Keymap::new(b"roles");
+
+// The owner implicitly holds every role
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    FeeManager,
+    Treasury,
+    Pauser,
+    TickerManager,
+}
@@ -5,7 +5,10 @@ use serde::{Deserialize, Serialize};
 use cosmwasm_std::{Addr, Uint128};
 use sp_secret_toolkit::macros::singleton::SingletonStorage;
 
-use crate::error::state_error::StateError;
+use crate::{
+    constants::{DEFAULT_MAX_FEE_DENOMINATOR, DEFAULT_MAX_FEE_NUMERATOR, EXPIRATION_WINDOW},
+    error::state_error::StateError,
+};
 
 #[derive(Getters, Setters, Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 #[getset(get = "pub", set = "pub")]
@@ -21,6 +24,105 @@ impl FeePercent {
             denominator,
         }
     }
+
+    pub fn validate(&self) -> Result<(), StateError> {
+        if self.denominator == 0 {
+            return Err(StateError::ZeroFeeDenominator);
+        }
+        if self.numerator > self.denominator {
+            return Err(StateError::FeeNumeratorExceedsDenominator {
+                numerator: self.numerator,
+                denominator: self.denominator,
+            });
+        }
+        Ok(())
+    }
+
+    // The part of an amount kept as a fee, the remainder rounds in the user's favour.
+    // A zero numerator takes no fee whatever the denominator, 2.0.0 stored fees as 0/0
+    pub fn fee_on(&self, amount: &Uint128) -> Uint128 {
+        if self.numerator == 0 {
+            return Uint128::zero();
+        }
+        *amount - amount.multiply_ratio(self.denominator - self.numerator, self.denominator)
+    }
+
+    // Compares the fractions without dividing so no precision is lost
+    pub fn exceeds(&self, other: &FeePercent) -> bool {
+        self.numerator * other.denominator > other.numerator * self.denominator
+    }
+}
+
+#[derive(Getters, Setters, Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[getset(get = "pub", set = "pub")]
+pub struct PendingFeeChange {
+    fee_percent: FeePercent,
+    effective_at: u64,
+}
+
+impl PendingFeeChange {
+    pub fn new(fee_percent: FeePercent, effective_at: u64) -> Self {
+        PendingFeeChange {
+            fee_percent,
+            effective_at,
+        }
+    }
+
+    pub fn is_due(&self, now: u64) -> bool {
+        self.effective_at <= now
+    }
+}
+
+// Stake limits in the base units of one currency, none means no limit
+#[derive(
+    Getters, Setters, Serialize, Deserialize, Clone, Debug, Default, Eq, PartialEq, JsonSchema,
+)]
+#[getset(get = "pub", set = "pub")]
+pub struct BetLimits {
+    // Largest total stake a user can hold on one contest
+    max_user_bet: Option<Uint128>,
+    // Largest total pool a contest can reach
+    max_pool: Option<Uint128>,
+    // Largest total stake a user can hold across contests that have not resolved
+    max_user_exposure: Option<Uint128>,
+}
+
+impl BetLimits {
+    pub fn new(
+        max_user_bet: Option<Uint128>,
+        max_pool: Option<Uint128>,
+        max_user_exposure: Option<Uint128>,
+    ) -> Self {
+        BetLimits {
+            max_user_bet,
+            max_pool,
+            max_user_exposure,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ContractStatus {
+    Normal,
+    BetsPaused,
+    ClaimsPaused,
+    Paused,
+    // Betting stops and bets on unresolved contests are refunded at face value
+    FailSafe,
+}
+
+impl ContractStatus {
+    pub fn bets_allowed(&self) -> bool {
+        matches!(self, ContractStatus::Normal | ContractStatus::ClaimsPaused)
+    }
+
+    pub fn claims_allowed(&self) -> bool {
+        matches!(
+            self,
+            ContractStatus::Normal | ContractStatus::BetsPaused | ContractStatus::FailSafe
+        )
+    }
 }
 
 #[derive(
@@ -42,6 +144,30 @@ pub struct State {
     minimum_bet: Uint128,
     fee_percent: FeePercent,
     claimable_fees: Uint128,
+    status: ContractStatus,
+    // Set while an ownership transfer is waiting to be accepted
+    pending_owner: Option<Addr>,
+    max_fee_percent: FeePercent,
+    // Replaces fee_percent for contests created once it is due
+    pending_fee_change: Option<PendingFeeChange>,
+    // Bets are not accepted this many seconds before a contest closes
+    betting_cutoff_seconds: u64,
+    // Kept from a bet's stake when it is cancelled before close
+    cancellation_fee_percent: FeePercent,
+    // Kept from a bet's stake when it is moved to another outcome
+    switching_fee_percent: FeePercent,
+    // Native bets are accepted in this denom, none means SNIP-20 only
+    native_denom: Option<String>,
+    // Smallest native bet, kept apart from the SNIP-20 minimum as the two are different tokens
+    native_minimum_bet: Uint128,
+    bet_limits: BetLimits,
+    // Limits on native bets, kept apart from the SNIP-20 limits for the same reason
+    native_bet_limits: BetLimits,
+    // Share of a contest's fee paid to whoever resolves it
+    keeper_reward_percent: FeePercent,
+    // How long a contest waits on the price feed before it is nullified, counted from close
+    // for interval contests and from resolve for custom contests
+    expiration_window_seconds: u64,
 }
 
 impl State {
@@ -53,6 +179,44 @@ impl State {
             minimum_bet: amount,
             fee_percent,
             claimable_fees: Uint128::zero(),
+            status: ContractStatus::Normal,
+            pending_owner: None,
+            max_fee_percent: FeePercent::new(
+                DEFAULT_MAX_FEE_NUMERATOR,
+                DEFAULT_MAX_FEE_DENOMINATOR,
+            ),
+            pending_fee_change: None,
+            betting_cutoff_seconds: 0,
+            cancellation_fee_percent: FeePercent::new(0, 100),
+            switching_fee_percent: FeePercent::new(0, 100),
+            native_denom: None,
+            native_minimum_bet: Uint128::zero(),
+            bet_limits: BetLimits::default(),
+            native_bet_limits: BetLimits::default(),
+            keeper_reward_percent: FeePercent::new(0, 100),
+            expiration_window_seconds: EXPIRATION_WINDOW,
+        }
+    }
+
+    // The fee new contests are created with at the given time
+    pub fn current_fee_percent(&self, now: u64) -> &FeePercent {
+        match self.pending_fee_change() {
+            Some(change) if change.is_due(now) => change.fee_percent(),
+            _ => self.fee_percent(),
+        }
+    }
+
+    // Moves a due fee change into fee_percent, returns true if one was applied
+    pub fn apply_pending_fee_change(&mut self, now: u64) -> bool {
+        match self.pending_fee_change.take() {
+            Some(change) if change.is_due(now) => {
+                self.fee_percent = change.fee_percent;
+                true
+            }
+            pending => {
+                self.pending_fee_change = pending;
+                false
+            }
         }
     }
 
@@ -67,4 +231,26 @@ impl State {
             })
         }
     }
+
+    pub fn assert_fee_allowed(&self, fee_percent: &FeePercent) -> Result<(), StateError> {
+        fee_percent.validate()?;
+        if fee_percent.exceeds(self.max_fee_percent()) {
+            return Err(StateError::FeeAboveMaximum {
+                fee: fee_percent.clone(),
+                maximum: self.max_fee_percent().clone(),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn assert_pending_owner(&self, caller: &Addr) -> Result<(), StateError> {
+        match self.pending_owner() {
+            Some(pending_owner) if pending_owner.to_string() == caller.to_string() => Ok(()),
+            Some(pending_owner) => Err(StateError::Unauthorized {
+                expected: pending_owner.to_string(),
+                actual: caller.to_string(),
+            }),
+            None => Err(StateError::NoPendingOwner),
+        }
+    }
 }
@@ -0,0 +1,35 @@
+use cosmwasm_std::ContractInfo;
+use getset::{Getters, Setters};
+use schemars::JsonSchema;
+use secret_toolkit::storage::Keymap;
+use serde::{Deserialize, Serialize};
+
+use super::outcome_band::OutcomeBand;
+
+pub static TICKERS: Keymap<String, TickerInfo> = Keymap::new(b"tickers");
+
+#[derive(Getters, Setters, Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[getset(get = "pub", set = "pub")]
+pub struct TickerInfo {
+    ticker: String,
+    enabled: bool,
+    date_added: u64,
+    // When unset the global interval and price feed are used
+    interval: Option<u64>,
+    price_feed: Option<ContractInfo>,
+    // When unset contests are a two way Bull/Bear split
+    outcome_bands: Option<Vec<OutcomeBand>>,
+}
+
+impl TickerInfo {
+    pub fn new(ticker: String, date_added: u64) -> Self {
+        TickerInfo {
+            ticker,
+            enabled: true, // Tickers are open for betting as soon as they are added
+            date_added,
+            interval: None,
+            price_feed: None,
+            outcome_bands: None,
+        }
+    }
+}
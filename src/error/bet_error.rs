@@ -1,8 +1,9 @@
+use cosmwasm_std::Uint128;
 use thiserror::Error;
 
 use crate::data::bets::UserContest;
 
-use super::contest_bet_summary_error::ContestBetSummaryError;
+use super::{contest_bet_summary_error::ContestBetSummaryError, currency_error::CurrencyError};
 
 #[derive(Error, Debug, PartialEq)]
 pub enum BetError {
@@ -17,9 +18,27 @@ pub enum BetError {
     #[error(transparent)]
     ContestBetSummaryError(#[from] ContestBetSummaryError),
 
+    #[error(transparent)]
+    CurrencyError(#[from] CurrencyError),
+
     #[error(transparent)]
     StandardError(#[from] cosmwasm_std::StdError),
 
+    #[error("User: {} cancelled their bet on Contest: {}. Display Text: Failure to place bet. Wallet has cancelled its bet on this contest.", .user_contest.get_address_as_str(), .user_contest.get_contest_id())]
+    BetCancelled { user_contest: UserContest },
+
+    #[error("Already backing outcome: {0}. Display Text: Failure to switch sides. Wallet is already on this side of the contest.")]
+    AlreadyOnOutcome(u8),
+
+    #[error("Stake on contest: {attempted} is above the limit: {limit}. Display Text: Failure to place bet. Bet is above the maximum allowed per wallet on this contest.")]
+    UserBetLimitExceeded { limit: Uint128, attempted: Uint128 },
+
+    #[error("Contest pool: {attempted} is above the limit: {limit}. Display Text: Failure to place bet. This contest has reached its maximum pool.")]
+    PoolLimitExceeded { limit: Uint128, attempted: Uint128 },
+
+    #[error("Open exposure: {attempted} is above the limit: {limit}. Display Text: Failure to place bet. Wallet has reached its maximum stake across open contests.")]
+    ExposureLimitExceeded { limit: Uint128, attempted: Uint128 },
+
     #[error("409: Cannot bet on both sides of a contest. Display Text: Failure to place bet. Current wallet can only be tied to one team.")]
     CannotBetOnBothSides,
 }
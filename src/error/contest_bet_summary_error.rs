@@ -12,6 +12,12 @@ pub enum ContestBetSummaryError {
     #[error("Outcome has already been set, and connot be reset")]
     CannotResetOutcome,
 
+    #[error("Contest with ID: {0} already has an outcome. Display Text: Failure to place bet. Contest has already been settled.")]
+    OutcomeAlreadySet(ContestId),
+
+    #[error("A reason must be given when setting a contest's outcome manually. Display Text: Failure to resolve contest. A reason is required.")]
+    MissingReason,
+
     #[error("Outcome Does Not Exist. Display Text: Failure to place bet. Cannot place bet on a side that does not exist.")]
     OutcomeDNE,
 
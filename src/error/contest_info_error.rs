@@ -3,6 +3,8 @@ use thiserror::Error;
 
 use crate::data::contest_info::{ContestId, ContestInfo};
 
+use super::ticker_error::TickerError;
+
 #[derive(Error, Debug, PartialEq)]
 pub enum ContestInfoError {
     #[error("A contest with id: {0} already exists and cannot be reinitialized. Display Text: A contest with id: {0} already exists and cannot be reinitialized.")]
@@ -21,6 +23,19 @@ pub enum ContestInfoError {
         current_time: u64,
     },
 
+    #[error("Time of close for contest with id: {contest_id} has yet to pass. Time of close: {time_of_close}, Current time: {current_time}. Display Text: Failure to void contest. Contest is still open for betting.")]
+    TimeOfCloseHasYetToPass {
+        contest_id: ContestId,
+        time_of_close: u64,
+        current_time: u64,
+    },
+
+    #[error("Time of close: {time_of_close} is not after the current time: {current_time}. Display Text: Failure to create contest. Contest must close in the future.")]
+    TimeOfCloseNotInFuture { time_of_close: u64, current_time: u64 },
+
+    #[error("Time of resolve: {time_of_resolve} is not after time of close: {time_of_close}. Display Text: Failure to create contest. Contest must resolve after it closes.")]
+    TimeOfResolveNotAfterClose { time_of_close: u64, time_of_resolve: u64 },
+
     #[error("412: Precondition Failed. Invalid Outcome ID found in contest with ID: {contest_id}")]
     InvalidOutcomeId { contest_id: <ContestInfo as Identifiable>::ID },
 
@@ -32,6 +47,9 @@ pub enum ContestInfoError {
     )]
     ContestNotFound(ContestId),
 
+    #[error(transparent)]
+    TickerError(#[from] TickerError),
+
     #[error("Outcome with ID: {outcome_id}, was not found on Contest with ID: {contest_id}.")]
     OutcomeNotFound { contest_id: <ContestInfo as Identifiable>::ID, outcome_id: u8 },
 
@@ -41,9 +59,6 @@ pub enum ContestInfoError {
     #[error("Outcome Does Not Exist. Display Text: Failure to place bet. Cannot place bet on a side that does not exist.")]
     OutcomeDNE,
 
-    #[error("Ticker Does Not Exist. Display Text: Failure to place bet. Cannot place bet on tickers that does not exist.")]
-    InvalidTicker,
-
 }
 
 impl From<ContestInfoError> for cosmwasm_std::StdError {
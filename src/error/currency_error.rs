@@ -0,0 +1,44 @@
+use thiserror::Error;
+
+use crate::data::{contest_info::ContestId, currency::Currency};
+
+use super::state_error::StateError;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum CurrencyError {
+    #[error("No native denom is configured. Display Text: Failure to place bet. Betting with native tokens is not enabled.")]
+    NativeBetsDisabled,
+
+    #[error("Native denom: {0} was set without a minimum bet. Display Text: Failure to set native denom. A minimum bet is required to take native bets.")]
+    NativeMinimumBetRequired(String),
+
+    #[error("Funds must be a single non zero coin of denom: {0}. Display Text: Failure to place bet. Send exactly one native token amount.")]
+    InvalidNativeFunds(String),
+
+    #[error("Contest: {contest_id} is not held in {actual:?}. Display Text: Failure to place bet. This contest takes bets in a different token.")]
+    CurrencyMismatch {
+        contest_id: ContestId,
+        actual: Currency,
+    },
+
+    #[error("Contests are held in more than one currency. Display Text: Failure to claim. Claim contests in different tokens separately.")]
+    MixedCurrencies,
+
+    #[error("Token: {0} is not accepted. Display Text: Failure to place bet. This token is not accepted for bets.")]
+    TokenNotAccepted(String),
+
+    #[error("Token: {0} is already accepted. Display Text: Failure to add token. This token is already accepted.")]
+    TokenAlreadyAccepted(String),
+
+    #[error(transparent)]
+    StateError(#[from] StateError),
+
+    #[error(transparent)]
+    StandardError(#[from] cosmwasm_std::StdError),
+}
+
+impl From<CurrencyError> for cosmwasm_std::StdError {
+    fn from(error: CurrencyError) -> Self {
+        cosmwasm_std::StdError::generic_err(format!("Currency Error: {}", error.to_string()))
+    }
+}
@@ -0,0 +1,35 @@
+use cosmwasm_std::Uint128;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum FeeError {
+    #[error("No fee recipients are configured. Display Text: Failure to distribute fees. Fee recipients have not been set.")]
+    NoFeeRecipients,
+
+    #[error("Fee recipient shares total {0} basis points. Display Text: Failure to set fee recipients. Shares must add up to 10000 basis points.")]
+    InvalidShares(u32),
+
+    #[error("Fee recipient: {0} has a zero share. Display Text: Failure to set fee recipients. Every recipient must receive a share.")]
+    ZeroShare(String),
+
+    #[error("Fee recipient: {0} is listed more than once. Display Text: Failure to set fee recipients. Recipients must be unique.")]
+    DuplicateRecipient(String),
+
+    #[error("Requested: {requested} is more than the claimable fees: {claimable}. Display Text: Failure to withdraw fees. Amount is more than the fees available to claim.")]
+    InsufficientClaimableFees {
+        requested: Uint128,
+        claimable: Uint128,
+    },
+
+    #[error("Cannot withdraw zero fees. Display Text: Failure to withdraw fees. Amount must be greater than zero.")]
+    ZeroWithdrawal,
+
+    #[error(transparent)]
+    StandardError(#[from] cosmwasm_std::StdError),
+}
+
+impl From<FeeError> for cosmwasm_std::StdError {
+    fn from(error: FeeError) -> Self {
+        cosmwasm_std::StdError::generic_err(format!("Fee Error: {}", error.to_string()))
+    }
+}
@@ -0,0 +1,30 @@
+use thiserror::Error;
+
+use super::{state_error::StateError, ticker_error::TickerError};
+
+#[derive(Error, Debug, PartialEq)]
+pub enum MigrationError {
+    #[error("Invalid contract version: {0}")]
+    InvalidVersion(String),
+
+    #[error("Cannot migrate from version {from} to older version {to}")]
+    Downgrade { from: String, to: String },
+
+    #[error("No migration path from version {from} to version {to}")]
+    NoMigrationPath { from: String, to: String },
+
+    #[error(transparent)]
+    TickerError(#[from] TickerError),
+
+    #[error(transparent)]
+    StateError(#[from] StateError),
+
+    #[error(transparent)]
+    StandardError(#[from] cosmwasm_std::StdError),
+}
+
+impl From<MigrationError> for cosmwasm_std::StdError {
+    fn from(error: MigrationError) -> Self {
+        cosmwasm_std::StdError::generic_err(format!("Migration Error: {}", error.to_string()))
+    }
+}
@@ -3,5 +3,9 @@ pub mod contest_activity_error;
 pub mod contest_bet_summary_error;
 pub mod contest_error;
 pub mod contest_info_error;
+pub mod currency_error;
+pub mod fee_error;
+pub mod migration_error;
 pub mod state_error;
+pub mod ticker_error;
 pub mod user_info_error;
@@ -2,7 +2,11 @@ use cosmwasm_std::Uint128;
 use sp_secret_toolkit::contract::error::SPContractError;
 use thiserror::Error;
 
-use crate::data::bets::UserContest;
+use crate::data::{
+    bets::UserContest,
+    roles::Role,
+    state::{ContractStatus, FeePercent},
+};
 
 use super::contest_bet_summary_error::ContestBetSummaryError;
 
@@ -32,6 +36,36 @@ pub enum StateError {
     },
     #[error(transparent)]
     SPContractError(#[from] SPContractError),
+
+    #[error("Contract status: {0:?} does not allow betting. Display Text: Failure to place bet. Betting is currently paused.")]
+    BetsPaused(ContractStatus),
+
+    #[error("Contract status: {0:?} does not allow claiming. Display Text: Failure to claim. Claiming is currently paused.")]
+    ClaimsPaused(ContractStatus),
+
+    #[error("There is no pending ownership transfer")]
+    NoPendingOwner,
+
+    #[error("Fee denominator cannot be zero. Display Text: Failure to set fee. Fee denominator must be greater than zero.")]
+    ZeroFeeDenominator,
+
+    #[error("Fee numerator: {numerator} is greater than denominator: {denominator}. Display Text: Failure to set fee. Fee cannot be more than 100%.")]
+    FeeNumeratorExceedsDenominator { numerator: u128, denominator: u128 },
+
+    #[error("Fee: {}/{} is above the maximum: {}/{}. Display Text: Failure to set fee. Fee is above the maximum allowed.", .fee.numerator(), .fee.denominator(), .maximum.numerator(), .maximum.denominator())]
+    FeeAboveMaximum {
+        fee: FeePercent,
+        maximum: FeePercent,
+    },
+
+    #[error("Fee change effective at: {effective_at} is not after the current time: {now}. Display Text: Failure to schedule fee change. Fee changes must be scheduled in the future.")]
+    FeeChangeNotInFuture { effective_at: u64, now: u64 },
+
+    #[error("Address: {address} does not have role: {role:?}")]
+    MissingRole { address: String, role: Role },
+
+    #[error("Address: {address} already has role: {role:?}")]
+    RoleAlreadyGranted { address: String, role: Role },
 }
 
 impl From<StateError> for cosmwasm_std::StdError {
@@ -0,0 +1,40 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum TickerError {
+    #[error("Ticker: {0} already exists. Display Text: Failure to add ticker. Ticker is already registered.")]
+    TickerAlreadyExists(String),
+
+    #[error("Ticker: {0} is invalid. Display Text: Failure to add ticker. Tickers cannot contain ':'.")]
+    InvalidTicker(String),
+
+    #[error("Ticker: {0} Does Not Exist. Display Text: Failure to place bet. Cannot place bet on tickers that does not exist.")]
+    TickerNotFound(String),
+
+    #[error("Ticker: {0} is paused. Display Text: Failure to place bet. Betting on this ticker is currently paused.")]
+    TickerPaused(String),
+
+    #[error("Interval: {0} is invalid. Display Text: Failure to configure ticker. Interval must be a non zero number of whole minutes.")]
+    InvalidInterval(u64),
+
+    #[error("Ticker: {0} needs at least two outcome bands. Display Text: Failure to configure ticker. Contests need at least two outcomes.")]
+    TooFewOutcomeBands(String),
+
+    #[error("Outcome id: {0} is invalid or used more than once. Display Text: Failure to configure ticker. Outcome ids must be unique and greater than zero.")]
+    InvalidOutcomeId(u8),
+
+    #[error("Outcome band: {0} has invalid bounds. Display Text: Failure to configure ticker. Band bounds must be at least -10000 and the lower bound must be below the upper bound.")]
+    InvalidOutcomeBand(u8),
+
+    #[error("Outcome bands: {0} and {1} overlap. Display Text: Failure to configure ticker. Outcome bands cannot overlap.")]
+    OverlappingOutcomeBands(u8, u8),
+
+    #[error(transparent)]
+    StandardError(#[from] cosmwasm_std::StdError),
+}
+
+impl From<TickerError> for cosmwasm_std::StdError {
+    fn from(error: TickerError) -> Self {
+        cosmwasm_std::StdError::generic_err(format!("Ticker Error: {}", error.to_string()))
+    }
+}
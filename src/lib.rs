@@ -1,12 +1,9 @@
-#[cfg(feature = "testing")]
-#[macro_use]
-extern crate lazy_static;
-
 pub mod command_handlers;
 pub mod constants;
 pub mod contract;
 pub mod data;
 pub mod error;
+pub mod migrations;
 pub mod msgs;
 pub mod responses;
 pub mod services;
@@ -0,0 +1,19 @@
+use cosmwasm_std::{Env, Storage};
+
+use crate::error::migration_error::MigrationError;
+
+pub mod v2_0_0;
+pub mod v2_1_0;
+
+pub struct MigrationStep {
+    pub from: &'static str,
+    pub to: &'static str,
+    pub migrate: fn(&mut dyn Storage, &Env) -> Result<(), MigrationError>,
+}
+
+// Ordered upgrade steps, each moves the storage layout forward by one release
+pub static MIGRATION_STEPS: &[MigrationStep] = &[MigrationStep {
+    from: "2.0.0",
+    to: "2.1.0",
+    migrate: v2_1_0::migrate,
+}];
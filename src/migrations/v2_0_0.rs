@@ -0,0 +1,16 @@
+// Storage layouts written by version 2.0.0, kept so upgrade steps can read them
+use cosmwasm_std::{Addr, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sp_secret_toolkit::macros::singleton::SingletonStorage;
+
+use crate::data::state::FeePercent;
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema, SingletonStorage)]
+pub struct State {
+    pub owner: Addr,
+    pub interval: u64,
+    pub minimum_bet: Uint128,
+    pub fee_percent: FeePercent,
+    pub claimable_fees: Uint128,
+}
@@ -0,0 +1,59 @@
+use cosmwasm_std::{Env, Storage};
+
+use crate::{
+    data::{
+        state::{FeePercent, State},
+        tickers::TICKERS,
+    },
+    error::migration_error::MigrationError,
+    services::ticker_service::add_ticker,
+};
+
+use super::v2_0_0;
+
+// Tickers that were hard coded before the ticker registry existed
+pub const LEGACY_TICKERS: &[&str] = &["BTC", "ETH"];
+
+pub fn migrate(storage: &mut dyn Storage, env: &Env) -> Result<(), MigrationError> {
+    migrate_state(storage)?;
+    seed_tickers(storage, env)?;
+    Ok(())
+}
+
+pub fn migrate_state(storage: &mut dyn Storage) -> Result<(), MigrationError> {
+    let legacy_state = v2_0_0::State::singleton_load(storage)?;
+
+    let mut state = State::new(
+        legacy_state.owner,
+        legacy_state.interval,
+        legacy_state.minimum_bet,
+        normalize_legacy_fee_percent(legacy_state.fee_percent)?,
+    );
+    state.set_claimable_fees(legacy_state.claimable_fees);
+    // Never cap below a fee that was already live before the cap existed
+    if state.fee_percent().exceeds(state.max_fee_percent()) {
+        state.set_max_fee_percent(state.fee_percent().clone());
+    }
+
+    state.singleton_save(storage)?;
+    Ok(())
+}
+
+// 2.0.0 never validated its fee, a zero fee could be stored as 0/0 which cannot be applied
+fn normalize_legacy_fee_percent(fee_percent: FeePercent) -> Result<FeePercent, MigrationError> {
+    match fee_percent.validate() {
+        Ok(()) => Ok(fee_percent),
+        Err(_) if fee_percent.numerator() == &0 => Ok(FeePercent::new(0, 100)),
+        Err(error) => Err(error.into()),
+    }
+}
+
+fn seed_tickers(storage: &mut dyn Storage, env: &Env) -> Result<(), MigrationError> {
+    for ticker in LEGACY_TICKERS {
+        let ticker = ticker.to_string();
+        if !TICKERS.contains(storage, &ticker) {
+            add_ticker(storage, env, &ticker)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,12 @@
+use cosmwasm_std::{Binary, ContractInfo, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct AddAcceptedToken {
+    pub token: ContractInfo,
+    pub minimum_bet: Uint128,
+    // Used to generate the contract's viewing key on the token
+    pub entropy: Binary,
+}
@@ -0,0 +1,15 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::data::contest_info::ContestId;
+
+// A bet paid for with native funds sent along with the message
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct BetContest {
+    pub ticker: String,
+    pub outcome_id: u8,
+    pub contest_id: Option<ContestId>,
+    pub roll_over: Option<bool>,
+    pub refund_on_failure: Option<bool>,
+}
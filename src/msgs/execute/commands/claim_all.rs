@@ -0,0 +1,9 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ClaimAll {
+    // How many of the user's unchecked contests to look at, capped at MAX_CLAIM_ALL_CONTESTS
+    pub max_contests: u32,
+}
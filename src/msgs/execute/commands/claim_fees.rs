@@ -1,7 +1,16 @@
+use cosmwasm_std::{Addr, Uint128};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::data::currency::Currency;
+
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct ClaimFees {
+    // Defaults to the full claimable balance
+    pub amount: Option<Uint128>,
+    // Defaults to the sender
+    pub recipient: Option<Addr>,
+    // Defaults to the SNIP-20 token
+    pub currency: Option<Currency>,
 }
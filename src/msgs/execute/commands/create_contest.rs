@@ -0,0 +1,14 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::data::{currency::Currency, outcome_band::OutcomeBand};
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct CreateContest {
+    pub ticker: String,
+    pub time_of_close: u64,
+    pub time_of_resolve: u64,
+    pub options: Vec<OutcomeBand>,
+    // Defaults to the SNIP-20 token
+    pub currency: Option<Currency>,
+}
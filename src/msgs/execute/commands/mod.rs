@@ -1,7 +1,38 @@
+pub mod accept_ownership;
+pub mod add_accepted_token;
+pub mod add_ticker;
+pub mod bet_contest;
+pub mod cancel_bet;
+pub mod cancel_ownership_transfer;
 pub mod claim;
+pub mod claim_all;
 pub mod claim_fees;
 pub mod claim_multiple;
+pub mod create_contest;
+pub mod distribute_fees;
 pub mod fail_safe;
+pub mod grant_role;
+pub mod pause_ticker;
+pub mod propose_new_owner;
 pub mod receive;
+pub mod remove_accepted_token;
+pub mod remove_ticker;
+pub mod resolve_contest_manually;
+pub mod resolve_contests;
+pub mod revoke_role;
+pub mod schedule_fee_change;
+pub mod set_bet_limits;
+pub mod set_betting_cutoff;
+pub mod set_cancellation_fee;
+pub mod set_expiration_window;
 pub mod set_fee;
+pub mod set_fee_recipients;
+pub mod set_keeper_reward;
+pub mod set_max_fee;
 pub mod set_minimum_bet;
+pub mod set_native_denom;
+pub mod set_switching_fee;
+pub mod set_ticker_config;
+pub mod set_ticker_outcomes;
+pub mod switch_side;
+pub mod void_contest;
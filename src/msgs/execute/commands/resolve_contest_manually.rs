@@ -0,0 +1,14 @@
+use cosmwasm_std::Decimal;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::data::contest_info::ContestId;
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ResolveContestManually {
+    pub contest_id: ContestId,
+    pub close_price: Decimal,
+    pub resolve_price: Decimal,
+    pub reason: String,
+}
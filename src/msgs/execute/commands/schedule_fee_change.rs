@@ -0,0 +1,11 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ScheduleFeeChange {
+    pub numerator: u64,
+    pub denominator: u64,
+    // Block time in seconds from which new contests use the fee
+    pub effective_at: u64,
+}
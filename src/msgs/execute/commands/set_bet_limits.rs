@@ -0,0 +1,16 @@
+use cosmwasm_std::Uint128;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::data::currency::Currency;
+
+// Replaces every limit, a limit left out is removed
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct SetBetLimits {
+    pub max_user_bet: Option<Uint128>,
+    pub max_pool: Option<Uint128>,
+    pub max_user_exposure: Option<Uint128>,
+    // Defaults to the SNIP-20 token
+    pub currency: Option<Currency>,
+}
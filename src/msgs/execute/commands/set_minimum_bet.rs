@@ -1,8 +1,13 @@
 use cosmwasm_std::Uint128;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+
+use crate::data::currency::Currency;
+
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct SetMinimumBet {
     pub amount: Uint128,
+    // Defaults to the SNIP-20 token
+    pub currency: Option<Currency>,
 }
@@ -0,0 +1,12 @@
+use cosmwasm_std::Uint128;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct SetNativeDenom {
+    // None stops native bets, existing native contests stay claimable
+    pub denom: Option<String>,
+    // Smallest bet taken in the denom, required when enabling native bets
+    pub minimum_bet: Option<Uint128>,
+}
@@ -0,0 +1,10 @@
+use cosmwasm_std::ContractInfo;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct SetTickerConfig {
+    pub ticker: String,
+    pub interval: Option<u64>,
+    pub price_feed: Option<ContractInfo>,
+}
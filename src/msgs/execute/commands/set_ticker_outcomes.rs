@@ -0,0 +1,10 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::data::outcome_band::OutcomeBand;
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct SetTickerOutcomes {
+    pub ticker: String,
+    pub outcome_bands: Option<Vec<OutcomeBand>>,
+}
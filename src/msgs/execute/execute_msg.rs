@@ -2,8 +2,21 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::commands::{
-    claim::Claim, claim_fees::ClaimFees, claim_multiple::ClaimMultiple, receive::Receive,
-    set_fee::SetFee, set_minimum_bet::SetMinimumBet,
+    accept_ownership::AcceptOwnership, add_accepted_token::AddAcceptedToken, add_ticker::AddTicker,
+    bet_contest::BetContest, cancel_bet::CancelBet,
+    cancel_ownership_transfer::CancelOwnershipTransfer, claim::Claim, claim_all::ClaimAll,
+    claim_fees::ClaimFees, claim_multiple::ClaimMultiple, create_contest::CreateContest,
+    distribute_fees::DistributeFees, fail_safe::FailSafe, grant_role::GrantRole,
+    pause_ticker::PauseTicker, propose_new_owner::ProposeNewOwner, receive::Receive,
+    remove_accepted_token::RemoveAcceptedToken, remove_ticker::RemoveTicker,
+    resolve_contest_manually::ResolveContestManually, resolve_contests::ResolveContests,
+    revoke_role::RevokeRole, schedule_fee_change::ScheduleFeeChange, set_bet_limits::SetBetLimits,
+    set_betting_cutoff::SetBettingCutoff, set_cancellation_fee::SetCancellationFee,
+    set_expiration_window::SetExpirationWindow, set_fee::SetFee,
+    set_fee_recipients::SetFeeRecipients, set_keeper_reward::SetKeeperReward,
+    set_max_fee::SetMaxFee, set_minimum_bet::SetMinimumBet, set_native_denom::SetNativeDenom,
+    set_switching_fee::SetSwitchingFee, set_ticker_config::SetTickerConfig,
+    set_ticker_outcomes::SetTickerOutcomes, switch_side::SwitchSide, void_contest::VoidContest,
 };
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
@@ -11,7 +24,39 @@ pub enum ExecuteMsg {
     Claim(Claim),
     ClaimFees(ClaimFees),
     ClaimMultiple(ClaimMultiple),
+    ClaimAll(ClaimAll),
+    ResolveContests(ResolveContests),
+    BetContest(BetContest),
+    CancelBet(CancelBet),
+    SwitchSide(SwitchSide),
     SetMinimumBet(SetMinimumBet),
+    SetNativeDenom(SetNativeDenom),
+    SetBettingCutoff(SetBettingCutoff),
+    SetBetLimits(SetBetLimits),
     Receive(Receive),
     SetFee(SetFee),
+    SetMaxFee(SetMaxFee),
+    SetCancellationFee(SetCancellationFee),
+    SetSwitchingFee(SetSwitchingFee),
+    SetKeeperReward(SetKeeperReward),
+    ScheduleFeeChange(ScheduleFeeChange),
+    AddAcceptedToken(AddAcceptedToken),
+    RemoveAcceptedToken(RemoveAcceptedToken),
+    AddTicker(AddTicker),
+    RemoveTicker(RemoveTicker),
+    PauseTicker(PauseTicker),
+    SetTickerConfig(SetTickerConfig),
+    SetTickerOutcomes(SetTickerOutcomes),
+    CreateContest(CreateContest),
+    ResolveContestManually(ResolveContestManually),
+    VoidContest(VoidContest),
+    SetExpirationWindow(SetExpirationWindow),
+    FailSafe(FailSafe),
+    ProposeNewOwner(ProposeNewOwner),
+    AcceptOwnership(AcceptOwnership),
+    CancelOwnershipTransfer(CancelOwnershipTransfer),
+    GrantRole(GrantRole),
+    RevokeRole(RevokeRole),
+    SetFeeRecipients(SetFeeRecipients),
+    DistributeFees(DistributeFees),
 }
@@ -12,4 +12,5 @@ pub struct InstantiateMsg {
     pub entropy: Binary,
     pub fee_numerator: u64,
     pub fee_denominator: u64,
+    pub tickers: Vec<String>,
 }
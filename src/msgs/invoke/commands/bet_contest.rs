@@ -2,10 +2,18 @@ use cosmwasm_std::Addr;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::data::contest_info::ContestId;
+
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct BetContest {
     pub ticker: String,
     pub outcome_id: u8,
     pub user: Addr,
+    // Custom contests are bet on by id, otherwise the ticker's current contest is used
+    pub contest_id: Option<ContestId>,
+    // Bets inside the betting cutoff go to the next contest instead of being rejected
+    pub roll_over: Option<bool>,
+    // Rejected bets are sent back to the user with an error code instead of reverting
+    pub refund_on_failure: Option<bool>,
 }
@@ -1,4 +1,5 @@
 pub mod execute;
 pub mod instantiate;
 pub mod invoke;
+pub mod migrate;
 pub mod query;
@@ -0,0 +1,19 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::data::{contest_info::ContestStatus, contests::SortOrder};
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct GetContests {
+    // The next_cursor of the previous page
+    pub start_after: Option<u32>,
+    pub limit: Option<u32>,
+    pub ticker: Option<String>,
+    pub status: Option<ContestStatus>,
+    // Bounds on the time of close, both inclusive
+    pub from_time: Option<u64>,
+    pub to_time: Option<u64>,
+    // Defaults to newest first
+    pub order: Option<SortOrder>,
+}
@@ -0,0 +1,11 @@
+use cosmwasm_std::Addr;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct GetFeePayouts {
+    pub recipient: Option<Addr>,
+    pub page: u32,
+    pub page_size: u32,
+}
@@ -0,0 +1,17 @@
+use cosmwasm_std::Addr;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::data::bets::UserBetStatus;
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct GetUserBets {
+    pub user: Addr,
+    pub viewing_key: String,
+    // The next_cursor of the previous page
+    pub start_after: Option<u32>,
+    pub limit: Option<u32>,
+    pub status: Option<UserBetStatus>,
+    pub ticker: Option<String>,
+}
@@ -1,12 +1,26 @@
+pub mod get_accepted_tokens;
+pub mod get_bet_limits;
+pub mod get_betting_cutoff;
 pub mod get_claimable_contests;
 pub mod get_claimable_fees;
 pub mod get_contest_by_id;
+pub mod get_contests;
 pub mod get_contests_by_ids;
+pub mod get_contract_status;
+pub mod get_expiration_window;
+pub mod get_fee_payouts;
 pub mod get_fee_percent;
+pub mod get_fee_recipients;
+pub mod get_fee_withdrawals;
 pub mod get_last_ten_contests;
 pub mod get_min_bet;
+pub mod get_native_denom;
+pub mod get_owner;
+pub mod get_pending_fee_change;
+pub mod get_roles;
 pub mod get_snip20;
 pub mod get_stats;
+pub mod get_tickers;
 pub mod get_times_to_resolve;
 pub mod get_total_number_of_bets;
 pub mod get_total_number_of_contests;
@@ -14,6 +28,7 @@ pub mod get_total_users;
 pub mod get_total_value;
 pub mod get_total_volume;
 pub mod get_user_bet;
+pub mod get_user_bets;
 pub mod get_users_last_ten_bets;
 pub mod get_users_list_of_bets;
 pub mod get_users_number_of_bets;
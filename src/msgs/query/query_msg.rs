@@ -2,15 +2,22 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::commands::{
-    get_claimable_contests::GetClaimableContests, get_claimable_fees::GetClaimableFees,
-    get_contest_by_id::GetContestById, get_contests_by_ids::GetContestsByIds,
-    get_fee_percent::GetFeePercent, get_last_ten_contests::GetLastTenContests,
-    get_min_bet::GetMinBet, get_snip20::GetSnip20, get_stats::GetStats,
-    get_times_to_resolve::GetTimesToResolve, get_total_number_of_bets::GetTotalNumberOfBets,
+    get_accepted_tokens::GetAcceptedTokens, get_bet_limits::GetBetLimits,
+    get_betting_cutoff::GetBettingCutoff, get_claimable_contests::GetClaimableContests,
+    get_claimable_fees::GetClaimableFees, get_contest_by_id::GetContestById,
+    get_contests::GetContests, get_contests_by_ids::GetContestsByIds,
+    get_contract_status::GetContractStatus, get_expiration_window::GetExpirationWindow,
+    get_fee_payouts::GetFeePayouts, get_fee_percent::GetFeePercent,
+    get_fee_recipients::GetFeeRecipients, get_fee_withdrawals::GetFeeWithdrawals,
+    get_last_ten_contests::GetLastTenContests, get_min_bet::GetMinBet,
+    get_native_denom::GetNativeDenom, get_owner::GetOwner,
+    get_pending_fee_change::GetPendingFeeChange, get_roles::GetRoles, get_snip20::GetSnip20,
+    get_stats::GetStats, get_tickers::GetTickers, get_times_to_resolve::GetTimesToResolve,
+    get_total_number_of_bets::GetTotalNumberOfBets,
     get_total_number_of_contests::GetTotalNumberOfContests, get_total_users::GetTotalUsers,
     get_total_value::GetTotalValue, get_total_volume::GetTotalVolume, get_user_bet::GetUserBet,
-    get_users_last_ten_bets::GetUsersLastTenBets, get_users_list_of_bets::GetUsersListOfBets,
-    get_users_number_of_bets::GetUsersNumberOfBets,
+    get_user_bets::GetUserBets, get_users_last_ten_bets::GetUsersLastTenBets,
+    get_users_list_of_bets::GetUsersListOfBets, get_users_number_of_bets::GetUsersNumberOfBets,
 };
 
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
@@ -18,15 +25,23 @@ use super::commands::{
 pub enum QueryMsg {
     GetContestById(GetContestById),
     GetContestsByIds(GetContestsByIds),
+    GetContests(GetContests),
     GetUserBet(GetUserBet),
+    GetUserBets(GetUserBets),
     GetUsersLastTenBets(GetUsersLastTenBets),
     GetMinBet(GetMinBet),
+    GetBettingCutoff(GetBettingCutoff),
+    GetExpirationWindow(GetExpirationWindow),
+    GetBetLimits(GetBetLimits),
     GetTotalValue(GetTotalValue),
     GetSnip20(GetSnip20),
+    GetAcceptedTokens(GetAcceptedTokens),
+    GetNativeDenom(GetNativeDenom),
     GetTimesToResolve(GetTimesToResolve),
     GetClaimableFees(GetClaimableFees),
     GetClaimableContests(GetClaimableContests),
     GetFeePercent(GetFeePercent),
+    GetPendingFeeChange(GetPendingFeeChange),
     GetTotalNumberOfContests(GetTotalNumberOfContests),
     GetTotalNumberOfBets(GetTotalNumberOfBets),
     GetTotalVolume(GetTotalVolume),
@@ -35,4 +50,11 @@ pub enum QueryMsg {
     GetLastTenContests(GetLastTenContests),
     GetTotalUsers(GetTotalUsers),
     GetStats(GetStats),
+    GetTickers(GetTickers),
+    GetContractStatus(GetContractStatus),
+    GetOwner(GetOwner),
+    GetRoles(GetRoles),
+    GetFeeRecipients(GetFeeRecipients),
+    GetFeePayouts(GetFeePayouts),
+    GetFeeWithdrawals(GetFeeWithdrawals),
 }
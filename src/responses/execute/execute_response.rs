@@ -3,7 +3,9 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::response_types::{
-    bet::BetResonse, claim::ClaimResponse,
+    bet::BetResonse, cancel_bet::CancelBetResponse, claim::ClaimResponse,
+    claim_all::ClaimAllResponse, distribute_fees::DistributeFeesResponse,
+    resolve_contests::ResolveContestsResponse, switch_side::SwitchSideResponse,
 };
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema, Debug)]
@@ -17,7 +19,12 @@ pub enum ResponseStatus {
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteResponse {
     Claim(ClaimResponse),
+    ClaimAll(ClaimAllResponse),
+    ResolveContests(ResolveContestsResponse),
     Bet(BetResonse),
+    CancelBet(CancelBetResponse),
+    SwitchSide(SwitchSideResponse),
+    DistributeFees(DistributeFeesResponse),
 }
 
 impl From<ExecuteResponse> for Binary {
@@ -3,8 +3,27 @@ use serde::{Deserialize, Serialize};
 
 use crate::responses::execute::execute_response::ResponseStatus;
 
+// Why a bet was refunded, so wallets can react without parsing error text
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BetErrorCode {
+    BetsNotAllowed,
+    BetBelowMinimum,
+    TickerUnavailable,
+    ContestNotFound,
+    ContestClosed,
+    OutcomeNotFound,
+    CannotBetOnBothSides,
+    BetCancelled,
+    CurrencyMismatch,
+    UserBetLimitExceeded,
+    PoolLimitExceeded,
+    ExposureLimitExceeded,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct BetResonse {
     pub status: ResponseStatus,
+    pub error_code: Option<BetErrorCode>,
 }
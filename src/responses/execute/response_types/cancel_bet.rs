@@ -0,0 +1,13 @@
+use cosmwasm_std::Uint128;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::responses::execute::execute_response::ResponseStatus;
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct CancelBetResponse {
+    pub status: ResponseStatus,
+    pub refunded: Uint128,
+    pub fee: Uint128,
+}
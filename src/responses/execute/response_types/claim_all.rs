@@ -0,0 +1,25 @@
+use cosmwasm_std::Uint128;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    data::{contest_info::ContestId, currency::Currency},
+    responses::execute::execute_response::ResponseStatus,
+};
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ContestClaim {
+    pub contest_id: ContestId,
+    pub currency: Currency,
+    pub amount: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ClaimAllResponse {
+    pub status: ResponseStatus,
+    pub claims: Vec<ContestClaim>,
+    // Contests not yet claimed, including ones still waiting on a result
+    pub remaining: u32,
+}
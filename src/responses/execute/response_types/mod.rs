@@ -1,2 +1,7 @@
 pub mod bet;
+pub mod cancel_bet;
 pub mod claim;
+pub mod claim_all;
+pub mod distribute_fees;
+pub mod resolve_contests;
+pub mod switch_side;
@@ -0,0 +1,28 @@
+use cosmwasm_std::Uint128;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    data::{
+        contest_info::{ContestId, ContestOutcome},
+        currency::Currency,
+    },
+    responses::execute::execute_response::ResponseStatus,
+};
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ResolvedContest {
+    pub contest_id: ContestId,
+    pub outcome: ContestOutcome,
+    pub currency: Currency,
+    pub keeper_reward: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ResolveContestsResponse {
+    pub status: ResponseStatus,
+    // Contests that could not be resolved yet, or already were, are left out
+    pub resolved: Vec<ResolvedContest>,
+}
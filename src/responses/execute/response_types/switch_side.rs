@@ -0,0 +1,14 @@
+use cosmwasm_std::Uint128;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::responses::execute::execute_response::ResponseStatus;
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct SwitchSideResponse {
+    pub status: ResponseStatus,
+    pub outcome_id: u8,
+    pub amount: Uint128,
+    pub fee: Uint128,
+}
@@ -2,31 +2,47 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::response_types::{
-    bet::UserBetResponse, claimable_fees::ClaimableFeesResponse, contest_data::ContestDataResponse,
-    contest_data_list::ContestDataListResponse, fee_percent::FeePercentResponse,
-    get_claimable_value::ClaimableValueResponse, get_snip20::GetSnip20Response,
-    minimum_bet::MinimumBetResponse, stats::StatsResponse,
-    times_to_resolve::TimesToResolveResponse, total_number_of_bets::TotalNumberOfBetsResponse,
+    accepted_tokens::AcceptedTokensResponse, bet::UserBetResponse, bet_limits::BetLimitsResponse,
+    betting_cutoff::BettingCutoffResponse, claimable_fees::ClaimableFeesResponse,
+    contest_data::ContestDataResponse, contest_data_list::ContestDataListResponse,
+    contests_page::ContestsPageResponse, contract_status::ContractStatusResponse,
+    expiration_window::ExpirationWindowResponse, fee_payouts::FeePayoutsResponse,
+    fee_percent::FeePercentResponse, fee_recipients::FeeRecipientsResponse,
+    fee_withdrawals::FeeWithdrawalsResponse, get_claimable_value::ClaimableValueResponse,
+    get_snip20::GetSnip20Response, minimum_bet::MinimumBetResponse,
+    native_denom::NativeDenomResponse, owner::OwnerResponse,
+    pending_fee_change::PendingFeeChangeResponse, roles::RolesResponse, stats::StatsResponse,
+    tickers::TickersResponse, times_to_resolve::TimesToResolveResponse,
+    total_number_of_bets::TotalNumberOfBetsResponse,
     total_number_of_contests::TotalNumberOfContestsResponse,
     total_number_of_users::TotalNumberOfUsersResponse,
     total_users_number_of_bets::TotalUsersNumberOfBetsResponse, total_value::TotalValueResponse,
-    total_volume::TotalVolumeResponse, users_bets::UsersBetsResponse,
+    total_volume::TotalVolumeResponse, user_bets_page::UserBetsPageResponse,
+    users_bets::UsersBetsResponse,
 };
 
 // Enum to encapsulate each query response type
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryResponse {
-    ContestData(ContestDataResponse),
+    ContestData(Box<ContestDataResponse>),
     ContestDataList(ContestDataListResponse),
+    ContestsPage(ContestsPageResponse),
     UserBet(UserBetResponse),
     UsersBets(UsersBetsResponse),
+    UserBetsPage(UserBetsPageResponse),
     MinimumBet(MinimumBetResponse),
+    BettingCutoff(BettingCutoffResponse),
+    ExpirationWindow(ExpirationWindowResponse),
+    BetLimits(BetLimitsResponse),
     TotalValue(TotalValueResponse),
     Snip20(GetSnip20Response),
+    AcceptedTokens(AcceptedTokensResponse),
+    NativeDenom(NativeDenomResponse),
     TimesToResolve(TimesToResolveResponse),
     ClaimableFees(ClaimableFeesResponse),
     FeePercent(FeePercentResponse),
+    PendingFeeChange(PendingFeeChangeResponse),
     ClaimableValue(ClaimableValueResponse),
     TotalNumberOfContests(TotalNumberOfContestsResponse),
     TotalNumberOfBets(TotalNumberOfBetsResponse),
@@ -34,4 +50,11 @@ pub enum QueryResponse {
     TotalUsersNumberOfBets(TotalUsersNumberOfBetsResponse),
     TotalNumberOfUsers(TotalNumberOfUsersResponse),
     Stats(StatsResponse),
+    Tickers(TickersResponse),
+    ContractStatus(ContractStatusResponse),
+    Owner(OwnerResponse),
+    Roles(RolesResponse),
+    FeeRecipients(FeeRecipientsResponse),
+    FeePayouts(FeePayoutsResponse),
+    FeeWithdrawals(FeeWithdrawalsResponse),
 }
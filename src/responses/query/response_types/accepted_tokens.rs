@@ -0,0 +1,22 @@
+use cosmwasm_std::{ContractInfo, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::data::currency::Currency;
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct AcceptedTokensResponse {
+    pub tokens: Vec<AcceptedTokenResponse>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct AcceptedTokenResponse {
+    pub token: ContractInfo,
+    // The currency to name when claiming fees or setting the minimum bet
+    pub currency: Currency,
+    pub minimum_bet: Uint128,
+    pub claimable_fees: Uint128,
+    pub accepting_bets: bool,
+    // False until the token confirms the contract's viewing key
+    pub viewing_key_set: bool,
+}
@@ -1,9 +1,10 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::data::bets::Bet;
+use crate::data::bets::{Bet, BetCancellation};
 
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 pub struct UserBetResponse {
     pub bet: Bet,
+    pub cancellation: Option<BetCancellation>,
 }
@@ -0,0 +1,10 @@
+use cosmwasm_std::Uint128;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct BetLimitsResponse {
+    pub max_user_bet: Option<Uint128>,
+    pub max_pool: Option<Uint128>,
+    pub max_user_exposure: Option<Uint128>,
+}
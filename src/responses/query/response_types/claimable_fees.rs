@@ -5,4 +5,11 @@ use serde::{Deserialize, Serialize};
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 pub struct ClaimableFeesResponse {
     pub claimable_fees: Uint128,
+    pub native_claimable_fees: Vec<NativeClaimableFee>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct NativeClaimableFee {
+    pub denom: String,
+    pub amount: Uint128,
 }
@@ -1,11 +1,18 @@
+use cosmwasm_std::ContractInfo;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::data::{contest_bet_summary::ContestBetSummary, contest_info::ContestInfo};
+use crate::data::{
+    contest_bet_summary::ContestBetSummary, contest_info::ContestInfo,
+    contest_resolution::ManualResolution,
+};
 
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct ContestDataResponse {
     pub contest_info: ContestInfo,
     pub contest_bet_summary: ContestBetSummary,
+    pub price_feed: ContractInfo,
+    // Set when the owner resolved or voided the contest instead of the price feed
+    pub manual_resolution: Option<ManualResolution>,
 }
@@ -0,0 +1,12 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::contest_data::ContestDataResponse;
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ContestsPageResponse {
+    pub contests: Vec<ContestDataResponse>,
+    // Pass as start_after to get the next page, none once every contest has been listed
+    pub next_cursor: Option<u32>,
+}
@@ -0,0 +1,10 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::data::fee_recipients::FeePayout;
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct FeePayoutsResponse {
+    pub payouts: Vec<FeePayout>,
+    pub total: u32,
+}
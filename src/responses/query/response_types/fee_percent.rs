@@ -6,4 +6,8 @@ use crate::data::state::FeePercent;
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 pub struct FeePercentResponse {
     pub fee_percent: FeePercent,
+    pub max_fee_percent: FeePercent,
+    pub cancellation_fee_percent: FeePercent,
+    pub switching_fee_percent: FeePercent,
+    pub keeper_reward_percent: FeePercent,
 }
@@ -0,0 +1,10 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::data::fee_withdrawals::FeeWithdrawal;
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct FeeWithdrawalsResponse {
+    pub withdrawals: Vec<FeeWithdrawal>,
+    pub total: u32,
+}
@@ -1,12 +1,26 @@
+pub mod accepted_tokens;
 pub mod bet;
+pub mod bet_limits;
+pub mod betting_cutoff;
 pub mod claimable_fees;
 pub mod contest_data;
 pub mod contest_data_list;
+pub mod contests_page;
+pub mod contract_status;
+pub mod expiration_window;
+pub mod fee_payouts;
 pub mod fee_percent;
+pub mod fee_recipients;
+pub mod fee_withdrawals;
 pub mod get_claimable_value;
 pub mod get_snip20;
 pub mod minimum_bet;
+pub mod native_denom;
+pub mod owner;
+pub mod pending_fee_change;
+pub mod roles;
 pub mod stats;
+pub mod tickers;
 pub mod times_to_resolve;
 pub mod total_number_of_bets;
 pub mod total_number_of_contests;
@@ -14,4 +28,5 @@ pub mod total_number_of_users;
 pub mod total_users_number_of_bets;
 pub mod total_value;
 pub mod total_volume;
+pub mod user_bets_page;
 pub mod users_bets;
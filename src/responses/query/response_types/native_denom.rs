@@ -0,0 +1,9 @@
+use cosmwasm_std::Uint128;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct NativeDenomResponse {
+    pub denom: Option<String>,
+    pub minimum_bet: Uint128,
+}
@@ -0,0 +1,9 @@
+use cosmwasm_std::Addr;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct OwnerResponse {
+    pub owner: Addr,
+    pub pending_owner: Option<Addr>,
+}
@@ -0,0 +1,9 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::data::state::PendingFeeChange;
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct PendingFeeChangeResponse {
+    pub pending_fee_change: Option<PendingFeeChange>,
+}
@@ -2,10 +2,14 @@ use cosmwasm_std::Uint128;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use super::total_volume::CurrencyVolume;
+
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 pub struct StatsResponse {
     pub contests: u32,
     pub bets: u64,
     pub users: u32,
+    // Volume of contests held in the SNIP-20 token
     pub volume: Uint128,
+    pub currency_volumes: Vec<CurrencyVolume>,
 }
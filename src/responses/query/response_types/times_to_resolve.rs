@@ -1,3 +1,4 @@
+use cosmwasm_std::ContractInfo;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -5,4 +6,12 @@ use serde::{Deserialize, Serialize};
 #[serde(rename_all = "snake_case")]
 pub struct TimesToResolveResponse {
     pub times: Vec<u64>,
+    pub price_feeds: Vec<PriceFeedTimes>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PriceFeedTimes {
+    pub price_feed: ContractInfo,
+    pub times: Vec<u64>,
 }
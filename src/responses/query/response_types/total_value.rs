@@ -2,7 +2,17 @@ use cosmwasm_std::Uint128;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::data::currency::Currency;
+
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 pub struct TotalValueResponse {
+    // The contract's balance of the SNIP-20 token
     pub total_value: Uint128,
+    pub currency_values: Vec<CurrencyValue>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct CurrencyValue {
+    pub currency: Currency,
+    pub value: Uint128,
 }
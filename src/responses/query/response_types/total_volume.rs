@@ -2,7 +2,17 @@ use cosmwasm_std::Uint128;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::data::currency::Currency;
+
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 pub struct TotalVolumeResponse {
+    // Volume of contests held in the SNIP-20 token
     pub total_volume: Uint128,
+    pub currency_volumes: Vec<CurrencyVolume>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct CurrencyVolume {
+    pub currency: Currency,
+    pub volume: Uint128,
 }
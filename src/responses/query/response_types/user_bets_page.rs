@@ -0,0 +1,12 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::users_bets::UserContestBetInfo;
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct UserBetsPageResponse {
+    pub contests_bets: Vec<UserContestBetInfo>,
+    // Pass as start_after to get the next page, none once every bet has been listed
+    pub next_cursor: Option<u32>,
+}
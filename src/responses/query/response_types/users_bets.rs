@@ -1,7 +1,11 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::data::{bets::Bet, contest_bet_summary::ContestBetSummary, contest_info::ContestInfo};
+use crate::data::{
+    bets::{Bet, BetCancellation},
+    contest_bet_summary::ContestBetSummary,
+    contest_info::ContestInfo,
+};
 
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
@@ -9,6 +13,7 @@ pub struct UserContestBetInfo {
     pub contest_info: ContestInfo,
     pub contest_bet_summary: ContestBetSummary,
     pub user_bet: Bet,
+    pub cancellation: Option<BetCancellation>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
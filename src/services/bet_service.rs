@@ -1,11 +1,17 @@
-use cosmwasm_std::{Addr, Deps, Env, StdError, Storage, Uint128};
+use cosmwasm_std::{Addr, Deps, Env, StdError, StdResult, Storage, Uint128};
 use sp_secret_toolkit::macros::identifiable::Identifiable;
 
 use crate::{
+    constants::{MAX_CONTESTS_SCANNED, MAX_PAGE_SIZE},
     data::{
-        bets::{Bet, UserContest, TOTAL_BETS, TOTAL_VOLUME},
+        bets::{
+            get_users_open_positions, Bet, BetCancellation, OpenPosition, UserBetStatus,
+            UserContest, CANCELLED_BETS, CURRENCY_VOLUMES, TOTAL_BETS, TOTAL_VOLUME,
+        },
         contest_bet_summary::ContestBetSummary,
         contest_info::{ContestId, ContestInfo},
+        currency::Currency,
+        state::FeePercent,
         user_info::{get_users_contest_map, TOTAL_USERS},
     },
     error::bet_error::BetError,
@@ -17,10 +23,26 @@ use super::{
         get_contest_bet_summaries, update_contest_bet_summaries_with_results,
     },
     contest_info_service::get_contest_infos_for_ids,
-    integrations::price_feed_service::pricefeed::NULL_AND_VOID_CONTEST_RESULT,
+    currency_service::get_bet_limits_in,
+    integrations::price_feed_service::{PriceSource, NULL_AND_VOID_CONTEST_RESULT},
     user_info_service::get_unchecked_contests_for_user,
 };
 
+// Narrows a user's bet history, a filter left as none matches every bet
+pub struct UserBetFilter {
+    pub ticker: Option<String>,
+    pub status: Option<UserBetStatus>,
+}
+
+impl UserBetFilter {
+    fn matches(&self, contest_info: &ContestInfo, status: UserBetStatus) -> bool {
+        self.ticker
+            .as_ref()
+            .map_or(true, |ticker| ticker == &contest_info.get_ticker())
+            && self.status.map_or(true, |wanted| wanted == status)
+    }
+}
+
 // Assuming the existence of State, UserContest, Bet, ContestInfoError, and necessary validation functions.
 
 /// Places a new bet or updates an existing bet for a user on a given contest and outcome.
@@ -46,6 +68,7 @@ pub fn place_or_update_bet(
 ) -> Result<bool, BetError> {
     // Attempt to retrieve an existing bet
     let user_contest_key = UserContest::new(user.clone(), contest_id.clone()); // Cloning address is necessary here for ownership reasons
+    assert_not_cancelled(storage, &user_contest_key)?;
     match Bet::keymap_get_by_id(storage, &user_contest_key) {
         Some(mut bet) => {
             // If a bet exists but on a different outcome, reject the new bet
@@ -54,7 +77,7 @@ pub fn place_or_update_bet(
             }
             // Update the existing bet amount and save metrics
             bet.add_amount(*amount);
-            update_total_volume(storage, amount);
+            update_total_volume(storage, contest_id.currency(), amount);
             bet.keymap_save(storage)?;
             Ok(false)
         }
@@ -67,13 +90,230 @@ pub fn place_or_update_bet(
                 outcome_id.clone(),
             ); // Cloning address is necessary for Bet creation
             new_bet.keymap_save(storage)?;
-            update_total_volume(storage, amount);
+            update_total_volume(storage, contest_id.currency(), amount);
             increment_total_bets(storage);
             Ok(true)
         }
     }
 }
 
+/// Cancels a user's bet on a contest, keeping the bet itself for the user's history.
+///
+/// # Arguments
+///
+/// * `storage` - Storage holding the bet and the global metrics.
+/// * `user` - The address of the user cancelling their bet.
+/// * `contest_id` - The ID of the contest the bet was placed on.
+/// * `fee_percent` - The share of the stake kept as a cancellation fee.
+/// * `now` - The current block time in seconds.
+///
+/// # Returns
+///
+/// The cancelled bet and the refund/fee split for it.
+pub fn cancel_bet(
+    storage: &mut dyn Storage,
+    user: &Addr,
+    contest_id: &ContestId,
+    fee_percent: &FeePercent,
+    now: u64,
+) -> Result<(Bet, BetCancellation), BetError> {
+    let user_contest = UserContest::new(user.clone(), contest_id.clone());
+    assert_not_cancelled(storage, &user_contest)?;
+    let bet = get_user_bet(storage, user_contest.clone())?;
+
+    let fee = fee_percent.fee_on(bet.get_amount());
+    let refunded = *bet.get_amount() - fee;
+    let cancellation = BetCancellation::new(refunded, fee, now);
+    CANCELLED_BETS.insert(storage, &user_contest, &cancellation)?;
+    get_users_open_positions(user).remove(storage, contest_id)?;
+
+    decrease_total_volume(storage, contest_id.currency(), bet.get_amount());
+    decrement_total_bets(storage);
+    Ok((bet, cancellation))
+}
+
+/// Moves a user's whole stake on a contest to another outcome.
+///
+/// # Arguments
+///
+/// * `storage` - Storage holding the bet and the global metrics.
+/// * `user` - The address of the user switching sides.
+/// * `contest_id` - The ID of the contest the bet was placed on.
+/// * `outcome_id` - The ID of the outcome the stake moves to.
+/// * `fee_percent` - The share of the stake kept as a switching fee.
+///
+/// # Returns
+///
+/// The outcome the stake moved from, the updated bet and the fee taken.
+pub fn switch_bet_side(
+    storage: &mut dyn Storage,
+    user: &Addr,
+    contest_id: &ContestId,
+    outcome_id: &u8,
+    fee_percent: &FeePercent,
+) -> Result<(u8, Bet, Uint128), BetError> {
+    let user_contest = UserContest::new(user.clone(), contest_id.clone());
+    assert_not_cancelled(storage, &user_contest)?;
+    let mut bet = get_user_bet(storage, user_contest)?;
+    let previous_outcome_id = *bet.get_outcome_id();
+    if previous_outcome_id == *outcome_id {
+        return Err(BetError::AlreadyOnOutcome(*outcome_id));
+    }
+
+    let fee = fee_percent.fee_on(bet.get_amount());
+    bet.switch_outcome(*outcome_id, fee);
+    bet.keymap_save(storage)?;
+    decrease_total_volume(storage, contest_id.currency(), &fee);
+    Ok((previous_outcome_id, bet, fee))
+}
+
+pub fn get_bet_cancellation(
+    storage: &dyn Storage,
+    user_contest: &UserContest,
+) -> Option<BetCancellation> {
+    CANCELLED_BETS.get(storage, user_contest)
+}
+
+/// Checks a bet against the configured stake limits before anything is written.
+///
+/// # Arguments
+///
+/// * `storage` - Storage holding the limits, bets and contest pools.
+/// * `now` - The current block time in seconds.
+/// * `user` - The address of the user placing the bet.
+/// * `contest_id` - The ID of the contest the bet is placed on.
+/// * `currency` - The currency the bet is placed in.
+/// * `amount` - The amount being added to the user's stake.
+///
+/// # Returns
+///
+/// An error naming the limit and the total the bet would have reached.
+pub fn assert_bet_within_limits(
+    storage: &dyn Storage,
+    now: u64,
+    user: &Addr,
+    contest_id: &ContestId,
+    currency: &Currency,
+    amount: &Uint128,
+) -> Result<(), BetError> {
+    // Limits are set per currency, as stakes in different tokens do not compare
+    let limits = get_bet_limits_in(storage, currency)?;
+
+    if let Some(limit) = limits.max_user_bet() {
+        let user_contest = UserContest::new(user.clone(), contest_id.clone());
+        let current_stake = Bet::keymap_get_by_id(storage, &user_contest)
+            .map(|bet| *bet.get_amount())
+            .unwrap_or_default();
+        let attempted = current_stake + amount;
+        if &attempted > limit {
+            return Err(BetError::UserBetLimitExceeded {
+                limit: *limit,
+                attempted,
+            });
+        }
+    }
+
+    if let Some(limit) = limits.max_pool() {
+        let current_pool = ContestBetSummary::keymap_get_by_id(storage, contest_id)
+            .map(|contest_bet_summary| contest_bet_summary.calc_total_pool())
+            .unwrap_or_default();
+        let attempted = current_pool + amount;
+        if &attempted > limit {
+            return Err(BetError::PoolLimitExceeded {
+                limit: *limit,
+                attempted,
+            });
+        }
+    }
+
+    if let Some(limit) = limits.max_user_exposure() {
+        let attempted = get_open_exposure(storage, now, user, currency)? + amount;
+        if &attempted > limit {
+            return Err(BetError::ExposureLimitExceeded {
+                limit: *limit,
+                attempted,
+            });
+        }
+    }
+    Ok(())
+}
+
+// Total stake a user holds in one currency on contests that have not reached their time of resolve
+pub fn get_open_exposure(
+    storage: &dyn Storage,
+    now: u64,
+    user: &Addr,
+    currency: &Currency,
+) -> Result<Uint128, BetError> {
+    let mut exposure = Uint128::zero();
+    for position in get_users_open_positions(user).iter(storage)? {
+        let (contest_id, position) = position?;
+        if !position.is_open(now) || position.currency() != currency {
+            continue;
+        }
+        let user_contest = UserContest::new(user.clone(), contest_id);
+        if is_bet_cancelled(storage, &user_contest) {
+            continue;
+        }
+        if let Some(bet) = Bet::keymap_get_by_id(storage, &user_contest) {
+            exposure += bet.get_amount();
+        }
+    }
+    Ok(exposure)
+}
+
+/// Records that a user holds a stake on a contest, dropping positions that have since resolved.
+///
+/// # Arguments
+///
+/// * `storage` - Storage holding the user's open positions.
+/// * `now` - The current block time in seconds.
+/// * `user` - The address of the user who placed the bet.
+/// * `contest_info` - The contest the bet was placed on.
+/// * `currency` - The currency the contest is held in.
+pub fn add_open_position(
+    storage: &mut dyn Storage,
+    now: u64,
+    user: &Addr,
+    contest_info: &ContestInfo,
+    currency: &Currency,
+) -> Result<(), BetError> {
+    let open_positions = get_users_open_positions(user);
+    let resolved: Vec<ContestId> = open_positions
+        .iter(storage)?
+        .filter_map(|position| position.ok())
+        .filter(|(_, position)| !position.is_open(now))
+        .map(|(contest_id, _)| contest_id)
+        .collect();
+    for contest_id in resolved.iter() {
+        open_positions.remove(storage, contest_id)?;
+    }
+
+    open_positions.insert(
+        storage,
+        &contest_info.get_id(),
+        &OpenPosition::new(contest_info.get_time_of_resolve(), currency.clone()),
+    )?;
+    Ok(())
+}
+
+pub fn is_bet_cancelled(storage: &dyn Storage, user_contest: &UserContest) -> bool {
+    CANCELLED_BETS.contains(storage, user_contest)
+}
+
+pub fn assert_not_cancelled(
+    storage: &dyn Storage,
+    user_contest: &UserContest,
+) -> Result<(), BetError> {
+    if is_bet_cancelled(storage, user_contest) {
+        Err(BetError::BetCancelled {
+            user_contest: user_contest.clone(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
 pub fn get_bets_for_user_and_contests(
     storage: &dyn Storage,
     user: &Addr,
@@ -104,6 +344,7 @@ pub fn user_claims_bet(
     contest_bet_summary: &ContestBetSummary,
 ) -> Result<Uint128, BetError> {
     let user_contest = UserContest::new(user.clone(), contest_bet_summary.id());
+    assert_not_cancelled(storage, &user_contest)?;
     let bet_option = Bet::keymap_get_by_id(storage, &user_contest);
     match bet_option {
         Some(mut bet) => {
@@ -139,21 +380,160 @@ pub fn get_user_bet(storage: &dyn Storage, user_contest: UserContest) -> Result<
 }
 
 pub fn map_to_user_contest_bet_infos(
+    storage: &dyn Storage,
     filtered_results: Vec<(ContestInfo, ContestBetSummary, Bet)>,
 ) -> Vec<UserContestBetInfo> {
     let contests_bets: Vec<UserContestBetInfo> = filtered_results
         .into_iter()
-        .map(
-            |(contest_info, contest_bet_summary, user_bet)| UserContestBetInfo {
+        .map(|(contest_info, contest_bet_summary, user_bet)| {
+            let cancellation = get_bet_cancellation(
+                storage,
+                &UserContest::new(
+                    user_bet.get_user().clone(),
+                    user_bet.get_contest_id().clone(),
+                ),
+            );
+            UserContestBetInfo {
                 contest_info,
                 contest_bet_summary,
                 user_bet,
-            },
-        )
+                cancellation,
+            }
+        })
         .collect();
     contests_bets
 }
 
+/// Joins each contest a user has bet on with its bet summary and the user's bet, setting
+/// known results on the summaries. Contests missing any of the three records are skipped.
+pub fn get_user_contest_bets(
+    deps: &Deps,
+    price_source: &dyn PriceSource,
+    env: &Env,
+    user: &Addr,
+    contest_ids: &Vec<ContestId>,
+) -> Vec<(ContestInfo, ContestBetSummary, Bet)> {
+    let mut contest_infos: Vec<ContestInfo> = vec![];
+    let mut contest_bet_summaries: Vec<ContestBetSummary> = vec![];
+    let mut bets: Vec<Bet> = vec![];
+
+    for contest_id in contest_ids {
+        let user_contest = UserContest::new(user.clone(), contest_id.clone());
+        match (
+            ContestInfo::keymap_get_by_id(deps.storage, contest_id),
+            ContestBetSummary::keymap_get_by_id(deps.storage, contest_id),
+            Bet::keymap_get_by_id(deps.storage, &user_contest),
+        ) {
+            (Some(contest_info), Some(contest_bet_summary), Some(bet)) => {
+                contest_infos.push(contest_info);
+                contest_bet_summaries.push(contest_bet_summary);
+                bets.push(bet);
+            }
+            _ => continue,
+        }
+    }
+
+    update_contest_bet_summaries_with_results(
+        deps.storage,
+        &deps.querier,
+        price_source,
+        env,
+        &contest_infos,
+        &mut contest_bet_summaries,
+    );
+
+    contest_infos
+        .into_iter()
+        .zip(contest_bet_summaries.into_iter())
+        .zip(bets.into_iter())
+        .map(|((contest_info, contest_bet_summary), bet)| (contest_info, contest_bet_summary, bet))
+        .collect()
+}
+
+pub fn get_user_bet_status(
+    storage: &dyn Storage,
+    now: u64,
+    contest_info: &ContestInfo,
+    contest_bet_summary: &ContestBetSummary,
+    bet: &Bet,
+) -> UserBetStatus {
+    let user_contest = UserContest::new(bet.get_user().clone(), bet.get_contest_id().clone());
+    if is_bet_cancelled(storage, &user_contest) {
+        return UserBetStatus::Refunded;
+    }
+    if bet.has_been_paid() {
+        return UserBetStatus::Claimed;
+    }
+    // One sided contests show as nullified before they are due to resolve
+    if now < contest_info.get_time_of_resolve() {
+        return UserBetStatus::Pending;
+    }
+    match contest_bet_summary.get_outcome() {
+        Some(outcome) if outcome.get_id() == &NULL_AND_VOID_CONTEST_RESULT => {
+            UserBetStatus::Refunded
+        }
+        Some(outcome) if outcome.get_id() == bet.get_outcome_id() => UserBetStatus::Won,
+        Some(_) => UserBetStatus::Lost,
+        None => UserBetStatus::Pending,
+    }
+}
+
+/// Lists a user's bets newest first, walking the user's contest list from a cursor.
+///
+/// # Arguments
+///
+/// * `deps` - Dependencies for reading storage and querying the price feed.
+/// * `price_source` - Where contest prices are looked up.
+/// * `env` - The environment, for the current block time.
+/// * `user` - The address of the user whose bets are listed.
+/// * `filter` - Which bets to include.
+/// * `start_after` - The cursor returned with the previous page, if any.
+/// * `limit` - The most bets to return.
+///
+/// # Returns
+///
+/// The page of bets, and the cursor for the next page if the list was not exhausted.
+pub fn get_user_bets_page(
+    deps: &Deps,
+    price_source: &dyn PriceSource,
+    env: &Env,
+    user: &Addr,
+    filter: &UserBetFilter,
+    start_after: Option<u32>,
+    limit: u32,
+) -> StdResult<(Vec<(ContestInfo, ContestBetSummary, Bet)>, Option<u32>)> {
+    let user_map = get_users_contest_map(user);
+    let map_length = user_map.get_len(deps.storage)?;
+    let limit = limit.min(MAX_PAGE_SIZE) as usize;
+    let now = env.block.time.seconds();
+
+    let mut contests_bets = vec![];
+    let mut last_index = None;
+    let mut scanned = 0;
+    for index in (0..start_after.unwrap_or(map_length).min(map_length)).rev() {
+        if contests_bets.len() >= limit || scanned >= MAX_CONTESTS_SCANNED {
+            return Ok((contests_bets, last_index));
+        }
+        scanned += 1;
+        last_index = Some(index);
+
+        let contest_id = match user_map.get(deps.storage, &index) {
+            Some(contest_id) => contest_id,
+            None => continue,
+        };
+        for (contest_info, contest_bet_summary, bet) in
+            get_user_contest_bets(deps, price_source, env, user, &vec![contest_id])
+        {
+            let status =
+                get_user_bet_status(deps.storage, now, &contest_info, &contest_bet_summary, &bet);
+            if filter.matches(&contest_info, status) {
+                contests_bets.push((contest_info, contest_bet_summary, bet));
+            }
+        }
+    }
+    Ok((contests_bets, None))
+}
+
 pub fn assert_not_paid(bet: &Bet) -> Result<(), BetError> {
     if bet.has_been_paid() {
         Err(BetError::BetAlreadyPaid)
@@ -205,16 +585,27 @@ pub fn calculate_user_share(
 
 pub fn get_users_map_bets(
     deps: Deps,
+    price_source: &dyn PriceSource,
     env: Env,
     user: Addr,
 ) -> Result<Vec<(ContestInfo, ContestBetSummary, Bet)>, StdError> {
-    let users_contest_ids = get_unchecked_contests_for_user(deps.storage, &user)?;
+    // Cancelled bets have already been refunded so they are never claimable
+    let users_contest_ids: Vec<ContestId> = get_unchecked_contests_for_user(deps.storage, &user)?
+        .into_iter()
+        .filter(|contest_id| {
+            !is_bet_cancelled(
+                deps.storage,
+                &UserContest::new(user.clone(), contest_id.clone()),
+            )
+        })
+        .collect();
     let users_contest_infos = get_contest_infos_for_ids(deps.storage, &users_contest_ids)?;
     let mut users_contest_bet_summaries =
         get_contest_bet_summaries(deps.storage, &users_contest_ids)?;
     update_contest_bet_summaries_with_results(
         deps.storage,
         &deps.querier,
+        price_source,
         &env,
         &users_contest_infos,
         &mut users_contest_bet_summaries,
@@ -261,13 +652,37 @@ fn filter_claimable(
         .collect()
 }
 
-// Getter function to retrieve the total volume
+// Getter function to retrieve the total volume of SNIP-20 contests
 pub fn get_total_volume(storage: &dyn Storage) -> Uint128 {
     // Load the TOTAL_VOLUME from storage and return it
     let total_volume = TOTAL_VOLUME.load(storage).unwrap_or(Uint128::zero());
     total_volume
 }
 
+// Volume is only ever summed within one currency, amounts in different tokens do not add up
+pub fn get_total_volume_in(storage: &dyn Storage, currency: &Currency) -> Uint128 {
+    match currency {
+        Currency::Snip20 => get_total_volume(storage),
+        _ => CURRENCY_VOLUMES
+            .get(storage, currency)
+            .unwrap_or(Uint128::zero()),
+    }
+}
+
+fn set_total_volume_in(storage: &mut dyn Storage, currency: &Currency, total_volume: &Uint128) {
+    match currency {
+        Currency::Snip20 => TOTAL_VOLUME.save(storage, total_volume).unwrap(),
+        _ => CURRENCY_VOLUMES
+            .insert(storage, currency, total_volume)
+            .unwrap(),
+    }
+}
+
+// The volume of every currency other than the SNIP-20 token that has taken a bet
+pub fn get_currency_volumes(storage: &dyn Storage) -> StdResult<Vec<(Currency, Uint128)>> {
+    CURRENCY_VOLUMES.iter(storage)?.collect()
+}
+
 // Getter function to retrieve the total bets
 pub fn get_total_bets(storage: &dyn Storage) -> u64 {
     // Load the TOTAL_BETS from storage and return it
@@ -276,15 +691,33 @@ pub fn get_total_bets(storage: &dyn Storage) -> u64 {
 }
 
 // Update function for total volume
-pub fn update_total_volume(storage: &mut dyn Storage, amount: &Uint128) {
-    // Load the current TOTAL_VOLUME
-    let current_total_volume: Uint128 = TOTAL_VOLUME.load(storage).unwrap_or(Uint128::zero());
+pub fn update_total_volume(storage: &mut dyn Storage, currency: &Currency, amount: &Uint128) {
+    // Load the current total volume in the bet's currency
+    let current_total_volume = get_total_volume_in(storage, currency);
 
     // Add the command amount to the current total volume
     let updated_total_volume = current_total_volume + amount;
 
-    // Store the updated total volume back into TOTAL_VOLUME
-    TOTAL_VOLUME.save(storage, &updated_total_volume).unwrap()
+    // Store the updated total volume back under the same currency
+    set_total_volume_in(storage, currency, &updated_total_volume)
+}
+
+// Update function for total volume
+pub fn decrease_total_volume(storage: &mut dyn Storage, currency: &Currency, amount: &Uint128) {
+    let current_total_volume = get_total_volume_in(storage, currency);
+    set_total_volume_in(
+        storage,
+        currency,
+        &current_total_volume.saturating_sub(*amount),
+    )
+}
+
+// Update function for total bets
+pub fn decrement_total_bets(storage: &mut dyn Storage) {
+    let current_total_bets = TOTAL_BETS.load(storage).unwrap_or(0);
+    TOTAL_BETS
+        .save(storage, &current_total_bets.saturating_sub(1))
+        .unwrap()
 }
 
 // Update function for total bets
@@ -1,19 +1,25 @@
-use cosmwasm_std::{DepsMut, Env, QuerierWrapper, StdResult, Storage, Uint128};
+use cosmwasm_std::{Decimal, DepsMut, Env, QuerierWrapper, StdResult, Storage, Uint128};
+use sp_secret_toolkit::macros::identifiable::Identifiable;
 
 use crate::{
-    constants::EXPIRATION_WINDOW,
     data::{
         contest_bet_summary::ContestBetSummary,
         contest_info::{ContestId, ContestInfo, ContestOutcome},
-        state::State,
+        contest_resolution::{ManualResolution, MANUAL_RESOLUTIONS},
+        state::{ContractStatus, FeePercent, State},
     },
     error::contest_bet_summary_error::ContestBetSummaryError,
 };
 
 use super::{
-    contest_info_service::{assert_contest_ready_to_be_claimed, get_contest_result},
-    integrations::price_feed_service::pricefeed::{query_prices, NULL_AND_VOID_CONTEST_RESULT},
-    state_service::add_claimable_fee_for_pool,
+    contest_info_service::{
+        assert_contest_closed, assert_contest_ready_to_be_claimed, get_contest_info,
+        get_contest_outcome_bands, get_contest_price_feed, get_contest_result,
+        get_expiration_window_start, get_outcome_from_prices,
+    },
+    currency_service::{add_claimable_fees_in, get_claimable_fees_in, set_claimable_fees_in},
+    integrations::price_feed_service::{PriceSource, NULL_AND_VOID_CONTEST_RESULT},
+    state_service::get_contract_status,
 }; // Make sure to adjust the import based on your actual storage handling
 
 /// Adds a bet to a contest summary.
@@ -49,11 +55,67 @@ pub fn add_bet_to_contest_summary(
     Ok(())
 }
 
+/// Takes a cancelled bet back out of a contest summary.
+///
+/// # Arguments
+///
+/// * `storage` - Storage holding the contest bet summary.
+/// * `contest_id` - The ID of the contest the bet was placed on.
+/// * `outcome_id` - The ID of the outcome the bet was placed on.
+/// * `amount` - The full amount of the cancelled bet.
+///
+/// # Returns
+///
+/// A result indicating success or failure.
+pub fn remove_bet_from_contest_summary(
+    storage: &mut dyn Storage,
+    contest_id: &ContestId,
+    outcome_id: &u8,
+    amount: &Uint128,
+) -> Result<(), ContestBetSummaryError> {
+    let mut contest_bet_summary = ContestBetSummary::keymap_get_by_id(storage, contest_id)
+        .ok_or(ContestBetSummaryError::DNE(contest_id.clone()))?;
+    contest_bet_summary.remove_bet_from_option(outcome_id, amount)?;
+    contest_bet_summary.keymap_save(storage)?;
+    Ok(())
+}
+
+/// Moves a bet's stake between outcomes of a contest summary.
+///
+/// # Arguments
+///
+/// * `storage` - Storage holding the contest bet summary.
+/// * `contest_id` - The ID of the contest the bet was placed on.
+/// * `from_outcome_id` - The outcome the stake is taken off.
+/// * `to_outcome_id` - The outcome the stake is moved to.
+/// * `stake` - The full stake before the switch.
+/// * `fee` - The part of the stake kept as a switching fee.
+///
+/// # Returns
+///
+/// A result indicating success or failure.
+pub fn switch_bet_in_contest_summary(
+    storage: &mut dyn Storage,
+    contest_id: &ContestId,
+    from_outcome_id: &u8,
+    to_outcome_id: &u8,
+    stake: &Uint128,
+    fee: &Uint128,
+) -> Result<(), ContestBetSummaryError> {
+    let mut contest_bet_summary = ContestBetSummary::keymap_get_by_id(storage, contest_id)
+        .ok_or(ContestBetSummaryError::DNE(contest_id.clone()))?;
+    contest_bet_summary.remove_bet_from_option(from_outcome_id, stake)?;
+    contest_bet_summary.add_bet_to_option(to_outcome_id, &(*stake - *fee))?;
+    contest_bet_summary.keymap_save(storage)?;
+    Ok(())
+}
+
 /// Creates a new ContestBetSummary and saves it to storage.
 ///
 /// # Arguments
 ///
 /// * `deps` - A mutable reference to the dependencies, including storage access.
+/// * `env` - The environment, used to apply any scheduled fee change that is due.
 /// * `contest_info` - Information about the contest to create a summary for. Borrowed parameter.
 ///
 /// # Returns
@@ -61,9 +123,14 @@ pub fn add_bet_to_contest_summary(
 /// Result indicating the operation's success or failure.
 pub fn create_new_contest_bet_summary(
     storage: &mut dyn Storage,
+    env: &Env,
     contest_info: &ContestInfo, // Borrowing contest_info
 ) -> StdResult<()> {
-    let state = State::singleton_load(storage).unwrap();
+    let mut state = State::singleton_load(storage).unwrap();
+    // A scheduled fee change only reaches contests created after it is due
+    if state.apply_pending_fee_change(env.block.time.seconds()) {
+        state.singleton_save(storage)?;
+    }
     let fee = state.fee_percent();
 
     // Create a new ContestBetSummary instance
@@ -78,6 +145,7 @@ pub fn create_new_contest_bet_summary(
 pub fn finalize_contest_outcome(
     deps: &mut DepsMut,
     env: &Env,
+    price_source: &dyn PriceSource,
     contest_info: &ContestInfo,
 ) -> Result<(ContestBetSummary, bool), ContestBetSummaryError> {
     // Attempt to retrieve the ContestBetSummary from storage.
@@ -95,13 +163,17 @@ pub fn finalize_contest_outcome(
         contest_info.get_time_of_close(),
         contest_info.get_time_of_resolve(),
     ];
-    let prices = query_prices(&deps.querier, deps.storage, &price_posting_ids);
-    let expiry = EXPIRATION_WINDOW + contest_info.get_time_of_close();
+    let price_feed = get_contest_price_feed(deps.storage, &contest_info.get_id())?;
+    let prices = price_source.query_prices(&deps.querier, &price_feed, &price_posting_ids);
+    let state = State::singleton_load(deps.storage)?;
+    let expiry = state.expiration_window_seconds() + get_expiration_window_start(contest_info);
     let result: Option<ContestOutcome>;
 
-    // if one side does not have a bet set to null and void
-    if bets_on_both_sides(contest_bet_summary.clone()) {
-        result = get_contest_result(env, &prices, &expiry);
+    // if fewer than two outcomes have a bet set to null and void
+    if bets_on_multiple_outcomes(&contest_bet_summary) {
+        let outcome_bands = get_contest_outcome_bands(deps.storage, &contest_info.get_id());
+        result = get_contest_result(env, &prices, &expiry, &outcome_bands)
+            .map(|outcome| nullify_if_unbacked(&contest_bet_summary, outcome));
     } else {
         result = Some(ContestOutcome::nullified_result())
     }
@@ -112,7 +184,7 @@ pub fn finalize_contest_outcome(
 
         contest_bet_summary.set_outcome(&outcome)?;
         if outcome.get_id() != &NULL_AND_VOID_CONTEST_RESULT {
-            take_contest_fees(deps.storage, contest_bet_summary.clone());
+            take_contest_fees(deps.storage, contest_bet_summary.clone())?;
         }
     } else {
         return Err(ContestBetSummaryError::OutcomeDNE);
@@ -124,6 +196,120 @@ pub fn finalize_contest_outcome(
     Ok((contest_bet_summary, true))
 }
 
+pub fn nullify_unresolved_contest(
+    storage: &mut dyn Storage,
+    env: &Env,
+    contest_id: &ContestId,
+) -> Result<ContestBetSummary, ContestBetSummaryError> {
+    let mut contest_bet_summary = get_contest_bet_summary(storage, contest_id)?;
+
+    // Resolved contests keep their outcome, no fees are taken on nullified contests.
+    // Contests still taking bets are left open in case the contract returns to normal.
+    if contest_bet_summary.get_outcome().is_none() {
+        let contest_info = get_contest_info(storage, contest_id)?;
+        assert_contest_closed(env, &contest_info)?;
+        contest_bet_summary.set_outcome(&ContestOutcome::nullified_result())?;
+        contest_bet_summary.keymap_save(storage)?;
+    }
+
+    Ok(contest_bet_summary)
+}
+
+/// Sets a contest's outcome from prices supplied by the owner, for when the price feed has
+/// failed to post them.
+///
+/// # Arguments
+///
+/// * `storage` - Storage holding the contest and its bet summary.
+/// * `env` - The environment, for the current block time.
+/// * `contest_id` - The ID of the contest to resolve.
+/// * `close_price` - The price at the contest's time of close.
+/// * `resolve_price` - The price at the contest's time of resolve.
+/// * `reason` - Why the contest was resolved manually, kept with the contest.
+///
+/// # Returns
+///
+/// The outcome the contest was resolved to.
+pub fn resolve_contest_manually(
+    storage: &mut dyn Storage,
+    env: &Env,
+    contest_id: &ContestId,
+    close_price: Decimal,
+    resolve_price: Decimal,
+    reason: String,
+) -> Result<ContestOutcome, ContestBetSummaryError> {
+    assert_contest_ready_to_be_claimed(storage, env, contest_id)?;
+    let mut contest_bet_summary = get_unresolved_contest_bet_summary(storage, contest_id)?;
+    assert_reason_given(&reason)?;
+
+    let outcome = if bets_on_multiple_outcomes(&contest_bet_summary) {
+        let outcome_bands = get_contest_outcome_bands(storage, contest_id);
+        let outcome = get_outcome_from_prices(&close_price, &resolve_price, &outcome_bands);
+        nullify_if_unbacked(&contest_bet_summary, outcome)
+    } else {
+        ContestOutcome::nullified_result()
+    };
+    contest_bet_summary.set_outcome(&outcome)?;
+    if outcome.get_id() != &NULL_AND_VOID_CONTEST_RESULT {
+        take_contest_fees(storage, contest_bet_summary.clone())?;
+    }
+    contest_bet_summary.keymap_save(storage)?;
+
+    let manual_resolution = ManualResolution::new(
+        reason,
+        env.block.time.seconds(),
+        Some(close_price),
+        Some(resolve_price),
+    );
+    MANUAL_RESOLUTIONS.insert(storage, contest_id, &manual_resolution)?;
+    Ok(outcome)
+}
+
+// Nullifies a closed contest that has no outcome yet so every bet on it is refunded
+pub fn void_contest(
+    storage: &mut dyn Storage,
+    env: &Env,
+    contest_id: &ContestId,
+    reason: String,
+) -> Result<(), ContestBetSummaryError> {
+    let contest_info = get_contest_info(storage, contest_id)?;
+    assert_contest_closed(env, &contest_info)?;
+    let mut contest_bet_summary = get_unresolved_contest_bet_summary(storage, contest_id)?;
+    assert_reason_given(&reason)?;
+
+    contest_bet_summary.set_outcome(&ContestOutcome::nullified_result())?;
+    contest_bet_summary.keymap_save(storage)?;
+
+    let manual_resolution = ManualResolution::new(reason, env.block.time.seconds(), None, None);
+    MANUAL_RESOLUTIONS.insert(storage, contest_id, &manual_resolution)?;
+    Ok(())
+}
+
+pub fn get_manual_resolution(
+    storage: &dyn Storage,
+    contest_id: &ContestId,
+) -> Option<ManualResolution> {
+    MANUAL_RESOLUTIONS.get(storage, contest_id)
+}
+
+fn get_unresolved_contest_bet_summary(
+    storage: &dyn Storage,
+    contest_id: &ContestId,
+) -> Result<ContestBetSummary, ContestBetSummaryError> {
+    let contest_bet_summary = get_contest_bet_summary(storage, contest_id)?;
+    if contest_bet_summary.get_outcome().is_some() {
+        return Err(ContestBetSummaryError::CannotResetOutcome);
+    }
+    Ok(contest_bet_summary)
+}
+
+fn assert_reason_given(reason: &String) -> Result<(), ContestBetSummaryError> {
+    if reason.trim().is_empty() {
+        return Err(ContestBetSummaryError::MissingReason);
+    }
+    Ok(())
+}
+
 pub fn get_contest_bet_summary(
     storage: &dyn Storage,
     contest_id: &ContestId,
@@ -133,6 +319,19 @@ pub fn get_contest_bet_summary(
     Ok(contest_bet_summary)
 }
 
+pub fn assert_contest_has_no_outcome(
+    storage: &dyn Storage,
+    contest_id: &ContestId,
+) -> Result<(), ContestBetSummaryError> {
+    let contest_bet_summary = get_contest_bet_summary(storage, contest_id)?;
+    if contest_bet_summary.get_outcome().is_some() {
+        return Err(ContestBetSummaryError::OutcomeAlreadySet(
+            contest_id.clone(),
+        ));
+    }
+    Ok(())
+}
+
 pub fn get_contest_bet_summaries_ignore_missing(
     storage: &dyn Storage,
     contest_ids: &Vec<ContestId>,
@@ -151,13 +350,18 @@ pub fn get_contest_bet_summaries_ignore_missing(
     return contest_bet_summaries;
 }
 
-pub fn bets_on_both_sides(contest_bet_summary: ContestBetSummary) -> bool {
-    if !(contest_bet_summary.get_allocation(1).unwrap() == Uint128::zero()
-        || contest_bet_summary.get_allocation(2).unwrap() == Uint128::zero())
-    {
-        return true;
-    } else {
-        return false;
+pub fn bets_on_multiple_outcomes(contest_bet_summary: &ContestBetSummary) -> bool {
+    contest_bet_summary.count_outcomes_with_stake() >= 2
+}
+
+// A winning outcome nobody bet on leaves no one to pay out, so the contest is refunded
+pub fn nullify_if_unbacked(
+    contest_bet_summary: &ContestBetSummary,
+    outcome: ContestOutcome,
+) -> ContestOutcome {
+    match contest_bet_summary.get_allocation(*outcome.get_id()) {
+        Ok(allocation) if !allocation.is_zero() => outcome,
+        _ => ContestOutcome::nullified_result(),
     }
 }
 
@@ -182,29 +386,38 @@ pub fn get_contest_bet_summaries(
 pub fn query_contest_result_oracle(
     storage: &dyn Storage,
     querier: &QuerierWrapper,
+    price_source: &dyn PriceSource,
     env: &Env,
     contest_info: &ContestInfo,
 ) -> Result<Option<ContestOutcome>, ContestBetSummaryError> {
     assert_contest_ready_to_be_claimed(storage, env, &contest_info.get_id())?;
-    let prices = query_prices(
+    let price_feed = get_contest_price_feed(storage, &contest_info.get_id())?;
+    let prices = price_source.query_prices(
         querier,
-        storage,
+        &price_feed,
         &vec![
             contest_info.get_time_of_close(),
             contest_info.get_time_of_resolve(),
         ],
     );
-    let expiry = EXPIRATION_WINDOW + contest_info.get_time_of_close();
-    Ok(get_contest_result(env, &prices, &expiry))
+    let state = State::singleton_load(storage)?;
+    let expiry = state.expiration_window_seconds() + get_expiration_window_start(contest_info);
+    let outcome_bands = get_contest_outcome_bands(storage, &contest_info.get_id());
+    Ok(get_contest_result(env, &prices, &expiry, &outcome_bands))
 }
 
 pub fn update_contest_bet_summaries_with_results(
     storage: &dyn Storage,
     querier: &QuerierWrapper,
+    price_source: &dyn PriceSource,
     env: &Env,
     contest_infos: &Vec<ContestInfo>, // Added vector of ContestInfos
     contest_bet_summaries: &mut Vec<ContestBetSummary>,
 ) -> Vec<ContestBetSummary> {
+    let fail_safe = get_contract_status(storage)
+        .map(|status| status == ContractStatus::FailSafe)
+        .unwrap_or(false);
+
     for (contest_info, contest_bet_summary) in
         contest_infos.iter().zip(contest_bet_summaries.iter_mut())
     {
@@ -212,13 +425,22 @@ pub fn update_contest_bet_summaries_with_results(
         if contest_bet_summary.get_outcome().is_some() {
             continue; // Skip if already set
         }
+        // Closed unresolved contests are refunded while the contract is in fail safe
+        if fail_safe {
+            if env.block.time.seconds() < contest_info.get_time_of_close() {
+                continue;
+            }
+            let _ = contest_bet_summary.set_outcome(&ContestOutcome::nullified_result());
+            continue;
+        }
         // Only check results if its not meant to be null from lack of participation
-        if bets_on_both_sides(contest_bet_summary.clone()) {
+        if bets_on_multiple_outcomes(contest_bet_summary) {
             // Attempt to get the oracle result for the specific contest_info
             if let Ok(Some(outcome)) =
-                query_contest_result_oracle(storage, querier, env, contest_info)
+                query_contest_result_oracle(storage, querier, price_source, env, contest_info)
             {
                 // Update the contest bet summary with the new outcome
+                let outcome = nullify_if_unbacked(contest_bet_summary, outcome);
                 let _ = contest_bet_summary.set_outcome(&outcome);
             }
         } else {
@@ -230,8 +452,35 @@ pub fn update_contest_bet_summaries_with_results(
     contest_bet_summaries.to_vec() // Return the updated summaries
 }
 
-pub fn take_contest_fees(storage: &mut dyn Storage, contest_bet_summary: ContestBetSummary) {
+pub fn take_contest_fees(
+    storage: &mut dyn Storage,
+    contest_bet_summary: ContestBetSummary,
+) -> StdResult<()> {
     let total_pool = contest_bet_summary.calc_total_pool();
     let fee = contest_bet_summary.get_fee();
-    add_claimable_fee_for_pool(storage, &total_pool, &fee);
+    // Fees are kept in the currency the pool was bet in
+    let currency = contest_bet_summary.id().currency().clone();
+    add_claimable_fees_in(storage, &currency, &fee.fee_on(&total_pool))
+}
+
+// Pays part of a newly resolved contest's fee to the keeper that resolved it, returns the reward
+pub fn take_keeper_reward(
+    storage: &mut dyn Storage,
+    contest_bet_summary: &ContestBetSummary,
+    keeper_reward_percent: &FeePercent,
+) -> StdResult<Uint128> {
+    match contest_bet_summary.get_outcome() {
+        Some(outcome) if outcome.get_id() != &NULL_AND_VOID_CONTEST_RESULT => {}
+        // No fee is taken on nullified contests so there is nothing to share
+        _ => return Ok(Uint128::zero()),
+    }
+    let fee = contest_bet_summary
+        .get_fee()
+        .fee_on(&contest_bet_summary.calc_total_pool());
+    let reward = keeper_reward_percent.fee_on(&fee);
+
+    let currency = contest_bet_summary.id().currency().clone();
+    let claimable_fees = get_claimable_fees_in(storage, &currency)?;
+    set_claimable_fees_in(storage, &currency, claimable_fees.checked_sub(reward)?)?;
+    Ok(reward)
 }
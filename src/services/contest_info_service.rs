@@ -1,16 +1,74 @@
 // contest_info_service.rs
-use cosmwasm_std::{DepsMut, Env, StdError, Storage};
-use sp_secret_toolkit::price_feed::response::response_types::prices_by_ids::PricesByIdsResponse;
+use cosmwasm_std::{ContractInfo, Decimal, DepsMut, Env, StdError, Storage};
+use sp_secret_toolkit::{
+    contract::contract::Contract,
+    price_feed::{response::response_types::prices_by_ids::PricesByIdsResponse, PriceFeed},
+};
 
-use crate::services::state_service::get_interval;
+use crate::services::ticker_service::{
+    get_ticker_interval, get_ticker_outcome_bands, get_ticker_price_feed, validate_outcome_bands,
+};
 use crate::{
-    constants::{BEAR, BULL, SECONDS_IN_A_MINUTE, TICKERS},
-    data::contest_info::{ContestId, ContestInfo, ContestOutcome},
+    constants::{BEAR, BULL, SECONDS_IN_A_MINUTE},
+    data::{
+        contest_config::{ContestConfig, CONTEST_CONFIGS},
+        contest_info::{ContestId, ContestInfo, ContestOutcome},
+        currency::Currency,
+        outcome_band::OutcomeBand,
+    },
     error::contest_info_error::ContestInfoError,
 };
 pub fn create_new_contest(
     deps: &mut DepsMut,
     contest_info: &ContestInfo,
+) -> Result<(), ContestInfoError> {
+    let outcome_bands = get_ticker_outcome_bands(deps.storage, &contest_info.get_ticker());
+    save_new_contest(deps, contest_info, outcome_bands)
+}
+
+/// Creates a one-off contest with its own close and resolve times, outside the ticker's
+/// regular interval. Each option is an outcome band settled on the price change between
+/// time of close and time of resolve.
+pub fn create_custom_contest(
+    deps: &mut DepsMut,
+    env: &Env,
+    ticker: &String,
+    time_of_close: u64,
+    time_of_resolve: u64,
+    outcome_bands: Vec<OutcomeBand>,
+    currency: Currency,
+) -> Result<ContestInfo, ContestInfoError> {
+    let current_time = env.block.time.seconds();
+    if time_of_close <= current_time {
+        return Err(ContestInfoError::TimeOfCloseNotInFuture {
+            time_of_close,
+            current_time,
+        });
+    }
+    if time_of_resolve <= time_of_close {
+        return Err(ContestInfoError::TimeOfResolveNotAfterClose {
+            time_of_close,
+            time_of_resolve,
+        });
+    }
+    validate_outcome_bands(ticker, &outcome_bands)?;
+
+    let contest_info = ContestInfo::new(
+        ticker.clone(),
+        time_of_close,
+        time_of_resolve,
+        outcome_bands.iter().map(OutcomeBand::to_outcome).collect(),
+        currency,
+        true,
+    );
+    save_new_contest(deps, &contest_info, Some(outcome_bands))?;
+    Ok(contest_info)
+}
+
+fn save_new_contest(
+    deps: &mut DepsMut,
+    contest_info: &ContestInfo,
+    outcome_bands: Option<Vec<OutcomeBand>>,
 ) -> Result<(), ContestInfoError> {
     validate_contest(contest_info)?;
 
@@ -21,9 +79,49 @@ pub fn create_new_contest(
     }
 
     contest_info.keymap_save(deps.storage)?;
+
+    // Pin the price feed and outcome bands so the contest settles against what it was opened with
+    let price_feed = get_ticker_price_feed(deps.storage, &contest_info.get_ticker())?;
+    CONTEST_CONFIGS.insert(
+        deps.storage,
+        &contest_id,
+        &ContestConfig::new(price_feed, outcome_bands),
+    )?;
     Ok(())
 }
 
+pub fn get_contest_price_feed(
+    storage: &dyn Storage,
+    contest_id: &ContestId,
+) -> Result<ContractInfo, ContestInfoError> {
+    match CONTEST_CONFIGS.get(storage, contest_id) {
+        Some(contest_config) => Ok(contest_config.price_feed().to_owned()),
+        None => {
+            // Contests created before per ticker feeds settle against the global feed
+            let price_feed = PriceFeed::singleton_load(storage)?;
+            Ok(Contract::get_contract_info(&price_feed))
+        }
+    }
+}
+
+pub fn get_contest_outcome_bands(
+    storage: &dyn Storage,
+    contest_id: &ContestId,
+) -> Option<Vec<OutcomeBand>> {
+    CONTEST_CONFIGS
+        .get(storage, contest_id)
+        .and_then(|contest_config| contest_config.outcome_bands().to_owned())
+}
+
+// Interval contests expire a window after close, custom contests can resolve long after
+// they close so their window starts at resolve
+pub fn get_expiration_window_start(contest_info: &ContestInfo) -> u64 {
+    if contest_info.is_custom() {
+        return contest_info.get_time_of_resolve();
+    }
+    contest_info.get_time_of_close()
+}
+
 pub fn get_contest_info(
     storage: &dyn Storage,
     contest_id: &ContestId,
@@ -83,18 +181,50 @@ pub fn assert_contest_ready_to_be_claimed(
     Ok(contest_info)
 }
 
-pub fn assert_ticker_valid(ticker: &String) -> Result<(), ContestInfoError> {
-    if TICKERS.contains(&ticker.as_str()) {
-        Ok(())
-    } else {
-        Err(ContestInfoError::InvalidTicker)
+pub fn assert_contest_closed(
+    env: &Env,
+    contest_info: &ContestInfo,
+) -> Result<(), ContestInfoError> {
+    let current_time = env.block.time.seconds();
+    if current_time < contest_info.get_time_of_close() {
+        return Err(ContestInfoError::TimeOfCloseHasYetToPass {
+            contest_id: contest_info.get_id(),
+            time_of_close: contest_info.get_time_of_close(),
+            current_time,
+        });
     }
+    Ok(())
+}
+
+// Bets are only taken while strictly more than the cutoff remains before close
+fn is_inside_betting_cutoff(
+    current_seconds: u64,
+    time_of_close: u64,
+    betting_cutoff_seconds: u64,
+) -> bool {
+    time_of_close.saturating_sub(current_seconds) <= betting_cutoff_seconds
+}
+
+pub fn assert_contest_open(
+    env: &Env,
+    contest_info: &ContestInfo,
+    betting_cutoff_seconds: u64,
+) -> Result<(), ContestInfoError> {
+    if is_inside_betting_cutoff(
+        env.block.time.seconds(),
+        contest_info.get_time_of_close(),
+        betting_cutoff_seconds,
+    ) {
+        return Err(ContestInfoError::TimeOfClosePassed(contest_info.get_id()));
+    }
+    Ok(())
 }
 
 pub fn get_contest_result(
     env: &Env,
     prices: &Result<PricesByIdsResponse, StdError>,
     expiry: &u64,
+    outcome_bands: &Option<Vec<OutcomeBand>>,
 ) -> Option<ContestOutcome> {
     // Handle the case where prices contain an error
     let prices = match prices {
@@ -115,16 +245,37 @@ pub fn get_contest_result(
         }
         return None;
     }
+    Some(get_outcome_from_prices(
+        prices[0].price(),
+        prices[1].price(),
+        outcome_bands,
+    ))
+}
+
+// Works out which outcome a contest settles on from its close and resolve prices
+pub fn get_outcome_from_prices(
+    close_price: &Decimal,
+    resolve_price: &Decimal,
+    outcome_bands: &Option<Vec<OutcomeBand>>,
+) -> ContestOutcome {
+    // Banded contests are won by the band the price change lands in, gaps are null and void
+    if let Some(outcome_bands) = outcome_bands {
+        return match outcome_bands
+            .iter()
+            .find(|band| band.contains(close_price, resolve_price))
+        {
+            Some(band) => band.to_outcome(),
+            None => ContestOutcome::nullified_result(),
+        };
+    }
+
     // Compare the first and second price postings
-    if prices[0].price() < prices[1].price() {
-        Some(ContestOutcome::new(1, "First price is greater".to_string()))
-    } else if prices[0].price() > prices[1].price() {
-        Some(ContestOutcome::new(
-            2,
-            "Second price is greater".to_string(),
-        ))
+    if close_price < resolve_price {
+        ContestOutcome::new(1, "First price is greater".to_string())
+    } else if close_price > resolve_price {
+        ContestOutcome::new(2, "Second price is greater".to_string())
     } else {
-        Some(ContestOutcome::nullified_result())
+        ContestOutcome::nullified_result()
     }
 }
 
@@ -156,21 +307,27 @@ pub fn validate_contest(contest_info: &ContestInfo) -> Result<(), ContestInfoErr
     Ok(())
 }
 
-/// Calculates the Unix timestamp for the next 5-minute interval on the minute.
+/// Calculates the Unix timestamp for the next interval of the ticker on the minute.
 /// This function is used to determine when the next betting round should close,
 /// ensuring that all bets are placed within a specific time frame.
 ///
 /// # Arguments
 ///
 /// * `env` - The environment context provided by CosmWasm that includes time information.
+/// * `ticker` - The ticker whose interval is used, falling back to the global interval.
 ///
 /// # Returns
 ///
-/// Returns the Unix timestamp of the next 5-minute mark from the current block time.
-pub fn get_current_close(storage: &dyn Storage, env: &Env) -> u64 {
+/// Returns the Unix timestamp of the next interval mark from the current block time, or an
+/// error when the ticker's interval cannot be loaded.
+pub fn get_current_close(
+    storage: &dyn Storage,
+    env: &Env,
+    ticker: &String,
+) -> Result<u64, ContestInfoError> {
     // Retrieve the current time in seconds from the blockchain's environment.
     let current_seconds = env.block.time.seconds();
-    let interval = get_interval(storage).unwrap();
+    let interval = get_ticker_interval(storage, ticker)?;
 
     // Calculate the next multiple of the 5-minute interval from the current time.
     // This finds the smallest multiple of FIVE_MINUTE_INTERVAL that is greater than or equal to
@@ -180,21 +337,60 @@ pub fn get_current_close(storage: &dyn Storage, env: &Env) -> u64 {
 
     // Adjust the calculated time to the start of the 5-minute interval.
     // This removes any seconds past the start of the minute, setting the time exactly on the minute mark.
-    next_interval_seconds - (next_interval_seconds % SECONDS_IN_A_MINUTE)
+    Ok(next_interval_seconds - (next_interval_seconds % SECONDS_IN_A_MINUTE))
+}
+
+/// Finds the close time of the contest a bet placed now belongs to. Bets arriving within
+/// the betting cutoff of the current close are rolled over to the first close outside the
+/// cutoff when `roll_over` is set, and rejected otherwise.
+pub fn get_betting_close(
+    storage: &dyn Storage,
+    env: &Env,
+    ticker: &String,
+    currency: &Currency,
+    betting_cutoff_seconds: u64,
+    roll_over: bool,
+) -> Result<u64, ContestInfoError> {
+    let current_seconds = env.block.time.seconds();
+    let current_close = get_current_close(storage, env, ticker)?;
+    if !is_inside_betting_cutoff(current_seconds, current_close, betting_cutoff_seconds) {
+        return Ok(current_close);
+    }
+    if !roll_over {
+        return Err(ContestInfoError::TimeOfClosePassed(ContestId::new(
+            ticker.clone(),
+            current_close,
+            currency.clone(),
+            false,
+        )));
+    }
+
+    // Skip to the first close that is still outside the cutoff
+    let interval = get_ticker_interval(storage, ticker)?;
+    let shortfall = current_seconds + betting_cutoff_seconds - current_close;
+    Ok(current_close + (shortfall / interval + 1) * interval)
 }
 
 pub fn create_new_contest_info(
     storage: &dyn Storage,
     ticker: &String,
     current_close: &u64,
-) -> ContestInfo {
-    ContestInfo::new(
-        ticker.clone(),
-        *current_close,
-        *current_close + get_interval(storage).unwrap(),
-        vec![
+    currency: &Currency,
+) -> Result<ContestInfo, ContestInfoError> {
+    let options = match get_ticker_outcome_bands(storage, ticker) {
+        Some(outcome_bands) => outcome_bands.iter().map(OutcomeBand::to_outcome).collect(),
+        None => vec![
             ContestOutcome::new(1, BULL.to_string()),
             ContestOutcome::new(2, BEAR.to_string()),
         ],
-    )
+    };
+    let interval = get_ticker_interval(storage, ticker)?;
+    Ok(ContestInfo::new(
+        ticker.clone(),
+        *current_close,
+        *current_close + interval,
+        options,
+        currency.clone(),
+        false,
+    ))
 }
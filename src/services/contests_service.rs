@@ -1,14 +1,42 @@
-use cosmwasm_std::{Deps, Storage};
+use cosmwasm_std::{Deps, Env, StdResult, Storage};
 
 use crate::{
+    constants::{MAX_CONTESTS_SCANNED, MAX_PAGE_SIZE},
     data::{
-        contest_info::{ContestId, ContestInfo},
-        contests::{add_contest_id, get_all_contest_id_store},
+        contest_bet_summary::ContestBetSummary,
+        contest_info::{ContestId, ContestInfo, ContestStatus},
+        contests::{add_contest_id, get_all_contest_id_store, SortOrder},
     },
-    error::contest_activity_error::ContestActivityError,
+    error::{contest_activity_error::ContestActivityError, contest_info_error::ContestInfoError},
+    responses::query::response_types::times_to_resolve::PriceFeedTimes,
 };
 
-use super::integrations::price_feed_service::pricefeed::query_prices;
+use super::{
+    contest_bet_summary_service::update_contest_bet_summaries_with_results,
+    contest_info_service::{assert_contest_open, get_contest_price_feed},
+    integrations::price_feed_service::{PriceSource, NULL_AND_VOID_CONTEST_RESULT},
+    state_service::get_betting_cutoff_seconds,
+};
+
+// Narrows a contest listing, a filter left as none matches every contest
+pub struct ContestFilter {
+    pub ticker: Option<String>,
+    pub status: Option<ContestStatus>,
+    // Bounds on the time of close, both inclusive
+    pub from_time: Option<u64>,
+    pub to_time: Option<u64>,
+}
+
+impl ContestFilter {
+    fn matches_info(&self, contest_info: &ContestInfo) -> bool {
+        let time_of_close = contest_info.get_time_of_close();
+        self.ticker
+            .as_ref()
+            .map_or(true, |ticker| ticker == &contest_info.get_ticker())
+            && self.from_time.map_or(true, |from| time_of_close >= from)
+            && self.to_time.map_or(true, |to| time_of_close <= to)
+    }
+}
 
 pub fn add_active_contest(
     storage: &mut dyn Storage,
@@ -18,18 +46,38 @@ pub fn add_active_contest(
     Ok(())
 }
 
+/// Groups the price times still missing for each contest by the price feed the contest
+/// settles against, in the order the feeds are first seen.
 pub fn get_times_to_resolve_from_contest_infos(
     deps: &Deps,
+    price_source: &dyn PriceSource,
     contest_infos: Vec<ContestInfo>,
-) -> Vec<u64> {
-    let mut times = vec![];
+) -> Result<Vec<PriceFeedTimes>, ContestInfoError> {
+    let mut price_feeds: Vec<PriceFeedTimes> = vec![];
     let mut close: u64;
     let mut resolve: u64;
     for contest_info in contest_infos {
+        let price_feed = get_contest_price_feed(deps.storage, &contest_info.get_id())?;
+        let index = match price_feeds
+            .iter()
+            .position(|feed_times| feed_times.price_feed == price_feed)
+        {
+            Some(index) => index,
+            None => {
+                price_feeds.push(PriceFeedTimes {
+                    price_feed: price_feed.clone(),
+                    times: vec![],
+                });
+                price_feeds.len() - 1
+            }
+        };
+        let times = &mut price_feeds[index].times;
+
         close = contest_info.get_time_of_close();
         resolve = contest_info.get_time_of_resolve();
 
-        if query_prices(&deps.querier, deps.storage, &vec![close])
+        if price_source
+            .query_prices(&deps.querier, &price_feed, &vec![close])
             .unwrap()
             .prices
             .is_empty()
@@ -37,7 +85,8 @@ pub fn get_times_to_resolve_from_contest_infos(
         {
             times.push(close)
         }
-        if query_prices(&deps.querier, deps.storage, &vec![resolve])
+        if price_source
+            .query_prices(&deps.querier, &price_feed, &vec![resolve])
             .unwrap()
             .prices
             .is_empty()
@@ -47,7 +96,7 @@ pub fn get_times_to_resolve_from_contest_infos(
         }
     }
 
-    times
+    Ok(price_feeds)
 }
 
 pub fn get_last_ten_contest_ids(storage: &dyn Storage) -> Vec<ContestId> {
@@ -67,6 +116,128 @@ pub fn get_last_ten_contest_ids(storage: &dyn Storage) -> Vec<ContestId> {
     contest_ids
 }
 
+/// Works out a contest's status, setting its outcome on the summary once the result is known.
+///
+/// # Arguments
+///
+/// * `deps` - Dependencies for reading storage and querying the price feed.
+/// * `price_source` - Where contest prices are looked up.
+/// * `env` - The environment, for the current block time.
+/// * `betting_cutoff_seconds` - How long before close bets stop being taken.
+/// * `contest_info` - The contest to check.
+/// * `contest_bet_summary` - The contest's bet summary.
+///
+/// # Returns
+///
+/// The contest's status.
+pub fn get_contest_status(
+    deps: &Deps,
+    price_source: &dyn PriceSource,
+    env: &Env,
+    betting_cutoff_seconds: u64,
+    contest_info: &ContestInfo,
+    contest_bet_summary: &mut ContestBetSummary,
+) -> ContestStatus {
+    // Results are only looked up once the contest is due to resolve
+    if env.block.time.seconds() >= contest_info.get_time_of_resolve() {
+        let mut contest_bet_summaries = vec![contest_bet_summary.clone()];
+        update_contest_bet_summaries_with_results(
+            deps.storage,
+            &deps.querier,
+            price_source,
+            env,
+            &vec![contest_info.clone()],
+            &mut contest_bet_summaries,
+        );
+        *contest_bet_summary = contest_bet_summaries.remove(0);
+    }
+    match contest_bet_summary.get_outcome() {
+        Some(outcome) if outcome.get_id() == &NULL_AND_VOID_CONTEST_RESULT => {
+            ContestStatus::Nullified
+        }
+        Some(_) => ContestStatus::Resolved,
+        None if env.block.time.seconds() >= contest_info.get_time_of_resolve() => {
+            ContestStatus::AwaitingResult
+        }
+        None if assert_contest_open(env, contest_info, betting_cutoff_seconds).is_ok() => {
+            ContestStatus::Open
+        }
+        None => ContestStatus::Closed,
+    }
+}
+
+/// Lists contests matching a filter, walking the contest list from a cursor.
+///
+/// # Arguments
+///
+/// * `deps` - Dependencies for reading storage and querying the price feed.
+/// * `price_source` - Where contest prices are looked up.
+/// * `env` - The environment, for the current block time.
+/// * `filter` - Which contests to include.
+/// * `start_after` - The cursor returned with the previous page, if any.
+/// * `limit` - The most contests to return.
+/// * `order` - Whether to walk from the oldest or the newest contest.
+///
+/// # Returns
+///
+/// The matching contests and, when the list was not exhausted, the cursor to continue from.
+/// A page can hold fewer than `limit` contests when many are filtered out.
+pub fn get_contests_page(
+    deps: &Deps,
+    price_source: &dyn PriceSource,
+    env: &Env,
+    filter: &ContestFilter,
+    start_after: Option<u32>,
+    limit: u32,
+    order: SortOrder,
+) -> StdResult<(Vec<(ContestInfo, ContestBetSummary)>, Option<u32>)> {
+    let contest_store = get_all_contest_id_store();
+    let store_length = contest_store.get_len(deps.storage)?;
+    let betting_cutoff_seconds = get_betting_cutoff_seconds(deps.storage)?;
+    let limit = limit.min(MAX_PAGE_SIZE) as usize;
+
+    let indexes: Box<dyn Iterator<Item = u32>> = match order {
+        SortOrder::Ascending => Box::new(start_after.map_or(0, |index| index + 1)..store_length),
+        SortOrder::Descending => {
+            Box::new((0..start_after.unwrap_or(store_length).min(store_length)).rev())
+        }
+    };
+
+    let mut contests = vec![];
+    let mut last_index = None;
+    let mut scanned = 0;
+    for index in indexes {
+        if contests.len() >= limit || scanned >= MAX_CONTESTS_SCANNED {
+            return Ok((contests, last_index));
+        }
+        scanned += 1;
+        last_index = Some(index);
+
+        let contest_id = contest_store.get_at(deps.storage, index)?;
+        let contest_info = match ContestInfo::keymap_get_by_id(deps.storage, &contest_id) {
+            Some(contest_info) if filter.matches_info(&contest_info) => contest_info,
+            _ => continue,
+        };
+        let mut contest_bet_summary =
+            match ContestBetSummary::keymap_get_by_id(deps.storage, &contest_id) {
+                Some(contest_bet_summary) => contest_bet_summary,
+                None => continue,
+            };
+        let status = get_contest_status(
+            deps,
+            price_source,
+            env,
+            betting_cutoff_seconds,
+            &contest_info,
+            &mut contest_bet_summary,
+        );
+        if filter.status.map_or(true, |wanted| wanted == status) {
+            contests.push((contest_info, contest_bet_summary));
+        }
+    }
+    Ok((contests, None))
+}
+
 pub fn get_total_number_of_contests(storage: &dyn Storage) -> u32 {
     let contests = get_all_contest_id_store();
     contests.get_len(storage).unwrap()
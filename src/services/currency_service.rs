@@ -0,0 +1,374 @@
+use cosmwasm_std::{
+    Addr, BankMsg, Coin, CosmosMsg, Deps, Env, StdResult, Storage, SubMsg, Uint128,
+};
+use sp_secret_toolkit::{contract::contract::Contract, snip20::Snip20};
+
+use crate::{
+    constants::SET_VIEWING_KEY_REPLY_ID,
+    data::{
+        accepted_tokens::{AcceptedToken, ACCEPTED_TOKENS, PENDING_VIEWING_KEY_TOKEN},
+        contest_info::ContestId,
+        currency::{Currency, NATIVE_CLAIMABLE_FEES},
+        state::{BetLimits, State},
+    },
+    error::{currency_error::CurrencyError, state_error::StateError},
+};
+
+use super::state_service::get_snip20;
+
+pub fn get_native_denom(storage: &dyn Storage) -> StdResult<Option<String>> {
+    let state = State::singleton_load(storage)?;
+    Ok(state.native_denom().clone())
+}
+
+pub fn assert_contest_currency(
+    contest_id: &ContestId,
+    currency: &Currency,
+) -> Result<(), CurrencyError> {
+    if contest_id.currency() != currency {
+        return Err(CurrencyError::CurrencyMismatch {
+            contest_id: contest_id.clone(),
+            actual: currency.clone(),
+        });
+    }
+    Ok(())
+}
+
+/// Reads the amount bet from the funds sent with a native bet.
+///
+/// # Arguments
+///
+/// * `storage` - Storage holding the configured native denom.
+/// * `funds` - The coins sent with the message.
+///
+/// # Returns
+///
+/// The native currency and the amount sent in it.
+pub fn assert_native_funds(
+    storage: &dyn Storage,
+    funds: &[Coin],
+) -> Result<(Currency, Uint128), CurrencyError> {
+    let denom = get_native_denom(storage)?.ok_or(CurrencyError::NativeBetsDisabled)?;
+    match funds {
+        [coin] if coin.denom == denom && !coin.amount.is_zero() => {
+            Ok((Currency::Native { denom }, coin.amount))
+        }
+        _ => Err(CurrencyError::InvalidNativeFunds(denom)),
+    }
+}
+
+pub fn get_accepted_token(
+    storage: &dyn Storage,
+    address: &String,
+) -> Result<AcceptedToken, CurrencyError> {
+    ACCEPTED_TOKENS
+        .get(storage, address)
+        .ok_or(CurrencyError::TokenNotAccepted(address.clone()))
+}
+
+pub fn get_accepted_tokens(storage: &dyn Storage) -> StdResult<Vec<(String, AcceptedToken)>> {
+    ACCEPTED_TOKENS.iter(storage)?.collect()
+}
+
+/// Accepts bets in a SNIP-20 token, or opens a removed token to bets again.
+///
+/// # Arguments
+///
+/// * `storage` - Storage holding the accepted tokens.
+/// * `snip20` - The token, with the viewing key the contract will set on it.
+/// * `minimum_bet` - The smallest bet taken in the token.
+///
+/// # Returns
+///
+/// True when the token is newly registered and still needs its receive hook and viewing key set.
+pub fn add_accepted_token(
+    storage: &mut dyn Storage,
+    snip20: Snip20,
+    minimum_bet: Uint128,
+) -> Result<bool, CurrencyError> {
+    let address = snip20.get_contract_info().address.into_string();
+    if Contract::assert_address(&get_snip20(storage)?, Addr::unchecked(&address)).is_ok() {
+        return Err(CurrencyError::TokenAlreadyAccepted(address));
+    }
+    match ACCEPTED_TOKENS.get(storage, &address) {
+        Some(token) if *token.accepting_bets() => Err(CurrencyError::TokenAlreadyAccepted(address)),
+        Some(mut token) => {
+            token.set_accepting_bets(true);
+            token.set_minimum_bet(minimum_bet);
+            ACCEPTED_TOKENS.insert(storage, &address, &token)?;
+            Ok(false)
+        }
+        None => {
+            ACCEPTED_TOKENS.insert(storage, &address, &AcceptedToken::new(snip20, minimum_bet))?;
+            Ok(true)
+        }
+    }
+}
+
+/// Sends SetViewingKey to an accepted token, the reply records whether it was set.
+///
+/// # Arguments
+///
+/// * `storage` - Storage holding the accepted tokens.
+/// * `address` - The address of the accepted token.
+///
+/// # Returns
+///
+/// The submessage to send, or none if the token already has its viewing key.
+pub fn create_set_viewing_key_submsg(
+    storage: &mut dyn Storage,
+    address: &String,
+) -> Result<Option<SubMsg>, CurrencyError> {
+    let token = get_accepted_token(storage, address)?;
+    if *token.viewing_key_set() {
+        return Ok(None);
+    }
+    PENDING_VIEWING_KEY_TOKEN.save(storage, address)?;
+    let msg = token.snip20().create_set_view_key_msg()?;
+    Ok(Some(SubMsg::reply_always(msg, SET_VIEWING_KEY_REPLY_ID)))
+}
+
+// Records the result of the outstanding SetViewingKey, returns the token it was sent to
+pub fn set_viewing_key_result(
+    storage: &mut dyn Storage,
+    viewing_key_set: bool,
+) -> Result<String, CurrencyError> {
+    let address = PENDING_VIEWING_KEY_TOKEN.load(storage)?;
+    PENDING_VIEWING_KEY_TOKEN.remove(storage);
+    let mut token = get_accepted_token(storage, &address)?;
+    token.set_viewing_key_set(viewing_key_set);
+    ACCEPTED_TOKENS.insert(storage, &address, &token)?;
+    Ok(address)
+}
+
+pub fn remove_accepted_token(
+    storage: &mut dyn Storage,
+    address: &String,
+) -> Result<(), CurrencyError> {
+    let mut token = get_accepted_token(storage, address)?;
+    if !token.accepting_bets() {
+        return Err(CurrencyError::TokenNotAccepted(address.clone()));
+    }
+    token.set_accepting_bets(false);
+    ACCEPTED_TOKENS.insert(storage, address, &token)?;
+    Ok(())
+}
+
+// Maps the SNIP-20 contract calling Receive to the currency of the bet it carries
+pub fn get_received_currency(
+    storage: &dyn Storage,
+    sender: &Addr,
+) -> Result<Currency, CurrencyError> {
+    if Contract::assert_address(&get_snip20(storage)?, sender.clone()).is_ok() {
+        return Ok(Currency::Snip20);
+    }
+    let address = sender.to_string();
+    match ACCEPTED_TOKENS.get(storage, &address) {
+        Some(token) if *token.accepting_bets() => Ok(Currency::Token { address }),
+        _ => Err(CurrencyError::TokenNotAccepted(address)),
+    }
+}
+
+pub fn get_minimum_bet_in(
+    storage: &dyn Storage,
+    currency: &Currency,
+) -> Result<Uint128, CurrencyError> {
+    match currency {
+        Currency::Snip20 => {
+            let state = State::singleton_load(storage)?;
+            Ok(*state.minimum_bet())
+        }
+        Currency::Native { .. } => {
+            let state = State::singleton_load(storage)?;
+            Ok(*state.native_minimum_bet())
+        }
+        Currency::Token { address } => Ok(*get_accepted_token(storage, address)?.minimum_bet()),
+    }
+}
+
+pub fn set_minimum_bet_in(
+    storage: &mut dyn Storage,
+    currency: &Currency,
+    amount: Uint128,
+) -> Result<(), CurrencyError> {
+    match currency {
+        Currency::Snip20 => {
+            let mut state = State::singleton_load(storage)?;
+            state.set_minimum_bet(amount);
+            state.singleton_save(storage)?;
+        }
+        Currency::Native { .. } => {
+            let mut state = State::singleton_load(storage)?;
+            state.set_native_minimum_bet(amount);
+            state.singleton_save(storage)?;
+        }
+        Currency::Token { address } => {
+            let mut token = get_accepted_token(storage, address)?;
+            token.set_minimum_bet(amount);
+            ACCEPTED_TOKENS.insert(storage, address, &token)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn get_bet_limits_in(
+    storage: &dyn Storage,
+    currency: &Currency,
+) -> Result<BetLimits, CurrencyError> {
+    match currency {
+        Currency::Snip20 => {
+            let state = State::singleton_load(storage)?;
+            Ok(state.bet_limits().clone())
+        }
+        Currency::Native { .. } => {
+            let state = State::singleton_load(storage)?;
+            Ok(state.native_bet_limits().clone())
+        }
+        Currency::Token { address } => {
+            Ok(get_accepted_token(storage, address)?.bet_limits().clone())
+        }
+    }
+}
+
+pub fn set_bet_limits_in(
+    storage: &mut dyn Storage,
+    currency: &Currency,
+    bet_limits: BetLimits,
+) -> Result<(), CurrencyError> {
+    match currency {
+        Currency::Snip20 => {
+            let mut state = State::singleton_load(storage)?;
+            state.set_bet_limits(bet_limits);
+            state.singleton_save(storage)?;
+        }
+        Currency::Native { .. } => {
+            let mut state = State::singleton_load(storage)?;
+            state.set_native_bet_limits(bet_limits);
+            state.singleton_save(storage)?;
+        }
+        Currency::Token { address } => {
+            let mut token = get_accepted_token(storage, address)?;
+            token.set_bet_limits(bet_limits);
+            ACCEPTED_TOKENS.insert(storage, address, &token)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn assert_amount_is_above_minimum_bet_in(
+    storage: &dyn Storage,
+    currency: &Currency,
+    amount: &Uint128,
+) -> Result<Uint128, CurrencyError> {
+    let minimum_bet = get_minimum_bet_in(storage, currency)?;
+    if amount >= &minimum_bet {
+        Ok(minimum_bet)
+    } else {
+        Err(StateError::BetBelowMinimum {
+            attempted: amount.to_owned(),
+            minimum: minimum_bet,
+        }
+        .into())
+    }
+}
+
+pub fn create_send_msg(
+    storage: &dyn Storage,
+    currency: &Currency,
+    recipient: &String,
+    amount: &Uint128,
+) -> StdResult<CosmosMsg> {
+    match currency {
+        Currency::Snip20 => {
+            let snip20 = Snip20::singleton_load(storage)?;
+            snip20.create_send_msg(recipient, amount)
+        }
+        Currency::Token { address } => get_accepted_token(storage, address)?
+            .snip20()
+            .create_send_msg(recipient, amount),
+        Currency::Native { denom } => Ok(BankMsg::Send {
+            to_address: recipient.clone(),
+            amount: vec![Coin::new(amount.u128(), denom.clone())],
+        }
+        .into()),
+    }
+}
+
+pub fn get_claimable_fees_in(storage: &dyn Storage, currency: &Currency) -> StdResult<Uint128> {
+    match currency {
+        Currency::Snip20 => {
+            let state = State::singleton_load(storage)?;
+            Ok(*state.claimable_fees())
+        }
+        Currency::Token { address } => Ok(*get_accepted_token(storage, address)?.claimable_fees()),
+        Currency::Native { denom } => Ok(NATIVE_CLAIMABLE_FEES
+            .get(storage, denom)
+            .unwrap_or(Uint128::zero())),
+    }
+}
+
+pub fn set_claimable_fees_in(
+    storage: &mut dyn Storage,
+    currency: &Currency,
+    amount: Uint128,
+) -> StdResult<()> {
+    match currency {
+        Currency::Snip20 => {
+            let mut state = State::singleton_load(storage)?;
+            state.set_claimable_fees(amount);
+            state.singleton_save(storage)
+        }
+        Currency::Token { address } => {
+            let mut token = get_accepted_token(storage, address)?;
+            token.set_claimable_fees(amount);
+            ACCEPTED_TOKENS.insert(storage, address, &token)
+        }
+        Currency::Native { denom } => NATIVE_CLAIMABLE_FEES.insert(storage, denom, &amount),
+    }
+}
+
+pub fn add_claimable_fees_in(
+    storage: &mut dyn Storage,
+    currency: &Currency,
+    amount: &Uint128,
+) -> StdResult<()> {
+    let claimable_fees = get_claimable_fees_in(storage, currency)?;
+    set_claimable_fees_in(storage, currency, claimable_fees + amount)
+}
+
+pub fn get_native_claimable_fees(storage: &dyn Storage) -> StdResult<Vec<(String, Uint128)>> {
+    NATIVE_CLAIMABLE_FEES.iter(storage)?.collect()
+}
+
+// None for a token whose viewing key was never set, the contract cannot read its balance
+pub fn get_contract_balance_in(
+    deps: Deps,
+    env: &Env,
+    currency: &Currency,
+) -> StdResult<Option<Uint128>> {
+    match currency {
+        Currency::Snip20 => {
+            let snip20 = get_snip20(deps.storage)?;
+            Ok(Some(
+                snip20.query_contract_balance(&deps.querier, env)?.amount,
+            ))
+        }
+        Currency::Token { address } => {
+            let token = get_accepted_token(deps.storage, address)?;
+            if !*token.viewing_key_set() {
+                return Ok(None);
+            }
+            Ok(Some(
+                token
+                    .snip20()
+                    .query_contract_balance(&deps.querier, env)?
+                    .amount,
+            ))
+        }
+        Currency::Native { denom } => Ok(Some(
+            deps.querier
+                .query_balance(&env.contract.address, denom)?
+                .amount,
+        )),
+    }
+}
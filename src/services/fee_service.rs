@@ -0,0 +1,165 @@
+use cosmwasm_std::{Addr, Env, StdResult, Storage, Uint128};
+
+use crate::{
+    constants::{BASIS_POINTS, MAX_PAGE_SIZE},
+    data::{
+        currency::Currency,
+        fee_recipients::{
+            add_fee_payout, get_fee_payout_store, get_recipients_fee_payout_store, FeePayout,
+            FeeRecipient, FEE_RECIPIENTS,
+        },
+        fee_withdrawals::{add_fee_withdrawal, get_fee_withdrawal_store, FeeWithdrawal},
+    },
+    error::fee_error::FeeError,
+    services::currency_service::{get_claimable_fees_in, set_claimable_fees_in},
+};
+
+pub fn set_fee_recipients(
+    storage: &mut dyn Storage,
+    recipients: &Vec<FeeRecipient>,
+) -> Result<(), FeeError> {
+    if recipients.is_empty() {
+        return Err(FeeError::NoFeeRecipients);
+    }
+
+    let mut total_bps: u32 = 0;
+    for (index, recipient) in recipients.iter().enumerate() {
+        if recipient.share_bps() == &0 {
+            return Err(FeeError::ZeroShare(recipient.address().to_string()));
+        }
+        if recipients[..index]
+            .iter()
+            .any(|other| other.address() == recipient.address())
+        {
+            return Err(FeeError::DuplicateRecipient(
+                recipient.address().to_string(),
+            ));
+        }
+        total_bps += *recipient.share_bps() as u32;
+    }
+
+    if total_bps as u128 != BASIS_POINTS {
+        return Err(FeeError::InvalidShares(total_bps));
+    }
+
+    FEE_RECIPIENTS.save(storage, recipients)?;
+    Ok(())
+}
+
+pub fn get_fee_recipients(storage: &dyn Storage) -> StdResult<Vec<FeeRecipient>> {
+    Ok(FEE_RECIPIENTS.may_load(storage)?.unwrap_or_default())
+}
+
+// Payouts oldest first, either every payout or only those sent to one recipient
+pub fn get_fee_payouts(
+    storage: &dyn Storage,
+    recipient: Option<&Addr>,
+    page: u32,
+    page_size: u32,
+) -> StdResult<Vec<FeePayout>> {
+    let payout_store = match recipient {
+        Some(recipient) => get_recipients_fee_payout_store(recipient),
+        None => get_fee_payout_store(),
+    };
+    payout_store.paging(storage, page, page_size.min(MAX_PAGE_SIZE))
+}
+
+pub fn get_total_fee_payouts(storage: &dyn Storage, recipient: Option<&Addr>) -> StdResult<u32> {
+    let payout_store = match recipient {
+        Some(recipient) => get_recipients_fee_payout_store(recipient),
+        None => get_fee_payout_store(),
+    };
+    payout_store.get_len(storage)
+}
+
+// Splits the claimable fees in a currency between the recipients by share.
+// Anything lost to rounding stays claimable for the next distribution.
+pub fn distribute_fees(
+    storage: &mut dyn Storage,
+    env: &Env,
+    currency: &Currency,
+) -> Result<Vec<(Addr, Uint128)>, FeeError> {
+    let recipients = get_fee_recipients(storage)?;
+    if recipients.is_empty() {
+        return Err(FeeError::NoFeeRecipients);
+    }
+
+    let claimable_fees = get_claimable_fees_in(storage, currency)?.u128();
+
+    let mut distributed: u128 = 0;
+    let mut payouts = Vec::new();
+    for recipient in recipients.iter() {
+        let amount = claimable_fees * (*recipient.share_bps() as u128) / BASIS_POINTS;
+        if amount == 0 {
+            continue;
+        }
+        distributed += amount;
+        add_fee_payout(
+            storage,
+            &FeePayout::new(
+                recipient.address().clone(),
+                Uint128::from(amount),
+                env.block.time.seconds(),
+                currency.clone(),
+            ),
+        )?;
+        payouts.push((recipient.address().clone(), Uint128::from(amount)));
+    }
+
+    set_claimable_fees_in(
+        storage,
+        currency,
+        Uint128::from(claimable_fees - distributed),
+    )?;
+    Ok(payouts)
+}
+
+// Takes the requested amount out of the claimable fees in a currency, or all of it when no amount is given
+pub fn withdraw_fees(
+    storage: &mut dyn Storage,
+    env: &Env,
+    currency: &Currency,
+    amount: Option<Uint128>,
+    recipient: &Addr,
+) -> Result<Uint128, FeeError> {
+    let claimable_fees = get_claimable_fees_in(storage, currency)?;
+
+    let amount = match amount {
+        Some(amount) if amount.is_zero() => return Err(FeeError::ZeroWithdrawal),
+        Some(amount) if amount > claimable_fees => {
+            return Err(FeeError::InsufficientClaimableFees {
+                requested: amount,
+                claimable: claimable_fees,
+            })
+        }
+        Some(amount) => amount,
+        None => claimable_fees,
+    };
+
+    set_claimable_fees_in(storage, currency, claimable_fees - amount)?;
+
+    if !amount.is_zero() {
+        let withdrawal = FeeWithdrawal::new(
+            amount,
+            recipient.clone(),
+            env.block.time.seconds(),
+            currency.clone(),
+        );
+        add_fee_withdrawal(storage, &withdrawal)?;
+    }
+    Ok(amount)
+}
+
+pub fn get_fee_withdrawals(
+    storage: &dyn Storage,
+    page: u32,
+    page_size: u32,
+) -> StdResult<Vec<FeeWithdrawal>> {
+    let withdrawal_store = get_fee_withdrawal_store();
+    withdrawal_store.paging(storage, page, page_size.min(MAX_PAGE_SIZE))
+}
+
+pub fn get_total_fee_withdrawals(storage: &dyn Storage) -> StdResult<u32> {
+    let withdrawal_store = get_fee_withdrawal_store();
+    withdrawal_store.get_len(storage)
+}
@@ -1,31 +1,31 @@
-#[cfg(not(feature = "testing"))]
-pub mod viewing_keys {
-    use cosmwasm_std::{Addr, QuerierWrapper, StdResult};
-    use sp_secret_toolkit::master_viewing_key::{
-        response::IsViewingKeyValidResponse, MasterViewingKey,
-    };
-    pub fn assert_valid_viewing_key(
-        storage: &dyn cosmwasm_std::Storage,
+use cosmwasm_std::{Addr, QuerierWrapper, StdResult, Storage};
+use sp_secret_toolkit::master_viewing_key::{
+    response::IsViewingKeyValidResponse, MasterViewingKey,
+};
+
+/// Checks the viewing keys users send with their private queries.
+pub trait ViewingKeyVerifier {
+    fn assert_valid_viewing_key(
+        &self,
+        storage: &dyn Storage,
         querier: &QuerierWrapper,
         address: &Addr,
         viewing_key: &String,
-    ) -> StdResult<IsViewingKeyValidResponse> {
-        let master_viewing_key_contract = MasterViewingKey::singleton_load(storage)?;
-        master_viewing_key_contract.assert_viewing_key_is_valid(querier, address, viewing_key)
-    }
+    ) -> StdResult<IsViewingKeyValidResponse>;
 }
-#[allow(unused)]
-#[cfg(feature = "testing")]
-pub mod viewing_keys {
-    use cosmwasm_std::{Addr, QuerierWrapper, StdResult};
-    use sp_secret_toolkit::master_viewing_key::response::IsViewingKeyValidResponse;
 
-    pub fn assert_valid_viewing_key(
-        storage: &dyn cosmwasm_std::Storage,
+/// Asks the master viewing key contract stored at instantiation.
+pub struct MasterViewingKeyVerifier;
+
+impl ViewingKeyVerifier for MasterViewingKeyVerifier {
+    fn assert_valid_viewing_key(
+        &self,
+        storage: &dyn Storage,
         querier: &QuerierWrapper,
         address: &Addr,
         viewing_key: &String,
     ) -> StdResult<IsViewingKeyValidResponse> {
-        Ok(IsViewingKeyValidResponse { validity: true })
+        let master_viewing_key_contract = MasterViewingKey::singleton_load(storage)?;
+        master_viewing_key_contract.assert_viewing_key_is_valid(querier, address, viewing_key)
     }
 }
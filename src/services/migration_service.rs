@@ -0,0 +1,61 @@
+use cosmwasm_std::{Env, Storage};
+
+use crate::{
+    constants::{CONTRACT_VERSION, UNVERSIONED_CONTRACT_VERSION},
+    data::contract_version::STORED_CONTRACT_VERSION,
+    error::migration_error::MigrationError,
+    migrations::MIGRATION_STEPS,
+};
+
+pub fn get_contract_version(storage: &dyn Storage) -> Result<String, MigrationError> {
+    let version = STORED_CONTRACT_VERSION.may_load(storage)?;
+    Ok(version.unwrap_or(UNVERSIONED_CONTRACT_VERSION.to_owned()))
+}
+
+pub fn set_contract_version(storage: &mut dyn Storage) -> Result<(), MigrationError> {
+    STORED_CONTRACT_VERSION.save(storage, &CONTRACT_VERSION.to_owned())?;
+    Ok(())
+}
+
+/// Runs every upgrade step between the stored version and the version of this code,
+/// returning the version the contract was migrated from.
+pub fn migrate_contract(storage: &mut dyn Storage, env: &Env) -> Result<String, MigrationError> {
+    let from = get_contract_version(storage)?;
+    let target = parse_version(CONTRACT_VERSION)?;
+
+    if parse_version(&from)? > target {
+        return Err(MigrationError::Downgrade {
+            from,
+            to: CONTRACT_VERSION.to_owned(),
+        });
+    }
+
+    let mut current = from.clone();
+    while parse_version(&current)? < target {
+        let step = MIGRATION_STEPS
+            .iter()
+            .find(|step| step.from == current)
+            .ok_or(MigrationError::NoMigrationPath {
+                from: current.clone(),
+                to: CONTRACT_VERSION.to_owned(),
+            })?;
+        (step.migrate)(storage, env)?;
+        current = step.to.to_owned();
+    }
+
+    set_contract_version(storage)?;
+    Ok(from)
+}
+
+fn parse_version(version: &str) -> Result<(u64, u64, u64), MigrationError> {
+    let parts: Vec<u64> = version
+        .split('.')
+        .map(|part| part.parse::<u64>())
+        .collect::<Result<Vec<u64>, _>>()
+        .map_err(|_| MigrationError::InvalidVersion(version.to_owned()))?;
+
+    match parts.as_slice() {
+        [major, minor, patch] => Ok((*major, *minor, *patch)),
+        _ => Err(MigrationError::InvalidVersion(version.to_owned())),
+    }
+}
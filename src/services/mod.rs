@@ -2,6 +2,10 @@ pub mod bet_service;
 pub mod contest_bet_summary_service;
 pub mod contest_info_service;
 pub mod contests_service;
+pub mod currency_service;
+pub mod fee_service;
 pub mod integrations;
+pub mod migration_service;
 pub mod state_service;
+pub mod ticker_service;
 pub mod user_info_service;
@@ -1,8 +1,11 @@
-use cosmwasm_std::{Addr, Storage, Uint128};
-use sp_secret_toolkit::{contract::contract::Contract, snip20::Snip20};
+use cosmwasm_std::{Addr, Env, Storage, Uint128};
+use sp_secret_toolkit::snip20::Snip20;
 
 use crate::{
-    data::state::{FeePercent, State},
+    data::{
+        roles::{Role, ROLES},
+        state::{ContractStatus, FeePercent, PendingFeeChange, State},
+    },
     error::state_error::StateError,
 };
 
@@ -11,6 +14,52 @@ pub fn get_fee_percent(storage: &dyn cosmwasm_std::Storage) -> Result<FeePercent
     Ok(state.fee_percent().clone())
 }
 
+pub fn get_current_fee_percent(storage: &dyn Storage, env: &Env) -> Result<FeePercent, StateError> {
+    let state = State::singleton_load(storage)?;
+    Ok(state.current_fee_percent(env.block.time.seconds()).clone())
+}
+
+pub fn get_pending_fee_change(
+    storage: &dyn Storage,
+    env: &Env,
+) -> Result<Option<PendingFeeChange>, StateError> {
+    let state = State::singleton_load(storage)?;
+    match state.pending_fee_change() {
+        Some(change) if !change.is_due(env.block.time.seconds()) => Ok(Some(change.clone())),
+        _ => Ok(None),
+    }
+}
+
+pub fn get_betting_cutoff_seconds(storage: &dyn Storage) -> Result<u64, StateError> {
+    let state = State::singleton_load(storage)?;
+    Ok(*state.betting_cutoff_seconds())
+}
+
+pub fn get_expiration_window_seconds(storage: &dyn Storage) -> Result<u64, StateError> {
+    let state = State::singleton_load(storage)?;
+    Ok(*state.expiration_window_seconds())
+}
+
+pub fn get_cancellation_fee_percent(storage: &dyn Storage) -> Result<FeePercent, StateError> {
+    let state = State::singleton_load(storage)?;
+    Ok(state.cancellation_fee_percent().clone())
+}
+
+pub fn get_switching_fee_percent(storage: &dyn Storage) -> Result<FeePercent, StateError> {
+    let state = State::singleton_load(storage)?;
+    Ok(state.switching_fee_percent().clone())
+}
+
+pub fn get_keeper_reward_percent(storage: &dyn Storage) -> Result<FeePercent, StateError> {
+    let state = State::singleton_load(storage)?;
+    Ok(state.keeper_reward_percent().clone())
+}
+
+pub fn get_max_fee_percent(storage: &dyn cosmwasm_std::Storage) -> Result<FeePercent, StateError> {
+    let state = State::singleton_load(storage)?;
+    Ok(state.max_fee_percent().clone())
+}
+
 pub fn get_minimum_bet(storage: &dyn cosmwasm_std::Storage) -> Result<Uint128, StateError> {
     let state = State::singleton_load(storage)?;
     Ok(state.minimum_bet().clone())
@@ -21,54 +70,97 @@ pub fn get_claimable_fees(storage: &dyn cosmwasm_std::Storage) -> Result<Uint128
     Ok(state.claimable_fees().clone())
 }
 
-pub fn add_claimable_fee_for_pool(
-    storage: &mut dyn cosmwasm_std::Storage,
-    total_pool: &Uint128,
-    fee: &FeePercent,
-) {
-    let mut state = State::singleton_load(storage).unwrap();
-    let current_fees = state.claimable_fees().to_owned();
-    let fee = fee;
+pub fn get_interval(storage: &dyn cosmwasm_std::Storage) -> Result<u64, StateError> {
+    let state = State::singleton_load(storage)?;
+    Ok(state.interval().clone())
+}
 
-    let mut fee_amount = 0;
+pub fn get_snip20(storage: &dyn Storage) -> Result<Snip20, StateError> {
+    let snip20 = Snip20::singleton_load(storage)?;
+    Ok(snip20)
+}
+
+pub fn get_contract_status(storage: &dyn Storage) -> Result<ContractStatus, StateError> {
+    let state = State::singleton_load(storage)?;
+    Ok(state.status().clone())
+}
 
-    if fee.numerator() > &(0 as u128) {
-        fee_amount = total_pool.u128()
-            - (total_pool.u128() * (fee.denominator() - fee.numerator()) / fee.denominator());
+pub fn assert_bets_allowed(storage: &dyn Storage) -> Result<(), StateError> {
+    let status = get_contract_status(storage)?;
+    if status.bets_allowed() {
+        Ok(())
+    } else {
+        Err(StateError::BetsPaused(status))
     }
-    let new_collected_fees = current_fees + Uint128::from(fee_amount);
+}
 
-    state.set_claimable_fees(new_collected_fees);
-    let _ = state.singleton_save(storage);
+pub fn assert_claims_allowed(storage: &dyn Storage) -> Result<ContractStatus, StateError> {
+    let status = get_contract_status(storage)?;
+    if status.claims_allowed() {
+        Ok(status)
+    } else {
+        Err(StateError::ClaimsPaused(status))
+    }
 }
 
-pub fn get_interval(storage: &dyn cosmwasm_std::Storage) -> Result<u64, StateError> {
-    let state = State::singleton_load(storage)?;
-    Ok(state.interval().clone())
+pub fn get_roles(storage: &dyn Storage, address: &Addr) -> Vec<Role> {
+    ROLES.get(storage, address).unwrap_or_default()
 }
 
-pub fn assert_amount_is_greater_than_minimum_bet(
-    storage: &dyn cosmwasm_std::Storage,
-    amount: &Uint128,
-) -> Result<Uint128, StateError> {
-    let minimum_bet = get_minimum_bet(storage)?;
-    if amount >= &minimum_bet {
-        Ok(minimum_bet)
-    } else {
-        Err(StateError::BetBelowMinimum {
-            attempted: amount.to_owned(),
-            minimum: minimum_bet,
-        })
+pub fn get_all_roles(storage: &dyn Storage) -> Result<Vec<(Addr, Vec<Role>)>, StateError> {
+    let mut roles = Vec::new();
+
+    for entry in ROLES.iter(storage)? {
+        roles.push(entry?);
     }
+
+    Ok(roles)
 }
 
-pub fn get_snip20(storage: &dyn Storage) -> Result<Snip20, StateError> {
-    let snip20 = Snip20::singleton_load(storage)?;
-    Ok(snip20)
+pub fn grant_role(storage: &mut dyn Storage, address: &Addr, role: Role) -> Result<(), StateError> {
+    let mut roles = get_roles(storage, address);
+    if roles.contains(&role) {
+        return Err(StateError::RoleAlreadyGranted {
+            address: address.to_string(),
+            role,
+        });
+    }
+
+    roles.push(role);
+    ROLES.insert(storage, address, &roles)?;
+    Ok(())
 }
 
-pub fn assert_snip20_address(storage: &dyn Storage, address: &Addr) -> Result<Snip20, StateError> {
-    let snip20 = get_snip20(storage)?;
-    Contract::assert_address(&snip20, address.clone())?;
-    Ok(snip20)
+pub fn revoke_role(
+    storage: &mut dyn Storage,
+    address: &Addr,
+    role: Role,
+) -> Result<(), StateError> {
+    let mut roles = get_roles(storage, address);
+    if !roles.contains(&role) {
+        return Err(StateError::MissingRole {
+            address: address.to_string(),
+            role,
+        });
+    }
+
+    roles.retain(|granted| granted != &role);
+    if roles.is_empty() {
+        ROLES.remove(storage, address)?;
+    } else {
+        ROLES.insert(storage, address, &roles)?;
+    }
+    Ok(())
+}
+
+pub fn assert_role(storage: &dyn Storage, address: &Addr, role: Role) -> Result<(), StateError> {
+    let state = State::singleton_load(storage)?;
+    if state.owner() == address || get_roles(storage, address).contains(&role) {
+        Ok(())
+    } else {
+        Err(StateError::MissingRole {
+            address: address.to_string(),
+            role,
+        })
+    }
 }
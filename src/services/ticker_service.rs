@@ -0,0 +1,193 @@
+use cosmwasm_std::{ContractInfo, Env, StdResult, Storage};
+use sp_secret_toolkit::{contract::contract::Contract, price_feed::PriceFeed};
+
+use crate::{
+    constants::{
+        BASIS_POINTS, NULL_AND_VOID_CONTEST_RESULT, SECONDS_IN_A_MINUTE, STORED_TICKER_SEPARATOR,
+    },
+    data::{
+        outcome_band::OutcomeBand,
+        state::State,
+        tickers::{TickerInfo, TICKERS},
+    },
+    error::ticker_error::TickerError,
+};
+
+pub fn add_ticker(
+    storage: &mut dyn Storage,
+    env: &Env,
+    ticker: &String,
+) -> Result<(), TickerError> {
+    if TICKERS.contains(storage, ticker) {
+        return Err(TickerError::TickerAlreadyExists(ticker.clone()));
+    }
+    // The separator splits the currency and custom segments off stored contest tickers
+    if ticker.contains(STORED_TICKER_SEPARATOR) {
+        return Err(TickerError::InvalidTicker(ticker.clone()));
+    }
+
+    let ticker_info = TickerInfo::new(ticker.clone(), env.block.time.seconds());
+    TICKERS.insert(storage, ticker, &ticker_info)?;
+    Ok(())
+}
+
+pub fn remove_ticker(storage: &mut dyn Storage, ticker: &String) -> Result<(), TickerError> {
+    // Existing contests on the ticker are left untouched so they can still be claimed
+    get_ticker(storage, ticker)?;
+    TICKERS.remove(storage, ticker)?;
+    Ok(())
+}
+
+pub fn set_ticker_paused(
+    storage: &mut dyn Storage,
+    ticker: &String,
+    paused: bool,
+) -> Result<(), TickerError> {
+    let mut ticker_info = get_ticker(storage, ticker)?;
+    ticker_info.set_enabled(!paused);
+    TICKERS.insert(storage, ticker, &ticker_info)?;
+    Ok(())
+}
+
+pub fn set_ticker_config(
+    storage: &mut dyn Storage,
+    ticker: &String,
+    interval: Option<u64>,
+    price_feed: Option<ContractInfo>,
+) -> Result<(), TickerError> {
+    if let Some(interval) = interval {
+        // Close times are rounded to the minute so intervals must be whole minutes
+        if interval == 0 || interval % SECONDS_IN_A_MINUTE != 0 {
+            return Err(TickerError::InvalidInterval(interval));
+        }
+    }
+
+    let mut ticker_info = get_ticker(storage, ticker)?;
+    ticker_info.set_interval(interval);
+    ticker_info.set_price_feed(price_feed);
+    TICKERS.insert(storage, ticker, &ticker_info)?;
+    Ok(())
+}
+
+pub fn set_ticker_outcome_bands(
+    storage: &mut dyn Storage,
+    ticker: &String,
+    outcome_bands: Option<Vec<OutcomeBand>>,
+) -> Result<(), TickerError> {
+    if let Some(outcome_bands) = &outcome_bands {
+        validate_outcome_bands(ticker, outcome_bands)?;
+    }
+
+    let mut ticker_info = get_ticker(storage, ticker)?;
+    ticker_info.set_outcome_bands(outcome_bands);
+    TICKERS.insert(storage, ticker, &ticker_info)?;
+    Ok(())
+}
+
+pub fn validate_outcome_bands(
+    ticker: &String,
+    outcome_bands: &Vec<OutcomeBand>,
+) -> Result<(), TickerError> {
+    if outcome_bands.len() < 2 {
+        return Err(TickerError::TooFewOutcomeBands(ticker.clone()));
+    }
+
+    let lowest_change = -(BASIS_POINTS as i32);
+    for (index, band) in outcome_bands.iter().enumerate() {
+        // Id 0 is reserved for nullified contests
+        if band.id() == &NULL_AND_VOID_CONTEST_RESULT
+            || outcome_bands[..index]
+                .iter()
+                .any(|other| other.id() == band.id())
+        {
+            return Err(TickerError::InvalidOutcomeId(*band.id()));
+        }
+
+        let min = band.min_change_bps().unwrap_or(lowest_change);
+        let max = band.max_change_bps().unwrap_or(i32::MAX);
+        if min < lowest_change || max <= min {
+            return Err(TickerError::InvalidOutcomeBand(*band.id()));
+        }
+    }
+
+    // Bands sorted by lower bound must each end before the next one starts
+    let mut sorted_bands = outcome_bands.clone();
+    sorted_bands.sort_by_key(|band| band.min_change_bps().unwrap_or(i32::MIN));
+    for pair in sorted_bands.windows(2) {
+        let overlaps = match (pair[0].max_change_bps(), pair[1].min_change_bps()) {
+            (Some(max), Some(min)) => max > min,
+            _ => true,
+        };
+        if overlaps {
+            return Err(TickerError::OverlappingOutcomeBands(
+                *pair[0].id(),
+                *pair[1].id(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn get_ticker(storage: &dyn Storage, ticker: &String) -> Result<TickerInfo, TickerError> {
+    match TICKERS.get(storage, ticker) {
+        Some(ticker_info) => Ok(ticker_info),
+        None => Err(TickerError::TickerNotFound(ticker.clone())),
+    }
+}
+
+pub fn get_tickers(storage: &dyn Storage) -> StdResult<Vec<TickerInfo>> {
+    let mut tickers = Vec::new();
+
+    for entry in TICKERS.iter(storage)? {
+        let (_ticker, ticker_info) = entry?;
+        tickers.push(ticker_info);
+    }
+
+    Ok(tickers)
+}
+
+pub fn assert_ticker_enabled(
+    storage: &dyn Storage,
+    ticker: &String,
+) -> Result<TickerInfo, TickerError> {
+    let ticker_info = get_ticker(storage, ticker)?;
+    if *ticker_info.enabled() {
+        Ok(ticker_info)
+    } else {
+        Err(TickerError::TickerPaused(ticker.clone()))
+    }
+}
+
+pub fn get_ticker_interval(storage: &dyn Storage, ticker: &String) -> Result<u64, TickerError> {
+    let interval = TICKERS
+        .get(storage, ticker)
+        .and_then(|ticker_info| ticker_info.interval().to_owned());
+
+    match interval {
+        Some(interval) => Ok(interval),
+        None => Ok(State::singleton_load(storage)?.interval().to_owned()),
+    }
+}
+
+pub fn get_ticker_price_feed(
+    storage: &dyn Storage,
+    ticker: &String,
+) -> Result<ContractInfo, TickerError> {
+    let price_feed = TICKERS
+        .get(storage, ticker)
+        .and_then(|ticker_info| ticker_info.price_feed().to_owned());
+
+    match price_feed {
+        Some(price_feed) => Ok(price_feed),
+        None => Ok(Contract::get_contract_info(&PriceFeed::singleton_load(
+            storage,
+        )?)),
+    }
+}
+
+pub fn get_ticker_outcome_bands(storage: &dyn Storage, ticker: &String) -> Option<Vec<OutcomeBand>> {
+    TICKERS
+        .get(storage, ticker)
+        .and_then(|ticker_info| ticker_info.outcome_bands().to_owned())
+}
@@ -49,6 +49,47 @@ pub fn advance_index(
     Ok(true)
 }
 
+pub fn get_last_claimed_index(storage: &dyn Storage, address: &Addr) -> u32 {
+    get_users_last_claimed_index(address)
+        .may_load(storage)
+        .unwrap_or_default()
+        .unwrap_or_default()
+}
+
+pub fn set_last_claimed_index(
+    storage: &mut dyn Storage,
+    address: &Addr,
+    index: u32,
+) -> StdResult<()> {
+    get_users_last_claimed_index(address).save(storage, &index)
+}
+
+// Function to retrieve up to `limit` of a user's unchecked contests along with their index
+pub fn get_unchecked_contests_for_user_with_index(
+    storage: &dyn Storage,
+    address: &Addr,
+    limit: u32,
+) -> StdResult<Vec<(u32, ContestId)>> {
+    let user_map = get_users_contest_map(address);
+    let range_start = get_last_claimed_index(storage, address);
+    let range_end = user_map
+        .get_len(storage)?
+        .min(range_start.saturating_add(limit));
+
+    let mut contests = Vec::new();
+    for key in range_start..range_end {
+        if let Some(contest_id) = user_map.get(storage, &key) {
+            contests.push((key, contest_id));
+        }
+    }
+
+    Ok(contests)
+}
+
+pub fn get_users_contest_count(storage: &dyn Storage, address: &Addr) -> StdResult<u32> {
+    get_users_contest_map(address).get_len(storage)
+}
+
 pub fn get_last_ten_bet_on(storage: &dyn Storage, address: &Addr) -> StdResult<Vec<ContestId>> {
     let user_map = get_users_contest_map(address);
     let bet_length = user_map.get_len(storage).unwrap();
@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data::state::FeePercent,
+        tests::{
+            constants::{BASE_FEE_PERCENT_DENOMINATOR, BASE_FEE_PERCENT_NUMERATOR},
+            test_env::tests::TestEnv,
+        },
+    };
+
+    ////////TESTS////////
+    #[test]
+    fn accept_ownership() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.propose_new_owner_success("new_owner");
+        test_env.set_sender("new_owner".to_owned());
+        test_env.accept_ownership_success("creator");
+        test_env.get_owner("new_owner", None);
+    }
+
+    #[test]
+    fn new_owner_has_admin_rights() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.propose_new_owner_success("new_owner");
+        test_env.set_sender("new_owner".to_owned());
+        test_env.accept_ownership_success("creator");
+        test_env.set_minimum_bet_success(&100);
+
+        test_env.set_sender("creator".to_owned());
+        test_env.set_minimum_bet_fail(&100);
+    }
+
+    #[test]
+    fn cannot_accept_without_proposal() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_sender("new_owner".to_owned());
+        test_env.accept_ownership_fail();
+    }
+
+    #[test]
+    fn cannot_accept_if_not_pending_owner() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.propose_new_owner_success("new_owner");
+        test_env.set_sender("user2".to_owned());
+        test_env.accept_ownership_fail();
+        test_env.get_owner("creator", Some("new_owner"));
+    }
+
+    #[test]
+    fn cannot_accept_twice() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.propose_new_owner_success("new_owner");
+        test_env.set_sender("new_owner".to_owned());
+        test_env.accept_ownership_success("creator");
+        test_env.accept_ownership_fail();
+    }
+}
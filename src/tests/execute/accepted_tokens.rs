@@ -0,0 +1,186 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Addr;
+
+    use crate::{
+        data::{currency::Currency, state::FeePercent},
+        tests::{
+            constants::{
+                AFTER_TIME_OF_RESOLVE, BASE_FEE_PERCENT_DENOMINATOR, BASE_FEE_PERCENT_NUMERATOR,
+            },
+            test_env::tests::TestEnv,
+        },
+    };
+
+    const SNIP20: &str = "Snip20 Address";
+    const TOKEN: &str = "sATOM Address";
+
+    fn token() -> Currency {
+        Currency::Token {
+            address: TOKEN.to_owned(),
+        }
+    }
+
+    ////////TESTS////////
+    #[test]
+    fn owner_adds_accepted_token() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.get_accepted_tokens(vec![(SNIP20, 1, 0, true)]);
+
+        test_env.set_sender("user2".to_owned());
+        test_env.add_accepted_token_fail(TOKEN);
+
+        test_env.set_sender("creator".to_owned());
+        test_env.add_accepted_token_success(TOKEN, &10, 2);
+        test_env.get_accepted_tokens(vec![(SNIP20, 1, 0, true), (TOKEN, 10, 0, true)]);
+        test_env.get_viewing_key_set(SNIP20, true);
+        test_env.get_viewing_key_set(TOKEN, false);
+
+        test_env.set_viewing_key_reply(true);
+        test_env.get_viewing_key_set(TOKEN, true);
+    }
+
+    #[test]
+    fn failed_viewing_key_is_reported_and_retried() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.add_accepted_token_success(TOKEN, &10, 2);
+        test_env.set_viewing_key_reply(false);
+        test_env.get_viewing_key_set(TOKEN, false);
+
+        // Adding the token again only resends the viewing key
+        test_env.remove_accepted_token_success(TOKEN);
+        test_env.add_accepted_token_success(TOKEN, &10, 1);
+        test_env.set_viewing_key_reply(true);
+        test_env.get_viewing_key_set(TOKEN, true);
+    }
+
+    #[test]
+    fn cannot_add_token_twice() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.add_accepted_token_fail(SNIP20);
+        test_env.add_accepted_token_success(TOKEN, &10, 2);
+        test_env.add_accepted_token_fail(TOKEN);
+    }
+
+    #[test]
+    fn bets_only_received_from_accepted_tokens() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.handle_receive_fail(&1, &1, &100, TOKEN);
+
+        test_env.add_accepted_token_success(TOKEN, &10, 2);
+        test_env.handle_receive_success(&1, &1, &100, Addr::unchecked(TOKEN));
+        test_env.get_user_bet_in_success(&1, &token(), Some(&100), Some(&1), Some(&false));
+        test_env.get_user_bet_failure(&1);
+    }
+
+    #[test]
+    fn removed_token_stops_bets_but_pays_out() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.remove_accepted_token_fail(TOKEN);
+        test_env.add_accepted_token_success(TOKEN, &10, 2);
+        test_env.set_viewing_key_reply(true);
+        test_env.handle_receive_success(&1, &1, &100, Addr::unchecked(TOKEN));
+
+        test_env.set_sender("user2".to_owned());
+        test_env.remove_accepted_token_fail(TOKEN);
+        test_env.set_sender("creator".to_owned());
+        test_env.remove_accepted_token_success(TOKEN);
+        test_env.remove_accepted_token_fail(TOKEN);
+        test_env.get_accepted_tokens(vec![(SNIP20, 1, 0, true), (TOKEN, 10, 0, false)]);
+
+        test_env.set_sender("user2".to_owned());
+        test_env.handle_receive_fail(&1, &2, &100, TOKEN);
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.set_sender("creator".to_owned());
+        test_env.claim_token_success(&1, &100, TOKEN);
+
+        // Adding it back reuses the existing registration
+        test_env.add_accepted_token_success(TOKEN, &20, 0);
+        test_env.get_accepted_tokens(vec![(SNIP20, 1, 0, true), (TOKEN, 20, 0, true)]);
+    }
+
+    #[test]
+    fn each_token_has_its_own_minimum_bet() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.add_accepted_token_success(TOKEN, &10, 2);
+        test_env.set_token_minimum_bet_success(TOKEN, &50);
+        test_env.get_accepted_tokens(vec![(SNIP20, 1, 0, true), (TOKEN, 50, 0, true)]);
+
+        test_env.handle_receive_fail(&1, &1, &40, TOKEN);
+        test_env.handle_receive_success(&14, &1, &40, Addr::unchecked(SNIP20));
+        test_env.handle_receive_success(&1, &1, &50, Addr::unchecked(TOKEN));
+    }
+
+    #[test]
+    fn token_pool_pays_out_and_collects_fees_in_token() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.add_accepted_token_success(TOKEN, &10, 2);
+        test_env.handle_receive_success(&1, &1, &100, Addr::unchecked(TOKEN));
+        test_env.set_sender("user2".to_owned());
+        test_env.handle_receive_success(&1, &2, &100, Addr::unchecked(TOKEN));
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.set_sender("creator".to_owned());
+        test_env.claim_token_success(&1, &198, TOKEN);
+
+        test_env.get_accepted_tokens(vec![(SNIP20, 1, 0, true), (TOKEN, 10, 2, true)]);
+        test_env.claim_token_fees_success(TOKEN, &2);
+        test_env.get_accepted_tokens(vec![(SNIP20, 1, 0, true), (TOKEN, 10, 0, true)]);
+    }
+
+    #[test]
+    fn each_token_gets_its_own_pool_for_the_same_contest() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.add_accepted_token_success(TOKEN, &10, 2);
+        test_env.handle_receive_success(&1, &1, &100, Addr::unchecked(TOKEN));
+        test_env.set_sender("user2".to_owned());
+        test_env.handle_receive_success(&1, &2, &100, Addr::unchecked(TOKEN));
+
+        // The first token does not pin the contest, the registered token opens its own pool
+        test_env.handle_receive_success(&1, &1, &300, Addr::unchecked(SNIP20));
+        test_env.set_sender("user3".to_owned());
+        test_env.handle_receive_success(&1, &2, &100, Addr::unchecked(SNIP20));
+        test_env.get_number_of_contests(Some(&2));
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.set_sender("creator".to_owned());
+        test_env.claim_token_success(&1, &198, TOKEN);
+        test_env.set_sender("user2".to_owned());
+        test_env.claim_success(&1, Some(&396));
+
+        test_env.get_accepted_tokens(vec![(SNIP20, 1, 4, true), (TOKEN, 10, 2, true)]);
+    }
+}
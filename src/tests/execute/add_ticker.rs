@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data::state::FeePercent,
+        tests::{
+            constants::{BASE_FEE_PERCENT_DENOMINATOR, BASE_FEE_PERCENT_NUMERATOR},
+            test_env::tests::TestEnv,
+        },
+    };
+
+    ////////TESTS////////
+    #[test]
+    fn add_ticker() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.add_ticker_success("SOL");
+        test_env.get_tickers(&vec![("BTC", true), ("ETH", true), ("SOL", true)]);
+    }
+
+    #[test]
+    fn cannot_add_existing_ticker() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.add_ticker_fail("BTC");
+    }
+
+    #[test]
+    fn cannot_add_ticker_with_pool_separator() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.add_ticker_fail("BTC:uscrt");
+    }
+
+    #[test]
+    fn cannot_add_ticker_if_not_admin() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_sender("user2".to_owned());
+        test_env.add_ticker_fail("SOL");
+    }
+
+    #[test]
+    fn bet_on_added_ticker() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_file = 13;
+        test_env.first_bet_on_contest_fail(&contest_file, &1, &100);
+        test_env.add_ticker_success("BTT");
+        test_env.first_bet_on_contest_success(&contest_file, &1, &100);
+    }
+}
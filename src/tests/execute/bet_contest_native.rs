@@ -0,0 +1,221 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{coins, Coin, Uint128};
+
+    use crate::{
+        data::{currency::Currency, outcome_band::OutcomeBand, state::FeePercent},
+        responses::execute::response_types::bet::BetErrorCode,
+        tests::{
+            constants::{
+                AFTER_TIME_OF_1_CLOSE, AFTER_TIME_OF_RESOLVE, BASE_FEE_PERCENT_DENOMINATOR,
+                BASE_FEE_PERCENT_NUMERATOR,
+            },
+            test_env::tests::TestEnv,
+        },
+    };
+
+    const DENOM: &str = "uscrt";
+
+    fn native() -> Currency {
+        Currency::Native {
+            denom: DENOM.to_owned(),
+        }
+    }
+
+    ////////TESTS////////
+    #[test]
+    fn native_bets_disabled_by_default() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.get_native_denom(None, 0);
+        test_env.bet_native_fail("BTC", &1, &coins(100, DENOM));
+    }
+
+    #[test]
+    fn only_owner_sets_native_denom() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_sender("user2".to_owned());
+        test_env.set_native_denom_fail(Some(DENOM), Some(1));
+
+        test_env.set_sender("creator".to_owned());
+        test_env.set_native_denom_success(Some(DENOM), Some(1));
+        test_env.get_native_denom(Some(DENOM), 1);
+        test_env.set_native_denom_success(None, None);
+        test_env.get_native_denom(None, 1);
+    }
+
+    #[test]
+    fn native_bets_need_a_minimum_bet() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_native_denom_fail(Some(DENOM), None);
+        test_env.get_native_denom(None, 0);
+    }
+
+    #[test]
+    fn native_denom_has_its_own_minimum_bet() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_native_denom_success(Some(DENOM), Some(1000));
+        test_env.get_native_denom(Some(DENOM), 1000);
+
+        // The SNIP-20 minimum does not apply to native bets
+        test_env.bet_native_fail("BTC", &1, &coins(999, DENOM));
+        test_env.first_bet_on_contest_success(&1, &1, &999);
+        test_env.get_minimum_bet_success(Some(&1));
+
+        test_env.set_native_minimum_bet_success(DENOM, &500);
+        test_env.get_native_denom(Some(DENOM), 500);
+        test_env.get_minimum_bet_success(Some(&1));
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_native_success("BTC", &1, &coins(500, DENOM));
+    }
+
+    #[test]
+    fn native_bet_needs_single_coin_of_denom() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_native_denom_success(Some(DENOM), Some(1));
+
+        test_env.bet_native_fail("BTC", &1, &[]);
+        test_env.bet_native_fail("BTC", &1, &coins(100, "uatom"));
+        test_env.bet_native_fail("BTC", &1, &coins(0, DENOM));
+        test_env.bet_native_fail("BTC", &1, &[Coin::new(100, DENOM), Coin::new(100, "uatom")]);
+        test_env.get_number_of_contests(Some(&0));
+
+        test_env.bet_native_success("BTC", &1, &coins(100, DENOM));
+        test_env.get_user_bet_in_success(&1, &native(), Some(&100), Some(&1), Some(&false));
+        test_env.get_user_bet_failure(&1);
+    }
+
+    #[test]
+    fn native_bets_get_their_own_pool() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_native_denom_success(Some(DENOM), Some(1));
+        test_env.bet_native_success("BTC", &1, &coins(100, DENOM));
+
+        // The same contest in the SNIP-20 token is a separate pool
+        test_env.set_sender("user2".to_owned());
+        test_env.first_bet_on_contest_success(&1, &2, &100);
+        test_env.set_sender("user3".to_owned());
+        test_env.bet_native_success("BTC", &2, &coins(100, DENOM));
+        test_env.get_contest_in_success(&1, &native());
+        test_env.get_contest_in_success(&1, &Currency::Snip20);
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.set_sender("creator".to_owned());
+        test_env.claim_native_success(&1, &198, DENOM);
+        // Nobody took the other side in SNIP-20 so that pool is refunded
+        test_env.set_sender("user2".to_owned());
+        test_env.claim_success(&1, Some(&100));
+    }
+
+    #[test]
+    fn custom_contest_pool_stays_single_currency() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_native_denom_success(Some(DENOM), Some(1));
+        let contest_id = test_env.create_contest_success(
+            "BTC",
+            1571800000,
+            1571900000,
+            vec![
+                OutcomeBand::new(1, "Over".to_owned(), Some(0), None),
+                OutcomeBand::new(2, "Under".to_owned(), None, Some(0)),
+            ],
+        );
+        test_env.bet_native_on_contest_by_id_fail(&contest_id, &1, &coins(100, DENOM));
+        test_env.bet_on_contest_by_id_success(&contest_id, &1, &100);
+    }
+
+    #[test]
+    fn native_winner_is_paid_in_native_denom() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_native_denom_success(Some(DENOM), Some(1));
+        test_env.bet_native_success("BTC", &1, &coins(100, DENOM));
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_native_success("BTC", &2, &coins(100, DENOM));
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.set_sender("creator".to_owned());
+        test_env.claim_native_success(&1, &198, DENOM);
+
+        // The fee is kept apart from the snip20 fees
+        test_env.get_claimable_fees(Some(&Uint128::zero()));
+        test_env.get_native_claimable_fees(vec![(DENOM, 2)]);
+        test_env.claim_native_fees_success(DENOM, &2);
+        test_env.get_native_claimable_fees(vec![(DENOM, 0)]);
+    }
+
+    #[test]
+    fn cannot_claim_multiple_across_currencies() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_native_denom_success(Some(DENOM), Some(1));
+        test_env.bet_native_success("BTC", &1, &coins(100, DENOM));
+        test_env.set_time(AFTER_TIME_OF_1_CLOSE);
+        test_env.first_bet_on_contest_success(&2, &1, &100);
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.claim_multiple_in_failure(vec![(&1, native()), (&2, Currency::Snip20)]);
+        test_env.claim_native_success(&1, &100, DENOM);
+        test_env.claim_success(&2, Some(&100));
+    }
+
+    #[test]
+    fn rejected_native_bet_is_refunded() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_native_denom_success(Some(DENOM), Some(1));
+        test_env.bet_native_refunded("BTC", &3, &coins(100, DENOM), BetErrorCode::OutcomeNotFound);
+        test_env.get_number_of_contests(Some(&0));
+    }
+
+    #[test]
+    fn cancelled_native_bet_is_refunded_in_native_denom() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_native_denom_success(Some(DENOM), Some(1));
+        test_env.set_cancellation_fee_success(FeePercent::new(2, 100));
+        test_env.bet_native_success("BTC", &1, &coins(100, DENOM));
+
+        test_env.cancel_bet_in_success(&1, &native(), &98, &2);
+        test_env.get_native_claimable_fees(vec![(DENOM, 2)]);
+    }
+}
@@ -0,0 +1,148 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Uint128;
+
+    use crate::{
+        data::state::FeePercent,
+        tests::{
+            constants::{
+                AFTER_TIME_OF_1_CLOSE, AFTER_TIME_OF_RESOLVE, BASE_FEE_PERCENT_DENOMINATOR,
+                BASE_FEE_PERCENT_NUMERATOR,
+            },
+            test_env::tests::TestEnv,
+        },
+    };
+
+    ////////TESTS////////
+    #[test]
+    fn cancel_bet_refunds_stake() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &1, &100);
+
+        test_env.cancel_bet_success(&contest_file, &100, &0);
+        test_env.get_claimable_fees(Some(&Uint128::zero()));
+        test_env.get_volume(Some(&Uint128::zero()));
+        test_env.get_number_of_bets(Some(&0));
+    }
+
+    #[test]
+    fn cancel_bet_keeps_cancellation_fee() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_cancellation_fee_success(FeePercent::new(2, 100));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &1, &100);
+
+        test_env.cancel_bet_success(&contest_file, &98, &2);
+        test_env.get_claimable_fees(Some(&Uint128::from(2u128)));
+    }
+
+    #[test]
+    fn cancelled_bet_stays_in_history() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_cancellation_fee_success(FeePercent::new(2, 100));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &1, &100);
+        test_env.get_user_bet_cancellation(&contest_file, None);
+
+        test_env.cancel_bet_success(&contest_file, &98, &2);
+        test_env.get_user_bet_success(&contest_file, Some(&100), Some(&1), Some(&false));
+        test_env.get_user_bet_cancellation(&contest_file, Some((98, 2)));
+        test_env.users_last_ten_contests_includes_contest(&contest_file);
+        test_env.get_number_of_users_bets(Some(&1));
+    }
+
+    #[test]
+    fn cannot_cancel_after_close() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &1, &100);
+
+        test_env.set_time(AFTER_TIME_OF_1_CLOSE);
+        test_env.cancel_bet_fail(&contest_file);
+    }
+
+    #[test]
+    fn cannot_cancel_without_bet() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &1, &100);
+
+        test_env.set_sender("user2".to_owned());
+        test_env.cancel_bet_fail(&contest_file);
+    }
+
+    #[test]
+    fn cannot_cancel_twice_or_bet_again() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &1, &100);
+
+        test_env.cancel_bet_success(&contest_file, &100, &0);
+        test_env.cancel_bet_fail(&contest_file);
+        test_env.bet_on_contest_fail(&contest_file, &1, &100);
+    }
+
+    #[test]
+    fn cancelled_bet_leaves_pool() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &1, &100);
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_on_contest_success(&contest_file, &2, &100);
+        test_env.set_sender("user3".to_owned());
+        test_env.bet_on_contest_success(&contest_file, &2, &100);
+
+        test_env.cancel_bet_success(&contest_file, &100, &0);
+        test_env.get_number_of_bets(Some(&2));
+        test_env.get_volume(Some(&Uint128::from(200u128)));
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.users_claimable_contests_has_length(0);
+        test_env.claim_failure(&contest_file);
+
+        test_env.set_sender("creator".to_owned());
+        test_env.claim_success(&contest_file, Some(&198));
+    }
+
+    #[test]
+    fn cancellation_fee_cannot_exceed_max_fee() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_cancellation_fee_fail(FeePercent::new(50, 100));
+
+        test_env.set_sender("user2".to_owned());
+        test_env.set_cancellation_fee_fail(FeePercent::new(2, 100));
+    }
+}
@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data::state::FeePercent,
+        tests::{
+            constants::{BASE_FEE_PERCENT_DENOMINATOR, BASE_FEE_PERCENT_NUMERATOR},
+            test_env::tests::TestEnv,
+        },
+    };
+
+    ////////TESTS////////
+    #[test]
+    fn cancel_ownership_transfer() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.propose_new_owner_success("new_owner");
+        test_env.cancel_ownership_transfer_success("new_owner");
+        test_env.get_owner("creator", None);
+
+        test_env.set_sender("new_owner".to_owned());
+        test_env.accept_ownership_fail();
+    }
+
+    #[test]
+    fn cannot_cancel_without_proposal() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.cancel_ownership_transfer_fail();
+    }
+
+    #[test]
+    fn cannot_cancel_if_not_admin() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.propose_new_owner_success("new_owner");
+        test_env.set_sender("new_owner".to_owned());
+        test_env.cancel_ownership_transfer_fail();
+        test_env.get_owner("creator", Some("new_owner"));
+    }
+}
@@ -9,7 +9,6 @@ mod tests {
         response::response_types::prices_by_ids::PricesByIdsResponse,
     };
 
-    use crate::services::integrations::price_feed_service::pricefeed::set_oracle_result;
     use crate::tests::{
         constants::{
             AFTER_TIME_OF_1_CLOSE, AFTER_TIME_OF_2_CLOSE, AFTER_TIME_OF_3_CLOSE,
@@ -530,7 +529,7 @@ mod tests {
                 PricePosting::new(Decimal::from_str("58205.29").unwrap(), 1571797800),
             ],
         };
-        set_oracle_result(Some(oracle_result));
+        test_env.set_oracle_result(oracle_result);
 
         test_env.claim_success(&contest_file, None);
     }
@@ -558,7 +557,7 @@ mod tests {
                 1571797500,
             )],
         };
-        set_oracle_result(Some(oracle_result));
+        test_env.set_oracle_result(oracle_result);
 
         test_env.claim_failure(&contest_file);
     }
@@ -586,7 +585,7 @@ mod tests {
                 PricePosting::new(Decimal::from_str("58205.29").unwrap(), 1571797800),
             ],
         };
-        set_oracle_result(Some(oracle_result));
+        test_env.set_oracle_result(oracle_result);
 
         test_env.claim_success(&contest_file, Some(&100));
     }
@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data::state::FeePercent,
+        tests::{
+            constants::{
+                AFTER_TIME_OF_1_CLOSE, AFTER_TIME_OF_2_CLOSE, AFTER_TIME_OF_RESOLVE,
+                BASE_FEE_PERCENT_DENOMINATOR, BASE_FEE_PERCENT_NUMERATOR,
+            },
+            test_env::tests::TestEnv,
+        },
+    };
+
+    ////////TESTS////////
+    #[test]
+    fn nothing_to_claim() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.claim_all_success(10, &[], 0);
+    }
+
+    #[test]
+    fn claims_won_and_nullified_contests_and_skips_lost() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        // Won on contest 1, lost on contest 2, contest 3 is one sided
+        test_env.first_bet_on_contest_success(&1, &1, &100);
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_on_contest_success(&1, &2, &100);
+        test_env.set_time(AFTER_TIME_OF_1_CLOSE);
+        test_env.first_bet_on_contest_success(&2, &1, &100);
+        test_env.set_sender("creator".to_owned());
+        test_env.bet_on_contest_success(&2, &2, &100);
+        test_env.set_time(AFTER_TIME_OF_2_CLOSE);
+        test_env.first_bet_on_contest_success(&3, &1, &100);
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.claim_all_success(10, &[(1, 198), (3, 100)], 0);
+        test_env.claim_all_success(10, &[], 0);
+        test_env.claim_failure(&1);
+    }
+
+    #[test]
+    fn skips_pending_contests_until_they_resolve() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.first_bet_on_contest_success(&1, &1, &100);
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_on_contest_success(&1, &2, &100);
+        test_env.set_sender("creator".to_owned());
+        test_env.set_time(AFTER_TIME_OF_1_CLOSE);
+        test_env.first_bet_on_contest_success(&2, &1, &100);
+
+        test_env.set_time(AFTER_TIME_OF_2_CLOSE);
+        test_env.claim_all_success(10, &[(1, 198)], 1);
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.claim_all_success(10, &[(2, 100)], 0);
+    }
+
+    #[test]
+    fn stops_after_max_contests() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.first_bet_on_contest_success(&1, &1, &100);
+        test_env.set_time(AFTER_TIME_OF_1_CLOSE);
+        test_env.first_bet_on_contest_success(&2, &1, &100);
+        test_env.set_time(AFTER_TIME_OF_2_CLOSE);
+        test_env.first_bet_on_contest_success(&3, &1, &100);
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.claim_all_success(2, &[(1, 100), (2, 100)], 1);
+        test_env.claim_all_success(2, &[(3, 100)], 0);
+    }
+}
@@ -274,7 +274,7 @@ mod tests {
     #[test]
     fn cannot_claim_twice() {
         let mut test_env = TestEnv::new();
-        test_env.initialize(FeePercent::new(0, 0));
+        test_env.initialize(FeePercent::new(0, 100));
         let contest_file = 1;
         test_env.first_bet_on_contest_success(&contest_file, &1, &5732724505327245311);
 
@@ -0,0 +1,194 @@
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use cosmwasm_std::Decimal;
+    use sp_secret_toolkit::price_feed::{
+        data::price_posting::PricePosting,
+        response::response_types::prices_by_ids::PricesByIdsResponse,
+    };
+
+    use crate::{
+        data::{outcome_band::OutcomeBand, state::FeePercent},
+        tests::{
+            constants::{
+                AFTER_TIME_OF_1_CLOSE, BASE_FEE_PERCENT_DENOMINATOR, BASE_FEE_PERCENT_NUMERATOR,
+            },
+            test_env::tests::TestEnv,
+        },
+    };
+
+    const WEEK: u64 = 604800;
+    const TIME_OF_CLOSE: u64 = 1571800000;
+    const TIME_OF_RESOLVE: u64 = TIME_OF_CLOSE + WEEK;
+    const TIME_OF_1_CLOSE: u64 = 1571797500;
+
+    fn over_under() -> Vec<OutcomeBand> {
+        vec![
+            OutcomeBand::new(1, "Over".to_owned(), Some(0), None),
+            OutcomeBand::new(2, "Under".to_owned(), None, Some(0)),
+        ]
+    }
+
+    ////////TESTS////////
+    #[test]
+    fn create_contest() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.create_contest_success("BTC", TIME_OF_CLOSE, TIME_OF_RESOLVE, over_under());
+        test_env.get_number_of_contests(Some(&1));
+    }
+
+    #[test]
+    fn cannot_create_contest_if_not_owner() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_sender("user2".to_owned());
+        test_env.create_contest_fail("BTC", TIME_OF_CLOSE, TIME_OF_RESOLVE, over_under());
+    }
+
+    #[test]
+    fn cannot_create_contest_with_invalid_times() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        // Close in the past
+        test_env.set_time(AFTER_TIME_OF_1_CLOSE);
+        test_env.create_contest_fail(
+            "BTC",
+            AFTER_TIME_OF_1_CLOSE - 1,
+            TIME_OF_RESOLVE,
+            over_under(),
+        );
+        test_env.create_contest_fail("BTC", AFTER_TIME_OF_1_CLOSE, TIME_OF_RESOLVE, over_under());
+        // Resolve not after close
+        test_env.create_contest_fail("BTC", TIME_OF_CLOSE, TIME_OF_CLOSE, over_under());
+        test_env.create_contest_fail("BTC", TIME_OF_CLOSE, TIME_OF_CLOSE - 1, over_under());
+    }
+
+    #[test]
+    fn custom_contest_can_share_a_close_with_the_interval_contest() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_id =
+            test_env.create_contest_success("BTC", TIME_OF_1_CLOSE, TIME_OF_RESOLVE, over_under());
+        test_env.first_bet_on_contest_success(&1, &1, &100);
+        test_env.bet_on_contest_by_id_success(&contest_id, &2, &50);
+
+        test_env.get_number_of_contests(Some(&2));
+        test_env.get_user_bet_success(&1, Some(&100), Some(&1), None);
+    }
+
+    #[test]
+    fn cannot_create_contest_with_invalid_options() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.create_contest_fail("BTC", TIME_OF_CLOSE, TIME_OF_RESOLVE, vec![]);
+        test_env.create_contest_fail(
+            "BTC",
+            TIME_OF_CLOSE,
+            TIME_OF_RESOLVE,
+            vec![
+                OutcomeBand::new(1, "Over".to_owned(), Some(-10), None),
+                OutcomeBand::new(2, "Under".to_owned(), None, Some(10)),
+            ],
+        );
+    }
+
+    #[test]
+    fn cannot_create_contest_on_missing_or_paused_ticker() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.create_contest_fail("SOL", TIME_OF_CLOSE, TIME_OF_RESOLVE, over_under());
+        test_env.pause_ticker_success("BTC", true);
+        test_env.create_contest_fail("BTC", TIME_OF_CLOSE, TIME_OF_RESOLVE, over_under());
+    }
+
+    #[test]
+    fn cannot_create_same_contest_twice() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.create_contest_success("BTC", TIME_OF_CLOSE, TIME_OF_RESOLVE, over_under());
+        test_env.create_contest_fail("BTC", TIME_OF_CLOSE, TIME_OF_RESOLVE, over_under());
+    }
+
+    #[test]
+    fn cannot_bet_on_closed_custom_contest() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_id =
+            test_env.create_contest_success("BTC", TIME_OF_CLOSE, TIME_OF_RESOLVE, over_under());
+        test_env.bet_on_contest_by_id_fail(&contest_id, &3, &100);
+        test_env.bet_on_contest_by_id_success(&contest_id, &1, &100);
+
+        test_env.set_time(TIME_OF_CLOSE);
+        test_env.bet_on_contest_by_id_fail(&contest_id, &1, &100);
+    }
+
+    #[test]
+    fn bet_and_claim_custom_contest() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_id =
+            test_env.create_contest_success("BTC", TIME_OF_CLOSE, TIME_OF_RESOLVE, over_under());
+        test_env.bet_on_contest_by_id_success(&contest_id, &2, &100);
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_on_contest_by_id_success(&contest_id, &1, &100);
+
+        // Settles more than a day after close, which would have expired an interval contest
+        test_env.set_time(TIME_OF_RESOLVE + 1);
+        test_env.set_oracle_result(PricesByIdsResponse {
+            prices: vec![
+                PricePosting::new(Decimal::from_str("58205.29").unwrap(), TIME_OF_CLOSE),
+                PricePosting::new(Decimal::from_str("60000").unwrap(), TIME_OF_RESOLVE),
+            ],
+        });
+
+        test_env.claim_contest_by_id_success(&contest_id, &198);
+    }
+
+    #[test]
+    fn custom_contest_expiration_window_starts_at_resolve() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_id =
+            test_env.create_contest_success("BTC", TIME_OF_CLOSE, TIME_OF_RESOLVE, over_under());
+        test_env.bet_on_contest_by_id_success(&contest_id, &2, &100);
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_on_contest_by_id_success(&contest_id, &1, &100);
+
+        // Over a day past close but within a day of resolve, so missing prices do not void it
+        test_env.set_time(TIME_OF_RESOLVE + 1);
+        test_env.set_oracle_error(true);
+        test_env.claim_contest_by_id_failure(&contest_id);
+    }
+}
@@ -0,0 +1,208 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{coins, Addr, Uint128};
+
+    use crate::{
+        data::{currency::Currency, roles::Role, state::FeePercent},
+        tests::{
+            constants::{
+                AFTER_TIME_OF_1_CLOSE, AFTER_TIME_OF_2_CLOSE, AFTER_TIME_OF_3_CLOSE,
+                AFTER_TIME_OF_RESOLVE, BASE_FEE_PERCENT_DENOMINATOR, BASE_FEE_PERCENT_NUMERATOR,
+            },
+            test_env::tests::TestEnv,
+        },
+    };
+
+    fn collect_fees(test_env: &mut TestEnv, amount_per_side: &u128) {
+        let contest_file = 1;
+        test_env.set_sender("creator".to_owned());
+        test_env.first_bet_on_contest_success(&contest_file, &1, amount_per_side);
+
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_on_contest_success(&contest_file, &2, amount_per_side);
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.set_sender("creator".to_owned());
+        test_env.claim_success(&contest_file, None);
+    }
+
+    ////////TESTS////////
+    #[test]
+    fn distribute_fees() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        collect_fees(&mut test_env, &1000);
+        test_env.get_claimable_fees(Some(&Uint128::from(20u128)));
+
+        test_env.set_fee_recipients_success(&vec![("treasury", 7500), ("team", 2500)]);
+        test_env.distribute_fees_success(&vec![("treasury", 15), ("team", 5)]);
+        test_env.get_claimable_fees(Some(&Uint128::zero()));
+        test_env.get_fee_payouts(None, 0, 10, &vec![("treasury", 15), ("team", 5)], 2);
+    }
+
+    #[test]
+    fn rounding_dust_stays_claimable() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        collect_fees(&mut test_env, &1000);
+
+        test_env.set_fee_recipients_success(&vec![
+            ("treasury", 3333),
+            ("team", 3333),
+            ("marketing", 3334),
+        ]);
+        test_env.distribute_fees_success(&vec![("treasury", 6), ("team", 6), ("marketing", 6)]);
+        test_env.get_claimable_fees(Some(&Uint128::from(2u128)));
+    }
+
+    #[test]
+    fn recipients_too_small_to_be_paid_are_skipped() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        collect_fees(&mut test_env, &100);
+
+        test_env.set_fee_recipients_success(&vec![("treasury", 9000), ("team", 1000)]);
+        test_env.distribute_fees_success(&vec![("treasury", 1)]);
+        test_env.get_claimable_fees(Some(&Uint128::from(1u128)));
+        test_env.get_fee_payouts(None, 0, 10, &vec![("treasury", 1)], 1);
+    }
+
+    #[test]
+    fn every_payout_is_recorded() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_fee_recipients_success(&vec![("treasury", 5000), ("team", 5000)]);
+
+        test_env.set_sender("creator".to_owned());
+        test_env.first_bet_on_contest_success(&1, &1, &1000);
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_on_contest_success(&1, &2, &1000);
+
+        test_env.set_time(AFTER_TIME_OF_1_CLOSE);
+        test_env.set_sender("creator".to_owned());
+        test_env.first_bet_on_contest_success(&2, &1, &1000);
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_on_contest_success(&2, &2, &1000);
+
+        test_env.set_time(AFTER_TIME_OF_2_CLOSE);
+        test_env.set_sender("creator".to_owned());
+        test_env.claim_success(&1, None);
+        test_env.distribute_fees_success(&vec![("treasury", 10), ("team", 10)]);
+
+        test_env.set_fee_recipients_success(&vec![("treasury", 10000)]);
+        test_env.set_time(AFTER_TIME_OF_3_CLOSE);
+        test_env.claim_success(&2, None);
+        test_env.distribute_fees_success(&vec![("treasury", 20)]);
+
+        test_env.get_fee_payouts(
+            None,
+            0,
+            10,
+            &vec![("treasury", 10), ("team", 10), ("treasury", 20)],
+            3,
+        );
+        test_env.get_fee_payouts(None, 1, 2, &vec![("treasury", 20)], 3);
+        test_env.get_fee_payouts(
+            Some("treasury"),
+            0,
+            10,
+            &vec![("treasury", 10), ("treasury", 20)],
+            2,
+        );
+        test_env.get_fee_payouts(Some("team"), 0, 10, &vec![("team", 10)], 1);
+    }
+
+    #[test]
+    fn cannot_distribute_without_recipients() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        collect_fees(&mut test_env, &1000);
+        test_env.distribute_fees_fail();
+        test_env.get_claimable_fees(Some(&Uint128::from(20u128)));
+    }
+
+    #[test]
+    fn treasury_can_distribute_fees() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        collect_fees(&mut test_env, &1000);
+        test_env.set_fee_recipients_success(&vec![("treasury", 10000)]);
+        test_env.grant_role_success("treasury", Role::Treasury);
+
+        test_env.set_sender("user2".to_owned());
+        test_env.distribute_fees_fail();
+
+        test_env.set_sender("treasury".to_owned());
+        test_env.distribute_fees_success(&vec![("treasury", 20)]);
+    }
+
+    #[test]
+    fn token_fees_are_distributed_in_the_token() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let token = "sATOM Address";
+        test_env.add_accepted_token_success(token, &10, 2);
+        test_env.handle_receive_success(&1, &1, &1000, Addr::unchecked(token));
+        test_env.set_sender("user2".to_owned());
+        test_env.handle_receive_success(&1, &2, &1000, Addr::unchecked(token));
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.set_sender("creator".to_owned());
+        test_env.claim_token_success(&1, &1980, token);
+
+        test_env.set_fee_recipients_success(&vec![("treasury", 7500), ("team", 2500)]);
+        let currency = Currency::Token {
+            address: token.to_owned(),
+        };
+        test_env.distribute_fees_in_success(&currency, &vec![("treasury", 15), ("team", 5)]);
+        test_env.get_accepted_tokens(vec![("Snip20 Address", 1, 0, true), (token, 10, 0, true)]);
+
+        // SNIP-20 fees are untouched and there are none to split
+        test_env.distribute_fees_success(&vec![]);
+        test_env.get_fee_payouts(None, 0, 10, &vec![("treasury", 15), ("team", 5)], 2);
+    }
+
+    #[test]
+    fn native_fees_are_distributed_in_the_denom() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let denom = "uscrt";
+        test_env.set_native_denom_success(Some(denom), Some(1));
+        test_env.bet_native_success("BTC", &1, &coins(1000, denom));
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_native_success("BTC", &2, &coins(1000, denom));
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.set_sender("creator".to_owned());
+        test_env.claim_native_success(&1, &1980, denom);
+
+        test_env.set_fee_recipients_success(&vec![("treasury", 10000)]);
+        let currency = Currency::Native {
+            denom: denom.to_owned(),
+        };
+        test_env.distribute_fees_in_success(&currency, &vec![("treasury", 20)]);
+        test_env.get_native_claimable_fees(vec![(denom, 0)]);
+    }
+}
@@ -0,0 +1,190 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data::state::{ContractStatus, FeePercent},
+        tests::{
+            constants::{
+                AFTER_TIME_OF_1_CLOSE, AFTER_TIME_OF_RESOLVE, BASE_FEE_PERCENT_DENOMINATOR,
+                BASE_FEE_PERCENT_NUMERATOR,
+            },
+            test_env::tests::TestEnv,
+        },
+    };
+
+    ////////TESTS////////
+    #[test]
+    fn set_each_status() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.get_contract_status(ContractStatus::Normal);
+        for status in [
+            ContractStatus::BetsPaused,
+            ContractStatus::ClaimsPaused,
+            ContractStatus::Paused,
+            ContractStatus::FailSafe,
+            ContractStatus::Normal,
+        ] {
+            test_env.fail_safe_success(status);
+            test_env.get_contract_status(status);
+        }
+    }
+
+    #[test]
+    fn cannot_set_status_if_not_admin() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_sender("user2".to_owned());
+        test_env.fail_safe_fail(ContractStatus::Paused);
+        test_env.get_contract_status(ContractStatus::Normal);
+    }
+
+    #[test]
+    fn bets_paused_allows_claims() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &1, &100);
+        test_env.fail_safe_success(ContractStatus::BetsPaused);
+        test_env.bet_on_contest_fail(&contest_file, &1, &100);
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.claim_success(&contest_file, Some(&100));
+    }
+
+    #[test]
+    fn claims_paused_allows_bets() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_file = 1;
+        test_env.fail_safe_success(ContractStatus::ClaimsPaused);
+        test_env.first_bet_on_contest_success(&contest_file, &1, &100);
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.claim_failure(&contest_file);
+
+        test_env.fail_safe_success(ContractStatus::Normal);
+        test_env.claim_success(&contest_file, Some(&100));
+    }
+
+    #[test]
+    fn paused_stops_bets_and_claims() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &1, &100);
+        test_env.fail_safe_success(ContractStatus::Paused);
+        test_env.bet_on_contest_fail(&contest_file, &1, &100);
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.claim_failure(&contest_file);
+    }
+
+    #[test]
+    fn fail_safe_refunds_unresolved_contest() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &1, &100);
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_on_contest_success(&contest_file, &2, &150);
+
+        test_env.set_sender("creator".to_owned());
+        test_env.fail_safe_success(ContractStatus::FailSafe);
+        test_env.bet_on_contest_fail(&contest_file, &1, &100);
+
+        // Open contests are left alone, both sides are refunded once the contest closes
+        test_env.claim_failure(&contest_file);
+        test_env.set_time(AFTER_TIME_OF_1_CLOSE);
+        test_env.claim_success(&contest_file, Some(&100));
+        test_env.claim_failure(&contest_file);
+        test_env.set_sender("user2".to_owned());
+        test_env.users_claimable_contests_has_length(1);
+        test_env.claim_success(&contest_file, Some(&150));
+    }
+
+    #[test]
+    fn fail_safe_keeps_resolved_outcome() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &1, &100);
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_on_contest_success(&contest_file, &2, &100);
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.set_sender("creator".to_owned());
+        test_env.claim_success(&contest_file, Some(&198));
+
+        test_env.fail_safe_success(ContractStatus::FailSafe);
+        test_env.set_sender("user2".to_owned());
+        test_env.claim_failure(&contest_file);
+    }
+
+    #[test]
+    fn contest_stays_refunded_after_fail_safe() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &1, &100);
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_on_contest_success(&contest_file, &2, &100);
+
+        test_env.set_sender("creator".to_owned());
+        test_env.fail_safe_success(ContractStatus::FailSafe);
+        test_env.set_time(AFTER_TIME_OF_1_CLOSE);
+        test_env.claim_success(&contest_file, Some(&100));
+        test_env.fail_safe_success(ContractStatus::Normal);
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.set_sender("user2".to_owned());
+        test_env.claim_success(&contest_file, Some(&100));
+    }
+
+    #[test]
+    fn open_contest_takes_bets_after_fail_safe_ends() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &1, &100);
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_on_contest_success(&contest_file, &2, &100);
+
+        test_env.set_sender("creator".to_owned());
+        test_env.fail_safe_success(ContractStatus::FailSafe);
+        test_env.claim_failure(&contest_file);
+        test_env.users_claimable_contests_has_length(0);
+        test_env.fail_safe_success(ContractStatus::Normal);
+
+        // The contest was never nullified so it keeps taking bets and pays out normally
+        test_env.bet_on_contest_success(&contest_file, &1, &100);
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.claim_success(&contest_file, Some(&297));
+    }
+}
@@ -0,0 +1,223 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data::{
+            outcome_band::OutcomeBand,
+            roles::Role,
+            state::{ContractStatus, FeePercent},
+        },
+        tests::{
+            constants::{
+                AFTER_TIME_OF_RESOLVE, BASE_FEE_PERCENT_DENOMINATOR, BASE_FEE_PERCENT_NUMERATOR,
+            },
+            test_env::tests::TestEnv,
+        },
+    };
+
+    ////////TESTS////////
+    #[test]
+    fn grant_role() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.grant_role_success("treasury", Role::Treasury);
+        test_env.get_roles(&vec![("treasury", vec![Role::Treasury])]);
+    }
+
+    #[test]
+    fn cannot_grant_role_twice() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.grant_role_success("treasury", Role::Treasury);
+        test_env.grant_role_fail("treasury", Role::Treasury);
+    }
+
+    #[test]
+    fn cannot_grant_role_if_not_owner() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.grant_role_success("treasury", Role::Treasury);
+        test_env.set_sender("treasury".to_owned());
+        test_env.grant_role_fail("user2", Role::Treasury);
+    }
+
+    #[test]
+    fn treasury_can_claim_fees_but_not_set_fee() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &1, &100);
+
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_on_contest_success(&contest_file, &2, &100);
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.set_sender("creator".to_owned());
+        test_env.claim_success(&contest_file, Some(&198));
+        test_env.grant_role_success("treasury", Role::Treasury);
+
+        test_env.set_sender("treasury".to_owned());
+        test_env.set_fee_fail(FeePercent::new(2, 100));
+        test_env.set_minimum_bet_fail(&10);
+        test_env.claim_fees_success(Some(&2));
+    }
+
+    #[test]
+    fn fee_manager_can_set_fee_but_not_claim_fees() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.grant_role_success("fee_manager", Role::FeeManager);
+
+        test_env.set_sender("fee_manager".to_owned());
+        test_env.set_fee_success(FeePercent::new(2, 100));
+        test_env.set_minimum_bet_success(&10);
+        test_env.claim_fees_failure();
+    }
+
+    #[test]
+    fn pauser_can_only_change_status() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.grant_role_success("pauser", Role::Pauser);
+
+        test_env.set_sender("pauser".to_owned());
+        test_env.fail_safe_success(ContractStatus::Paused);
+        test_env.get_contract_status(ContractStatus::Paused);
+        test_env.add_ticker_fail("SOL");
+    }
+
+    #[test]
+    fn ticker_manager_can_only_manage_tickers() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.grant_role_success("ticker_manager", Role::TickerManager);
+
+        test_env.set_sender("ticker_manager".to_owned());
+        test_env.add_ticker_success("SOL");
+        test_env.pause_ticker_success("SOL", true);
+        test_env.remove_ticker_success("SOL");
+        test_env.fail_safe_fail(ContractStatus::Paused);
+    }
+
+    #[test]
+    fn treasury_manages_accepted_currencies() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.grant_role_success("treasury", Role::Treasury);
+        test_env.grant_role_success("fee_manager", Role::FeeManager);
+
+        test_env.set_sender("fee_manager".to_owned());
+        test_env.add_accepted_token_fail("sATOM");
+        test_env.set_native_denom_fail(Some("uscrt"), Some(1));
+
+        test_env.set_sender("treasury".to_owned());
+        test_env.add_accepted_token_success("sATOM", &10, 2);
+        test_env.remove_accepted_token_success("sATOM");
+        test_env.set_native_denom_success(Some("uscrt"), Some(1));
+    }
+
+    #[test]
+    fn fee_manager_sets_bet_limits() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.grant_role_success("fee_manager", Role::FeeManager);
+        test_env.grant_role_success("treasury", Role::Treasury);
+
+        test_env.set_sender("treasury".to_owned());
+        test_env.set_bet_limits_fail(Some(500));
+
+        test_env.set_sender("fee_manager".to_owned());
+        test_env.set_bet_limits_success(Some(500), None, None);
+        test_env.get_bet_limits(Some(500), None, None);
+    }
+
+    #[test]
+    fn ticker_manager_sets_expiration_window() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.grant_role_success("ticker_manager", Role::TickerManager);
+        test_env.grant_role_success("pauser", Role::Pauser);
+        let options = vec![
+            OutcomeBand::new(1, "Over".to_owned(), Some(0), None),
+            OutcomeBand::new(2, "Under".to_owned(), None, Some(0)),
+        ];
+
+        test_env.set_sender("pauser".to_owned());
+        test_env.create_contest_fail("BTC", 1571800000, 1571900000, options.clone());
+        test_env.set_expiration_window_fail(3600);
+
+        test_env.set_sender("ticker_manager".to_owned());
+        // Custom contests are created by the owner only
+        test_env.create_contest_fail("BTC", 1571800000, 1571900000, options.clone());
+        test_env.set_expiration_window_success(3600);
+        test_env.get_expiration_window(3600);
+
+        test_env.set_sender("creator".to_owned());
+        test_env.create_contest_success("BTC", 1571800000, 1571900000, options);
+    }
+
+    #[test]
+    fn roles_cannot_use_owner_only_handlers() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &1, &100);
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_on_contest_success(&contest_file, &2, &100);
+        test_env.set_sender("creator".to_owned());
+        for role in [
+            Role::FeeManager,
+            Role::Treasury,
+            Role::Pauser,
+            Role::TickerManager,
+        ] {
+            test_env.grant_role_success("admin", role);
+        }
+        test_env.propose_new_owner_success("new_owner");
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.set_sender("admin".to_owned());
+        test_env.set_max_fee_fail(FeePercent::new(50, 100));
+        test_env.resolve_contest_manually_fail(&contest_file, "10", "9", "oracle outage");
+        test_env.void_contest_fail(&contest_file, "bad listing");
+        test_env.propose_new_owner_fail("admin");
+        test_env.cancel_ownership_transfer_fail();
+        test_env.grant_role_fail("user2", Role::Treasury);
+        test_env.revoke_role_fail("admin", Role::Treasury);
+
+        test_env.set_sender("creator".to_owned());
+        test_env.void_contest_success(&contest_file, "bad listing");
+    }
+}
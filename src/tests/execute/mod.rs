@@ -1,6 +1,31 @@
+pub mod accept_ownership;
+pub mod accepted_tokens;
+pub mod add_ticker;
+pub mod bet_contest_native;
+pub mod cancel_bet;
+pub mod cancel_ownership_transfer;
 pub mod claim;
+pub mod claim_all;
 pub mod claim_fees;
 pub mod claim_multiple;
+pub mod create_contest;
+pub mod distribute_fees;
+pub mod fail_safe;
+pub mod grant_role;
 pub mod handle_receive;
+pub mod pause_ticker;
+pub mod propose_new_owner;
+pub mod remove_ticker;
+pub mod resolve_contest_manually;
+pub mod resolve_contests;
+pub mod revoke_role;
+pub mod set_bet_limits;
+pub mod schedule_fee_change;
+pub mod set_betting_cutoff;
+pub mod set_fee_recipients;
 pub mod set_fees;
 pub mod set_minimum_bet;
+pub mod set_ticker_config;
+pub mod set_ticker_outcomes;
+pub mod switch_side;
+pub mod withdraw_fees;
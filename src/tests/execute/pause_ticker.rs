@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data::state::FeePercent,
+        tests::{
+            constants::{
+                AFTER_TIME_OF_RESOLVE, BASE_FEE_PERCENT_DENOMINATOR, BASE_FEE_PERCENT_NUMERATOR,
+            },
+            test_env::tests::TestEnv,
+        },
+    };
+
+    ////////TESTS////////
+    #[test]
+    fn pause_ticker() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.pause_ticker_success("BTC", true);
+        test_env.get_tickers(&vec![("BTC", false), ("ETH", true)]);
+    }
+
+    #[test]
+    fn unpause_ticker() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.pause_ticker_success("BTC", true);
+        test_env.pause_ticker_success("BTC", false);
+        test_env.get_tickers(&vec![("BTC", true), ("ETH", true)]);
+    }
+
+    #[test]
+    fn cannot_pause_missing_ticker() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.pause_ticker_fail("SOL", true);
+    }
+
+    #[test]
+    fn cannot_pause_ticker_if_not_admin() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_sender("user2".to_owned());
+        test_env.pause_ticker_fail("BTC", true);
+    }
+
+    #[test]
+    fn cannot_bet_on_paused_ticker() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_file = 1;
+        test_env.pause_ticker_success("BTC", true);
+        test_env.first_bet_on_contest_fail(&contest_file, &1, &100);
+    }
+
+    #[test]
+    fn cannot_bet_on_existing_contest_after_pause() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &1, &100);
+        test_env.pause_ticker_success("BTC", true);
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_on_contest_fail(&contest_file, &2, &100);
+    }
+
+    #[test]
+    fn claim_after_ticker_paused() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &1, &100);
+        test_env.pause_ticker_success("BTC", true);
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.claim_success(&contest_file, Some(&100));
+    }
+}
@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data::state::FeePercent,
+        tests::{
+            constants::{BASE_FEE_PERCENT_DENOMINATOR, BASE_FEE_PERCENT_NUMERATOR},
+            test_env::tests::TestEnv,
+        },
+    };
+
+    ////////TESTS////////
+    #[test]
+    fn propose_new_owner() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.propose_new_owner_success("new_owner");
+        test_env.get_owner("creator", Some("new_owner"));
+    }
+
+    #[test]
+    fn propose_replaces_pending_owner() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.propose_new_owner_success("new_owner");
+        test_env.propose_new_owner_success("other_owner");
+        test_env.get_owner("creator", Some("other_owner"));
+    }
+
+    #[test]
+    fn cannot_propose_new_owner_if_not_admin() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_sender("user2".to_owned());
+        test_env.propose_new_owner_fail("user2");
+        test_env.get_owner("creator", None);
+    }
+}
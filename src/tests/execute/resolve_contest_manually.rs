@@ -0,0 +1,181 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Uint128;
+
+    use crate::{
+        constants::EXPIRATION_WINDOW,
+        data::state::FeePercent,
+        tests::{
+            constants::{
+                AFTER_TIME_OF_1_CLOSE, AFTER_TIME_OF_2_CLOSE, AFTER_TIME_OF_RESOLVE,
+                BASE_FEE_PERCENT_DENOMINATOR, BASE_FEE_PERCENT_NUMERATOR,
+            },
+            test_env::tests::TestEnv,
+        },
+    };
+
+    fn bet_on_both_sides(test_env: &mut TestEnv, file_number: &u8) {
+        test_env.set_sender("creator".to_owned());
+        test_env.first_bet_on_contest_success(file_number, &1, &100);
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_on_contest_success(file_number, &2, &100);
+    }
+
+    fn initialized_test_env() -> TestEnv {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env
+    }
+
+    ////////TESTS////////
+    #[test]
+    fn owner_resolves_contest_with_manual_prices() {
+        let mut test_env = initialized_test_env();
+        bet_on_both_sides(&mut test_env, &1);
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.set_sender("creator".to_owned());
+        test_env.resolve_contest_manually_success(&1, "10", "9", "oracle outage", &2);
+        test_env.get_manual_resolution_reason(&1, Some("oracle outage"));
+        test_env.get_claimable_fees(Some(&Uint128::new(2)));
+
+        // The manual outcome overrides what the oracle would have reported
+        test_env.claim_failure(&1);
+        test_env.set_sender("user2".to_owned());
+        test_env.claim_success(&1, Some(&198));
+        test_env.get_claimable_fees(Some(&Uint128::new(2)));
+    }
+
+    #[test]
+    fn owner_resolves_contest_the_oracle_cannot() {
+        let mut test_env = initialized_test_env();
+        bet_on_both_sides(&mut test_env, &1);
+
+        test_env.set_time(AFTER_TIME_OF_2_CLOSE);
+        test_env.set_oracle_error(true);
+        test_env.set_sender("creator".to_owned());
+        test_env.claim_failure(&1);
+
+        test_env.resolve_contest_manually_success(&1, "9", "10", "oracle outage", &1);
+        test_env.claim_success(&1, Some(&198));
+    }
+
+    #[test]
+    fn manual_resolution_requires_owner_and_reason() {
+        let mut test_env = initialized_test_env();
+        bet_on_both_sides(&mut test_env, &1);
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.set_sender("user2".to_owned());
+        test_env.resolve_contest_manually_fail(&1, "10", "9", "oracle outage");
+        test_env.set_sender("creator".to_owned());
+        test_env.resolve_contest_manually_fail(&1, "10", "9", "  ");
+        test_env.get_manual_resolution_reason(&1, None);
+    }
+
+    #[test]
+    fn cannot_resolve_manually_before_time_of_resolve() {
+        let mut test_env = initialized_test_env();
+        bet_on_both_sides(&mut test_env, &1);
+
+        test_env.set_time(AFTER_TIME_OF_1_CLOSE);
+        test_env.set_sender("creator".to_owned());
+        test_env.resolve_contest_manually_fail(&1, "10", "9", "oracle outage");
+    }
+
+    #[test]
+    fn cannot_resolve_manually_once_outcome_is_set() {
+        let mut test_env = initialized_test_env();
+        bet_on_both_sides(&mut test_env, &1);
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.set_sender("creator".to_owned());
+        test_env.claim_success(&1, Some(&198));
+        test_env.resolve_contest_manually_fail(&1, "10", "9", "oracle outage");
+        test_env.void_contest_fail(&1, "oracle outage");
+    }
+
+    #[test]
+    fn one_sided_contest_is_nullified_by_manual_resolution() {
+        let mut test_env = initialized_test_env();
+        test_env.first_bet_on_contest_success(&1, &1, &100);
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.resolve_contest_manually_success(&1, "9", "10", "oracle outage", &0);
+        test_env.claim_success(&1, Some(&100));
+        test_env.get_claimable_fees(Some(&Uint128::zero()));
+    }
+
+    #[test]
+    fn voided_contest_refunds_every_bet() {
+        let mut test_env = initialized_test_env();
+        bet_on_both_sides(&mut test_env, &1);
+
+        test_env.set_time(AFTER_TIME_OF_1_CLOSE);
+        test_env.void_contest_fail(&1, "bad listing");
+        test_env.set_sender("creator".to_owned());
+        test_env.void_contest_fail(&1, "");
+        test_env.void_contest_success(&1, "bad listing");
+        test_env.get_manual_resolution_reason(&1, Some("bad listing"));
+        test_env.void_contest_fail(&1, "bad listing");
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.resolve_contest_manually_fail(&1, "9", "10", "oracle outage");
+        test_env.claim_success(&1, Some(&100));
+        test_env.set_sender("user2".to_owned());
+        test_env.claim_success(&1, Some(&100));
+        test_env.get_claimable_fees(Some(&Uint128::zero()));
+    }
+
+    #[test]
+    fn cannot_void_contest_before_it_closes() {
+        let mut test_env = initialized_test_env();
+        bet_on_both_sides(&mut test_env, &1);
+
+        test_env.set_sender("creator".to_owned());
+        test_env.void_contest_fail(&1, "bad listing");
+    }
+
+    #[test]
+    fn owner_sets_expiration_window() {
+        let mut test_env = initialized_test_env();
+        test_env.get_expiration_window(EXPIRATION_WINDOW);
+
+        test_env.set_expiration_window_success(3600);
+        test_env.get_expiration_window(3600);
+
+        test_env.set_sender("user2".to_owned());
+        test_env.set_expiration_window_fail(60);
+        test_env.get_expiration_window(3600);
+    }
+
+    #[test]
+    fn expiration_window_nullifies_contest_when_oracle_fails() {
+        let mut test_env = initialized_test_env();
+        bet_on_both_sides(&mut test_env, &1);
+        test_env.set_sender("creator".to_owned());
+        test_env.set_expiration_window_success(0);
+
+        test_env.set_time(AFTER_TIME_OF_2_CLOSE);
+        test_env.set_oracle_error(true);
+        test_env.claim_success(&1, Some(&100));
+        test_env.get_claimable_fees(Some(&Uint128::zero()));
+    }
+
+    #[test]
+    fn interval_contest_expiration_window_starts_at_close() {
+        let mut test_env = initialized_test_env();
+        bet_on_both_sides(&mut test_env, &1);
+        test_env.set_sender("creator".to_owned());
+        test_env.set_expiration_window_success(200);
+
+        // Past close plus the window but not yet past resolve plus the window
+        test_env.set_time(AFTER_TIME_OF_2_CLOSE);
+        test_env.set_oracle_error(true);
+        test_env.claim_success(&1, Some(&100));
+        test_env.get_claimable_fees(Some(&Uint128::zero()));
+    }
+}
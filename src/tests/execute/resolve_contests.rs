@@ -0,0 +1,122 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Uint128;
+
+    use crate::{
+        data::{roles::Role, state::FeePercent},
+        tests::{
+            constants::{
+                AFTER_TIME_OF_1_CLOSE, AFTER_TIME_OF_RESOLVE, BASE_FEE_PERCENT_DENOMINATOR,
+                BASE_FEE_PERCENT_NUMERATOR,
+            },
+            test_env::tests::TestEnv,
+        },
+    };
+
+    fn bet_on_both_sides(test_env: &mut TestEnv, file_number: &u8) {
+        test_env.set_sender("creator".to_owned());
+        test_env.first_bet_on_contest_success(file_number, &1, &100);
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_on_contest_success(file_number, &2, &100);
+    }
+
+    ////////TESTS////////
+    #[test]
+    fn anyone_can_resolve_and_fees_are_taken() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        bet_on_both_sides(&mut test_env, &1);
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.set_sender("keeper".to_owned());
+        test_env.resolve_contests_success(&[1], &[(1, 0)]);
+        test_env.get_claimable_fees(Some(&Uint128::new(2)));
+
+        // Claiming afterwards pays out the same and takes no second fee
+        test_env.set_sender("creator".to_owned());
+        test_env.claim_success(&1, Some(&198));
+        test_env.get_claimable_fees(Some(&Uint128::new(2)));
+    }
+
+    #[test]
+    fn keeper_is_paid_a_share_of_the_fee() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_keeper_reward_success(FeePercent::new(1, 2));
+        bet_on_both_sides(&mut test_env, &1);
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.set_sender("keeper".to_owned());
+        test_env.resolve_contests_success(&[1], &[(1, 1)]);
+        test_env.get_claimable_fees(Some(&Uint128::new(1)));
+
+        test_env.set_sender("creator".to_owned());
+        test_env.claim_success(&1, Some(&198));
+    }
+
+    #[test]
+    fn skips_contests_that_cannot_be_resolved() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_keeper_reward_success(FeePercent::new(1, 2));
+        bet_on_both_sides(&mut test_env, &1);
+        test_env.set_sender("creator".to_owned());
+        test_env.set_time(AFTER_TIME_OF_1_CLOSE);
+        test_env.first_bet_on_contest_success(&2, &1, &100);
+
+        test_env.set_sender("keeper".to_owned());
+        test_env.resolve_contests_success(&[1, 2], &[]);
+
+        // The one sided contest is nullified so there is no fee to share
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.resolve_contests_success(&[1, 2, 1], &[(1, 1), (2, 0)]);
+        test_env.resolve_contests_success(&[1, 2], &[]);
+        test_env.get_claimable_fees(Some(&Uint128::new(1)));
+    }
+
+    #[test]
+    fn set_keeper_reward_requires_fee_manager() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_sender("fee_manager".to_owned());
+        test_env.set_keeper_reward_fail(FeePercent::new(1, 2));
+
+        test_env.set_sender("creator".to_owned());
+        test_env.grant_role_success("fee_manager", Role::FeeManager);
+        test_env.set_sender("fee_manager".to_owned());
+        test_env.set_keeper_reward_success(FeePercent::new(1, 2));
+        test_env.set_keeper_reward_fail(FeePercent::new(3, 2));
+        test_env.set_keeper_reward_fail(FeePercent::new(1, 0));
+    }
+
+    #[test]
+    fn contest_with_legacy_zero_fee_resolves_without_fees() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_stored_fee_percent(FeePercent::new(0, 0));
+        bet_on_both_sides(&mut test_env, &1);
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.set_sender("keeper".to_owned());
+        test_env.resolve_contests_success(&[1], &[(1, 0)]);
+        test_env.get_claimable_fees(Some(&Uint128::zero()));
+
+        test_env.set_sender("creator".to_owned());
+        test_env.claim_success(&1, Some(&200));
+    }
+}
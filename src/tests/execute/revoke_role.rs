@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data::{roles::Role, state::FeePercent},
+        tests::{
+            constants::{BASE_FEE_PERCENT_DENOMINATOR, BASE_FEE_PERCENT_NUMERATOR},
+            test_env::tests::TestEnv,
+        },
+    };
+
+    ////////TESTS////////
+    #[test]
+    fn revoke_role() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.grant_role_success("fee_manager", Role::FeeManager);
+        test_env.revoke_role_success("fee_manager", Role::FeeManager);
+        test_env.get_roles(&vec![]);
+
+        test_env.set_sender("fee_manager".to_owned());
+        test_env.set_minimum_bet_fail(&10);
+    }
+
+    #[test]
+    fn revoke_keeps_other_roles() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.grant_role_success("admin", Role::FeeManager);
+        test_env.grant_role_success("admin", Role::Treasury);
+        test_env.revoke_role_success("admin", Role::FeeManager);
+        test_env.get_roles(&vec![("admin", vec![Role::Treasury])]);
+    }
+
+    #[test]
+    fn cannot_revoke_role_not_granted() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.grant_role_success("admin", Role::Treasury);
+        test_env.revoke_role_fail("admin", Role::FeeManager);
+        test_env.revoke_role_fail("user2", Role::Treasury);
+    }
+
+    #[test]
+    fn cannot_revoke_role_if_not_owner() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.grant_role_success("admin", Role::Treasury);
+        test_env.set_sender("admin".to_owned());
+        test_env.revoke_role_fail("admin", Role::Treasury);
+    }
+}
@@ -0,0 +1,132 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data::{roles::Role, state::FeePercent},
+        tests::{
+            constants::{
+                AFTER_TIME_OF_1_CLOSE, AFTER_TIME_OF_2_CLOSE, AFTER_TIME_OF_3_CLOSE,
+                BASE_FEE_PERCENT_DENOMINATOR, BASE_FEE_PERCENT_NUMERATOR,
+            },
+            test_env::tests::TestEnv,
+        },
+    };
+
+    ////////TESTS////////
+    #[test]
+    fn scheduled_fee_applies_to_contests_created_after_it_is_due() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.schedule_fee_change_success(FeePercent::new(5, 100), AFTER_TIME_OF_1_CLOSE);
+
+        // Contest 1 is created before the change is due and keeps the old fee
+        test_env.first_bet_on_contest_success(&1, &1, &100);
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_on_contest_success(&1, &2, &100);
+        test_env.get_fee_percent(None);
+
+        test_env.set_time(AFTER_TIME_OF_1_CLOSE);
+        test_env.get_fee_percent(Some(&FeePercent::new(5, 100)));
+        test_env.get_pending_fee_change(None);
+
+        test_env.set_sender("creator".to_owned());
+        test_env.bet_on_contest_success(&2, &1, &100);
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_on_contest_success(&2, &2, &100);
+
+        test_env.set_time(AFTER_TIME_OF_2_CLOSE);
+        test_env.set_sender("creator".to_owned());
+        test_env.claim_success(&1, Some(&198));
+
+        test_env.set_time(AFTER_TIME_OF_3_CLOSE);
+        test_env.claim_success(&2, Some(&190));
+        test_env.claim_fees_success(Some(&12));
+    }
+
+    #[test]
+    fn pending_fee_change_is_visible_until_due() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.get_pending_fee_change(None);
+
+        test_env.schedule_fee_change_success(FeePercent::new(5, 100), AFTER_TIME_OF_1_CLOSE);
+        test_env.get_pending_fee_change(Some((FeePercent::new(5, 100), AFTER_TIME_OF_1_CLOSE)));
+        test_env.get_fee_percent(None);
+
+        // Rescheduling replaces the pending change
+        test_env.schedule_fee_change_success(FeePercent::new(3, 100), AFTER_TIME_OF_2_CLOSE);
+        test_env.get_pending_fee_change(Some((FeePercent::new(3, 100), AFTER_TIME_OF_2_CLOSE)));
+
+        test_env.set_time(AFTER_TIME_OF_2_CLOSE);
+        test_env.get_pending_fee_change(None);
+        test_env.get_fee_percent(Some(&FeePercent::new(3, 100)));
+    }
+
+    #[test]
+    fn set_fee_keeps_future_fee_change() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.schedule_fee_change_success(FeePercent::new(5, 100), AFTER_TIME_OF_1_CLOSE);
+        test_env.set_fee_success(FeePercent::new(2, 100));
+        test_env.get_fee_percent(Some(&FeePercent::new(2, 100)));
+
+        test_env.set_time(AFTER_TIME_OF_1_CLOSE);
+        test_env.get_fee_percent(Some(&FeePercent::new(5, 100)));
+
+        // Once due, a direct fee change supersedes the scheduled one
+        test_env.set_fee_success(FeePercent::new(4, 100));
+        test_env.get_fee_percent(Some(&FeePercent::new(4, 100)));
+    }
+
+    #[test]
+    fn cannot_schedule_fee_change_in_the_past() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_time(AFTER_TIME_OF_1_CLOSE);
+        test_env.schedule_fee_change_fail(FeePercent::new(5, 100), AFTER_TIME_OF_1_CLOSE);
+        test_env.schedule_fee_change_fail(FeePercent::new(5, 100), 0);
+        test_env.get_pending_fee_change(None);
+    }
+
+    #[test]
+    fn cannot_schedule_invalid_fee() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.schedule_fee_change_fail(FeePercent::new(1, 0), AFTER_TIME_OF_1_CLOSE);
+        test_env.schedule_fee_change_fail(FeePercent::new(11, 100), AFTER_TIME_OF_1_CLOSE);
+
+        test_env.schedule_fee_change_success(FeePercent::new(8, 100), AFTER_TIME_OF_1_CLOSE);
+        test_env.set_max_fee_fail(FeePercent::new(5, 100));
+    }
+
+    #[test]
+    fn only_fee_manager_can_schedule_fee_change() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.grant_role_success("treasury", Role::Treasury);
+        test_env.grant_role_success("fee_manager", Role::FeeManager);
+
+        test_env.set_sender("treasury".to_owned());
+        test_env.schedule_fee_change_fail(FeePercent::new(5, 100), AFTER_TIME_OF_1_CLOSE);
+
+        test_env.set_sender("fee_manager".to_owned());
+        test_env.schedule_fee_change_success(FeePercent::new(5, 100), AFTER_TIME_OF_1_CLOSE);
+    }
+}
@@ -0,0 +1,142 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::coins;
+
+    use crate::{
+        data::{currency::Currency, state::FeePercent},
+        responses::execute::response_types::bet::BetErrorCode,
+        tests::{
+            constants::{
+                AFTER_TIME_OF_1_CLOSE, AFTER_TIME_OF_2_CLOSE, BASE_FEE_PERCENT_DENOMINATOR,
+                BASE_FEE_PERCENT_NUMERATOR,
+            },
+            test_env::tests::TestEnv,
+        },
+    };
+
+    ////////TESTS////////
+    #[test]
+    fn no_limits_by_default() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.get_bet_limits(None, None, None);
+    }
+
+    #[test]
+    fn only_owner_sets_bet_limits() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_sender("user2".to_owned());
+        test_env.set_bet_limits_fail(Some(100));
+
+        test_env.set_sender("creator".to_owned());
+        test_env.set_bet_limits_success(Some(100), Some(1000), Some(500));
+        test_env.get_bet_limits(Some(100), Some(1000), Some(500));
+        test_env.set_bet_limits_success(None, None, None);
+        test_env.get_bet_limits(None, None, None);
+    }
+
+    #[test]
+    fn user_bet_limit_counts_whole_stake_on_contest() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_bet_limits_success(Some(100), None, None);
+
+        test_env.bet_on_contest_fail(&1, &1, &101);
+        test_env.first_bet_on_contest_success(&1, &1, &60);
+        test_env.bet_on_contest_fail(&1, &1, &41);
+        test_env.bet_refund_on_failure(&1, &1, &41, Some(BetErrorCode::UserBetLimitExceeded));
+        test_env.bet_on_contest_success(&1, &1, &40);
+        test_env.get_user_bet_success(&1, Some(&100), Some(&1), Some(&false));
+
+        // The limit is per user
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_on_contest_success(&1, &2, &100);
+    }
+
+    #[test]
+    fn pool_limit_counts_every_bet_on_contest() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_bet_limits_success(None, Some(150), None);
+
+        test_env.bet_refund_on_failure(&1, &1, &151, Some(BetErrorCode::PoolLimitExceeded));
+        test_env.get_number_of_contests(Some(&0));
+        test_env.first_bet_on_contest_success(&1, &1, &100);
+
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_refund_on_failure(&1, &2, &51, Some(BetErrorCode::PoolLimitExceeded));
+        test_env.bet_on_contest_success(&1, &2, &50);
+    }
+
+    #[test]
+    fn exposure_limit_frees_up_once_contests_resolve() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_bet_limits_success(None, None, Some(100));
+        test_env.first_bet_on_contest_success(&1, &1, &60);
+
+        // Contest 1 has closed but not resolved so its stake is still open
+        test_env.set_time(AFTER_TIME_OF_1_CLOSE);
+        test_env.bet_refund_on_failure(&2, &1, &50, Some(BetErrorCode::ExposureLimitExceeded));
+        test_env.first_bet_on_contest_success(&2, &1, &40);
+
+        test_env.set_time(AFTER_TIME_OF_2_CLOSE);
+        test_env.bet_refund_on_failure(&3, &1, &61, Some(BetErrorCode::ExposureLimitExceeded));
+        test_env.first_bet_on_contest_success(&3, &1, &60);
+    }
+
+    #[test]
+    fn cancelled_bet_no_longer_counts_towards_exposure() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_bet_limits_success(None, None, Some(100));
+        test_env.first_bet_on_contest_success(&1, &1, &100);
+        test_env.first_bet_on_contest_fail(&14, &1, &10);
+
+        test_env.cancel_bet_success(&1, &100, &0);
+        test_env.first_bet_on_contest_success(&14, &1, &100);
+    }
+
+    #[test]
+    fn limits_are_set_per_currency() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_native_denom_success(Some("uscrt"), Some(1));
+        let native = Currency::Native {
+            denom: "uscrt".to_owned(),
+        };
+        test_env.set_bet_limits_success(Some(100), None, None);
+        test_env.get_bet_limits_in(Some(native.clone()), None, None, None);
+
+        // The SNIP-20 limit does not apply to native stakes
+        test_env.bet_native_success("BTC", &1, &coins(300, "uscrt"));
+
+        test_env.set_bet_limits_in_success(Some(native.clone()), Some(400), None, None);
+        test_env.get_bet_limits_in(Some(native), Some(400), None, None);
+        test_env.get_bet_limits(Some(100), None, None);
+        test_env.bet_native_fail("BTC", &1, &coins(101, "uscrt"));
+        test_env.bet_native_success("BTC", &1, &coins(100, "uscrt"));
+    }
+}
@@ -0,0 +1,154 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data::{outcome_band::OutcomeBand, roles::Role, state::FeePercent},
+        tests::{
+            constants::{BASE_FEE_PERCENT_DENOMINATOR, BASE_FEE_PERCENT_NUMERATOR},
+            test_env::tests::TestEnv,
+        },
+    };
+
+    // The default block time is 81 seconds before contest 1 closes
+    const INSIDE_CUTOFF: u64 = 120;
+    const AT_CUTOFF: u64 = 81;
+    const OUTSIDE_CUTOFF: u64 = 60;
+
+    ////////TESTS////////
+    #[test]
+    fn set_betting_cutoff() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.get_betting_cutoff(0);
+        test_env.set_betting_cutoff_success(INSIDE_CUTOFF);
+        test_env.get_betting_cutoff(INSIDE_CUTOFF);
+    }
+
+    #[test]
+    fn ticker_manager_can_set_betting_cutoff() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.grant_role_success("manager", Role::TickerManager);
+
+        test_env.set_sender("user2".to_owned());
+        test_env.set_betting_cutoff_fail(INSIDE_CUTOFF);
+
+        test_env.set_sender("manager".to_owned());
+        test_env.set_betting_cutoff_success(INSIDE_CUTOFF);
+        test_env.get_betting_cutoff(INSIDE_CUTOFF);
+    }
+
+    #[test]
+    fn bet_inside_cutoff_is_rejected() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_betting_cutoff_success(INSIDE_CUTOFF);
+        test_env.bet_on_ticker_fail("BTC", &1, &100, None);
+        test_env.bet_on_ticker_fail("BTC", &1, &100, Some(false));
+        test_env.get_number_of_contests(Some(&0));
+    }
+
+    #[test]
+    fn bet_inside_cutoff_rolls_over() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_betting_cutoff_success(INSIDE_CUTOFF);
+        test_env.bet_on_ticker_success("BTC", &1, &100, Some(true));
+
+        test_env.get_user_bet_failure(&1);
+        test_env.get_user_bet_success(&2, Some(&100), Some(&1), None);
+    }
+
+    #[test]
+    fn bet_outside_cutoff_is_unaffected() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_betting_cutoff_success(OUTSIDE_CUTOFF);
+        test_env.bet_on_ticker_success("BTC", &1, &100, None);
+        test_env.bet_on_ticker_success("BTC", &1, &100, Some(true));
+
+        test_env.get_user_bet_success(&1, Some(&200), Some(&1), None);
+    }
+
+    #[test]
+    fn bet_exactly_at_cutoff_is_rejected() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_betting_cutoff_success(AT_CUTOFF);
+        test_env.bet_on_ticker_fail("BTC", &1, &100, None);
+        test_env.bet_on_ticker_success("BTC", &1, &100, Some(true));
+
+        test_env.get_user_bet_failure(&1);
+        test_env.get_user_bet_success(&2, Some(&100), Some(&1), None);
+    }
+
+    #[test]
+    fn bet_one_second_before_cutoff_is_accepted() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_betting_cutoff_success(AT_CUTOFF - 1);
+        test_env.bet_on_ticker_success("BTC", &1, &100, None);
+
+        test_env.get_user_bet_success(&1, Some(&100), Some(&1), None);
+    }
+
+    #[test]
+    fn cutoff_longer_than_interval_rolls_over_several_contests() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_betting_cutoff_success(400);
+        test_env.bet_on_ticker_success("BTC", &1, &100, Some(true));
+
+        test_env.get_user_bet_failure(&2);
+        test_env.get_user_bet_success(&3, Some(&100), Some(&1), None);
+    }
+
+    #[test]
+    fn bet_inside_cutoff_of_custom_contest_is_rejected() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_id = test_env.create_contest_success(
+            "BTC",
+            1571797500,
+            1571800000,
+            vec![
+                OutcomeBand::new(1, "Over".to_owned(), Some(0), None),
+                OutcomeBand::new(2, "Under".to_owned(), None, Some(0)),
+            ],
+        );
+        test_env.set_betting_cutoff_success(INSIDE_CUTOFF);
+        test_env.bet_on_contest_by_id_fail(&contest_id, &1, &100);
+
+        test_env.set_betting_cutoff_success(AT_CUTOFF);
+        test_env.bet_on_contest_by_id_fail(&contest_id, &1, &100);
+
+        test_env.set_betting_cutoff_success(OUTSIDE_CUTOFF);
+        test_env.bet_on_contest_by_id_success(&contest_id, &1, &100);
+    }
+}
@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data::{roles::Role, state::FeePercent},
+        tests::{
+            constants::{BASE_FEE_PERCENT_DENOMINATOR, BASE_FEE_PERCENT_NUMERATOR},
+            test_env::tests::TestEnv,
+        },
+    };
+
+    ////////TESTS////////
+    #[test]
+    fn set_fee_recipients() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_fee_recipients_success(&vec![("treasury", 7000), ("team", 3000)]);
+        test_env.get_fee_recipients(&vec![("treasury", 7000), ("team", 3000)]);
+
+        test_env.set_fee_recipients_success(&vec![("treasury", 10000)]);
+        test_env.get_fee_recipients(&vec![("treasury", 10000)]);
+    }
+
+    #[test]
+    fn treasury_can_set_fee_recipients() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.grant_role_success("treasury", Role::Treasury);
+        test_env.grant_role_success("fee_manager", Role::FeeManager);
+
+        test_env.set_sender("fee_manager".to_owned());
+        test_env.set_fee_recipients_fail(&vec![("fee_manager", 10000)]);
+
+        test_env.set_sender("treasury".to_owned());
+        test_env.set_fee_recipients_success(&vec![("treasury", 10000)]);
+    }
+
+    #[test]
+    fn cannot_set_fee_recipients_if_not_admin() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_sender("user2".to_owned());
+        test_env.set_fee_recipients_fail(&vec![("user2", 10000)]);
+        test_env.get_fee_recipients(&vec![]);
+    }
+
+    #[test]
+    fn cannot_set_shares_not_totalling_10000() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_fee_recipients_fail(&vec![("treasury", 7000), ("team", 2000)]);
+        test_env.set_fee_recipients_fail(&vec![("treasury", 7000), ("team", 4000)]);
+        test_env.set_fee_recipients_fail(&vec![]);
+    }
+
+    #[test]
+    fn cannot_set_invalid_recipients() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_fee_recipients_fail(&vec![("treasury", 10000), ("team", 0)]);
+        test_env.set_fee_recipients_fail(&vec![("treasury", 5000), ("treasury", 5000)]);
+    }
+}
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use crate::{
-        data::state::FeePercent,
+        data::{roles::Role, state::FeePercent},
         tests::{
             constants::{
                 AFTER_TIME_OF_1_CLOSE, AFTER_TIME_OF_2_CLOSE, AFTER_TIME_OF_3_CLOSE,
@@ -45,7 +45,7 @@ mod tests {
             BASE_FEE_PERCENT_NUMERATOR,
             BASE_FEE_PERCENT_DENOMINATOR,
         ));
-        let new_fee = FeePercent::new(0, 0);
+        let new_fee = FeePercent::new(0, 100);
 
         test_env.set_fee_success(new_fee);
 
@@ -64,6 +64,95 @@ mod tests {
         test_env.claim_fees_success(Some(&0));
     }
 
+    #[test]
+    fn cannot_set_fee_with_zero_denominator() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_fee_fail(FeePercent::new(0, 0));
+        test_env.set_fee_fail(FeePercent::new(1, 0));
+        test_env.get_fee_percent(None);
+    }
+
+    #[test]
+    fn cannot_set_fee_numerator_above_denominator() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_max_fee_success(FeePercent::new(1, 1));
+        test_env.set_fee_fail(FeePercent::new(101, 100));
+        test_env.set_fee_success(FeePercent::new(100, 100));
+    }
+
+    #[test]
+    fn cannot_set_fee_above_max() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.get_max_fee_percent(&FeePercent::new(10, 100));
+        test_env.set_fee_fail(FeePercent::new(11, 100));
+        test_env.set_fee_fail(FeePercent::new(101, 1000));
+        test_env.set_fee_success(FeePercent::new(1, 10));
+        test_env.get_fee_percent(Some(&FeePercent::new(1, 10)));
+    }
+
+    #[test]
+    fn set_fee_after_raising_max() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_max_fee_success(FeePercent::new(20, 100));
+        test_env.get_max_fee_percent(&FeePercent::new(20, 100));
+        test_env.set_fee_success(FeePercent::new(15, 100));
+    }
+
+    #[test]
+    fn cannot_set_max_below_current_fee() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_fee_success(FeePercent::new(5, 100));
+        test_env.set_max_fee_fail(FeePercent::new(4, 100));
+        test_env.set_max_fee_fail(FeePercent::new(1, 0));
+        test_env.set_max_fee_success(FeePercent::new(5, 100));
+    }
+
+    #[test]
+    fn only_owner_can_set_max_fee() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.grant_role_success("fee_manager", Role::FeeManager);
+
+        test_env.set_sender("fee_manager".to_owned());
+        test_env.set_max_fee_fail(FeePercent::new(50, 100));
+        test_env.set_fee_fail(FeePercent::new(50, 100));
+    }
+
+    #[test]
+    fn cannot_initialize_with_invalid_fee() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize_fail(FeePercent::new(1, 0));
+
+        let mut test_env = TestEnv::new();
+        test_env.initialize_fail(FeePercent::new(2, 1));
+
+        let mut test_env = TestEnv::new();
+        test_env.initialize_fail(FeePercent::new(11, 100));
+    }
+
     #[test]
     fn fail_post_update_not_owner() {
         let mut test_env = TestEnv::new();
@@ -79,7 +168,7 @@ mod tests {
     #[test]
     fn init_fee_0() {
         let mut test_env = TestEnv::new();
-        test_env.initialize(FeePercent::new(0, 0));
+        test_env.initialize(FeePercent::new(0, 100));
         let contest_file = 1;
         test_env.first_bet_on_contest_success(&contest_file, &1, &100);
 
@@ -98,7 +187,7 @@ mod tests {
     #[test]
     fn init_fee_0_changes_1() {
         let mut test_env = TestEnv::new();
-        test_env.initialize(FeePercent::new(0, 0));
+        test_env.initialize(FeePercent::new(0, 100));
         let mut contest_file = 1;
         test_env.first_bet_on_contest_success(&contest_file, &1, &100);
 
@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Addr, ContractInfo};
+
+    use crate::{
+        data::state::FeePercent,
+        tests::{
+            constants::{
+                AFTER_TIME_OF_1_CLOSE, BASE_FEE_PERCENT_DENOMINATOR, BASE_FEE_PERCENT_NUMERATOR,
+            },
+            test_env::tests::TestEnv,
+        },
+    };
+
+    fn global_price_feed() -> ContractInfo {
+        ContractInfo {
+            address: Addr::unchecked("Price Feed Address"),
+            code_hash: "Price Feed CodeHash".to_owned(),
+        }
+    }
+
+    fn btc_price_feed() -> ContractInfo {
+        ContractInfo {
+            address: Addr::unchecked("BTC Price Feed Address"),
+            code_hash: "BTC Price Feed CodeHash".to_owned(),
+        }
+    }
+
+    ////////TESTS////////
+    #[test]
+    fn set_ticker_config() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_ticker_config_success("BTC", Some(3600), Some(btc_price_feed()));
+        test_env.set_ticker_config_success("BTC", None, None);
+    }
+
+    #[test]
+    fn cannot_set_ticker_config_if_not_admin() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_sender("user2".to_owned());
+        test_env.set_ticker_config_fail("BTC", Some(3600), None);
+    }
+
+    #[test]
+    fn cannot_set_config_on_missing_ticker() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_ticker_config_fail("SOL", Some(3600), None);
+    }
+
+    #[test]
+    fn cannot_set_invalid_interval() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_ticker_config_fail("BTC", Some(0), None);
+        test_env.set_ticker_config_fail("BTC", Some(90), None);
+    }
+
+    #[test]
+    fn bet_on_ticker_with_own_interval() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_ticker_config_success("ETH", Some(3600), None);
+
+        // ETH rounds are hourly while BTC keeps the global interval
+        test_env.first_bet_on_contest_success(&14, &1, &100);
+        test_env.get_contest_success(&14);
+        test_env.first_bet_on_contest_success(&1, &1, &100);
+        test_env.get_contest_success(&1);
+    }
+
+    #[test]
+    fn contest_uses_global_price_feed_by_default() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.first_bet_on_contest_success(&1, &1, &100);
+        test_env.get_contest_price_feed(&1, &global_price_feed());
+    }
+
+    #[test]
+    fn contest_keeps_price_feed_after_change() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.first_bet_on_contest_success(&1, &1, &100);
+        test_env.set_ticker_config_success("BTC", None, Some(btc_price_feed()));
+
+        test_env.set_time(AFTER_TIME_OF_1_CLOSE);
+        test_env.first_bet_on_contest_success(&2, &1, &100);
+
+        test_env.get_contest_price_feed(&1, &global_price_feed());
+        test_env.get_contest_price_feed(&2, &btc_price_feed());
+    }
+}
@@ -0,0 +1,235 @@
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use cosmwasm_std::Decimal;
+    use sp_secret_toolkit::price_feed::{
+        data::price_posting::PricePosting,
+        response::response_types::prices_by_ids::PricesByIdsResponse,
+    };
+
+    use crate::{
+        data::{outcome_band::OutcomeBand, roles::Role, state::FeePercent},
+        tests::{
+            constants::{
+                AFTER_TIME_OF_RESOLVE, BASE_FEE_PERCENT_DENOMINATOR, BASE_FEE_PERCENT_NUMERATOR,
+            },
+            test_env::tests::TestEnv,
+        },
+    };
+
+    // Up more than 1%, within 1% either way, or down more than 1%
+    fn three_way_bands() -> Vec<OutcomeBand> {
+        vec![
+            OutcomeBand::new(1, "Up".to_owned(), Some(100), None),
+            OutcomeBand::new(2, "Flat".to_owned(), Some(-100), Some(100)),
+            OutcomeBand::new(3, "Down".to_owned(), None, Some(-100)),
+        ]
+    }
+
+    fn set_resolve_price(test_env: &mut TestEnv, resolve_price: &str) {
+        test_env.set_oracle_result(PricesByIdsResponse {
+            prices: vec![
+                PricePosting::new(Decimal::from_str("58205.43").unwrap(), 1571797500),
+                PricePosting::new(Decimal::from_str(resolve_price).unwrap(), 1571797800),
+            ],
+        });
+    }
+
+    ////////TESTS////////
+    #[test]
+    fn set_ticker_outcomes() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_ticker_outcomes_success("BTC", Some(three_way_bands()));
+        test_env.set_ticker_outcomes_success("BTC", None);
+    }
+
+    #[test]
+    fn ticker_manager_can_set_ticker_outcomes() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.grant_role_success("manager", Role::TickerManager);
+
+        test_env.set_sender("user2".to_owned());
+        test_env.set_ticker_outcomes_fail("BTC", Some(three_way_bands()));
+
+        test_env.set_sender("manager".to_owned());
+        test_env.set_ticker_outcomes_success("BTC", Some(three_way_bands()));
+    }
+
+    #[test]
+    fn cannot_set_outcomes_on_missing_ticker() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_ticker_outcomes_fail("SOL", Some(three_way_bands()));
+    }
+
+    #[test]
+    fn cannot_set_invalid_outcome_bands() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        // Too few bands
+        test_env.set_ticker_outcomes_fail("BTC", Some(vec![]));
+        test_env.set_ticker_outcomes_fail(
+            "BTC",
+            Some(vec![OutcomeBand::new(1, "Any".to_owned(), None, None)]),
+        );
+        // Id 0 is reserved for null and void
+        test_env.set_ticker_outcomes_fail(
+            "BTC",
+            Some(vec![
+                OutcomeBand::new(0, "Up".to_owned(), Some(0), None),
+                OutcomeBand::new(1, "Down".to_owned(), None, Some(0)),
+            ]),
+        );
+        // Duplicate ids
+        test_env.set_ticker_outcomes_fail(
+            "BTC",
+            Some(vec![
+                OutcomeBand::new(1, "Up".to_owned(), Some(0), None),
+                OutcomeBand::new(1, "Down".to_owned(), None, Some(0)),
+            ]),
+        );
+        // Empty band
+        test_env.set_ticker_outcomes_fail(
+            "BTC",
+            Some(vec![
+                OutcomeBand::new(1, "Up".to_owned(), Some(100), Some(100)),
+                OutcomeBand::new(2, "Down".to_owned(), None, Some(0)),
+            ]),
+        );
+        // Below -100%
+        test_env.set_ticker_outcomes_fail(
+            "BTC",
+            Some(vec![
+                OutcomeBand::new(1, "Up".to_owned(), Some(0), None),
+                OutcomeBand::new(2, "Down".to_owned(), Some(-10_001), Some(0)),
+            ]),
+        );
+        // Overlapping
+        test_env.set_ticker_outcomes_fail(
+            "BTC",
+            Some(vec![
+                OutcomeBand::new(1, "Up".to_owned(), Some(-50), None),
+                OutcomeBand::new(2, "Down".to_owned(), None, Some(50)),
+            ]),
+        );
+    }
+
+    #[test]
+    fn bet_on_third_outcome() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_ticker_outcomes_success("BTC", Some(three_way_bands()));
+        test_env.first_bet_on_contest_success(&1, &3, &100);
+
+        // ETH keeps the default bull and bear outcomes
+        test_env.first_bet_on_contest_fail(&14, &3, &100);
+    }
+
+    #[test]
+    fn flat_band_wins() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_ticker_outcomes_success("BTC", Some(three_way_bands()));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &1, &100);
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_on_contest_success(&contest_file, &2, &100);
+        test_env.set_sender("user3".to_owned());
+        test_env.bet_on_contest_success(&contest_file, &3, &100);
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        set_resolve_price(&mut test_env, "58205.29");
+
+        test_env.claim_failure(&contest_file);
+        test_env.set_sender("creator".to_owned());
+        test_env.claim_failure(&contest_file);
+        test_env.set_sender("user2".to_owned());
+        test_env.claim_success(&contest_file, Some(&297));
+    }
+
+    #[test]
+    fn down_band_wins() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_ticker_outcomes_success("BTC", Some(three_way_bands()));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &1, &100);
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_on_contest_success(&contest_file, &2, &100);
+        test_env.set_sender("user3".to_owned());
+        test_env.bet_on_contest_success(&contest_file, &3, &100);
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        set_resolve_price(&mut test_env, "50000");
+
+        test_env.claim_success(&contest_file, Some(&297));
+        test_env.set_sender("user2".to_owned());
+        test_env.claim_failure(&contest_file);
+    }
+
+    #[test]
+    fn contest_is_null_and_void_when_winning_band_has_no_stake() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_ticker_outcomes_success("BTC", Some(three_way_bands()));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &1, &100);
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_on_contest_success(&contest_file, &2, &100);
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        set_resolve_price(&mut test_env, "50000");
+
+        test_env.claim_success(&contest_file, Some(&100));
+        test_env.set_sender("creator".to_owned());
+        test_env.claim_success(&contest_file, Some(&100));
+    }
+
+    #[test]
+    fn contest_is_null_and_void_when_one_outcome_has_stake() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_ticker_outcomes_success("BTC", Some(three_way_bands()));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &3, &100);
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_on_contest_success(&contest_file, &3, &100);
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        set_resolve_price(&mut test_env, "50000");
+
+        test_env.claim_success(&contest_file, Some(&100));
+        test_env.set_sender("creator".to_owned());
+        test_env.claim_success(&contest_file, Some(&100));
+    }
+}
@@ -0,0 +1,156 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Uint128;
+
+    use crate::{
+        data::state::FeePercent,
+        tests::{
+            constants::{
+                AFTER_TIME_OF_1_CLOSE, AFTER_TIME_OF_RESOLVE, BASE_FEE_PERCENT_DENOMINATOR,
+                BASE_FEE_PERCENT_NUMERATOR,
+            },
+            test_env::tests::TestEnv,
+        },
+    };
+
+    ////////TESTS////////
+    #[test]
+    fn switch_side_moves_stake() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &2, &100);
+
+        test_env.switch_side_success(&contest_file, &1, &100, &0);
+        test_env.get_user_bet_success(&contest_file, Some(&100), Some(&1), Some(&false));
+        test_env.get_number_of_bets(Some(&1));
+        test_env.get_volume(Some(&Uint128::from(100u128)));
+    }
+
+    #[test]
+    fn switch_side_keeps_switching_fee() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_switching_fee_success(FeePercent::new(2, 100));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &2, &100);
+
+        test_env.switch_side_success(&contest_file, &1, &98, &2);
+        test_env.get_user_bet_success(&contest_file, Some(&98), Some(&1), Some(&false));
+        test_env.get_claimable_fees(Some(&Uint128::from(2u128)));
+        test_env.get_volume(Some(&Uint128::from(98u128)));
+    }
+
+    #[test]
+    fn plain_bet_on_other_side_still_rejected() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &2, &100);
+        test_env.bet_on_contest_fail(&contest_file, &1, &100);
+
+        test_env.switch_side_success(&contest_file, &1, &100, &0);
+        test_env.bet_on_contest_fail(&contest_file, &2, &100);
+        test_env.bet_on_contest_success(&contest_file, &1, &100);
+        test_env.get_user_bet_success(&contest_file, Some(&200), Some(&1), Some(&false));
+    }
+
+    #[test]
+    fn cannot_switch_to_same_or_missing_outcome() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &2, &100);
+
+        test_env.switch_side_fail(&contest_file, &2);
+        test_env.switch_side_fail(&contest_file, &3);
+    }
+
+    #[test]
+    fn cannot_switch_without_bet() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &2, &100);
+
+        test_env.set_sender("user2".to_owned());
+        test_env.switch_side_fail(&contest_file, &1);
+    }
+
+    #[test]
+    fn cannot_switch_after_close() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &2, &100);
+
+        test_env.set_time(AFTER_TIME_OF_1_CLOSE);
+        test_env.switch_side_fail(&contest_file, &1);
+    }
+
+    #[test]
+    fn cannot_switch_cancelled_bet() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &2, &100);
+
+        test_env.cancel_bet_success(&contest_file, &100, &0);
+        test_env.switch_side_fail(&contest_file, &1);
+    }
+
+    #[test]
+    fn switched_stake_wins_on_new_side() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &2, &100);
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_on_contest_success(&contest_file, &2, &100);
+
+        test_env.set_sender("creator".to_owned());
+        test_env.switch_side_success(&contest_file, &1, &100, &0);
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.claim_success(&contest_file, Some(&198));
+        test_env.set_sender("user2".to_owned());
+        test_env.claim_failure(&contest_file);
+    }
+
+    #[test]
+    fn switching_fee_cannot_exceed_max_fee() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_switching_fee_fail(FeePercent::new(50, 100));
+
+        test_env.set_sender("user2".to_owned());
+        test_env.set_switching_fee_fail(FeePercent::new(2, 100));
+    }
+}
@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Uint128;
+
+    use crate::{
+        data::{roles::Role, state::FeePercent},
+        tests::{
+            constants::{
+                AFTER_TIME_OF_RESOLVE, BASE_FEE_PERCENT_DENOMINATOR, BASE_FEE_PERCENT_NUMERATOR,
+            },
+            test_env::tests::TestEnv,
+        },
+    };
+
+    fn collect_fees(test_env: &mut TestEnv) {
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &1, &1000);
+
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_on_contest_success(&contest_file, &2, &1000);
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.set_sender("creator".to_owned());
+        test_env.claim_success(&contest_file, Some(&1980));
+    }
+
+    ////////TESTS////////
+    #[test]
+    fn withdraw_partial_fees() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        collect_fees(&mut test_env);
+
+        test_env.withdraw_fees_success(&5, None);
+        test_env.get_claimable_fees(Some(&Uint128::from(15u128)));
+
+        test_env.withdraw_fees_success(&15, None);
+        test_env.get_claimable_fees(Some(&Uint128::zero()));
+    }
+
+    #[test]
+    fn withdraw_fees_to_recipient() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        collect_fees(&mut test_env);
+
+        test_env.withdraw_fees_success(&8, Some("cold_wallet"));
+        test_env.get_fee_withdrawals(0, 10, &vec![(8, "cold_wallet")], 1);
+    }
+
+    #[test]
+    fn claim_fees_without_amount_drains_balance() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        collect_fees(&mut test_env);
+
+        test_env.withdraw_fees_success(&5, None);
+        test_env.claim_fees_success(Some(&15));
+        test_env.get_claimable_fees(Some(&Uint128::zero()));
+        test_env.get_fee_withdrawals(0, 10, &vec![(5, "creator"), (15, "creator")], 2);
+    }
+
+    #[test]
+    fn cannot_withdraw_more_than_claimable() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        collect_fees(&mut test_env);
+
+        test_env.withdraw_fees_failure(&21, None);
+        test_env.withdraw_fees_failure(&0, None);
+        test_env.get_claimable_fees(Some(&Uint128::from(20u128)));
+        test_env.get_fee_withdrawals(0, 10, &vec![], 0);
+    }
+
+    #[test]
+    fn only_treasury_can_withdraw_fees() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        collect_fees(&mut test_env);
+        test_env.grant_role_success("treasury", Role::Treasury);
+
+        test_env.set_sender("user2".to_owned());
+        test_env.withdraw_fees_failure(&5, Some("user2"));
+
+        test_env.set_sender("treasury".to_owned());
+        test_env.withdraw_fees_success(&5, Some("cold_wallet"));
+    }
+}
@@ -1 +1,2 @@
 pub mod bet;
+pub mod refund_on_failure;
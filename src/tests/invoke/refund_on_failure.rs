@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data::state::{ContractStatus, FeePercent},
+        responses::execute::response_types::bet::BetErrorCode,
+        tests::{
+            constants::{BASE_FEE_PERCENT_DENOMINATOR, BASE_FEE_PERCENT_NUMERATOR},
+            test_env::tests::TestEnv,
+        },
+    };
+
+    ////////TESTS////////
+    #[test]
+    fn accepted_bet_is_not_refunded() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_file = 1;
+        test_env.bet_refund_on_failure(&contest_file, &1, &100, None);
+        test_env.get_user_bet_success(&contest_file, Some(&100), Some(&1), None);
+    }
+
+    #[test]
+    fn bet_below_minimum_is_refunded() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_minimum_bet_success(&100);
+        let contest_file = 1;
+        test_env.bet_refund_on_failure(&contest_file, &1, &10, Some(BetErrorCode::BetBelowMinimum));
+        test_env.get_user_bet_failure(&contest_file);
+        test_env.get_number_of_contests(Some(&0));
+    }
+
+    #[test]
+    fn bet_on_other_side_is_refunded() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &1, &100);
+        test_env.bet_refund_on_failure(
+            &contest_file,
+            &2,
+            &100,
+            Some(BetErrorCode::CannotBetOnBothSides),
+        );
+        test_env.get_user_bet_success(&contest_file, Some(&100), Some(&1), None);
+        test_env.get_volume(Some(&100u128.into()));
+    }
+
+    #[test]
+    fn bet_on_missing_outcome_is_refunded_without_creating_contest() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_file = 1;
+        test_env.bet_refund_on_failure(
+            &contest_file,
+            &3,
+            &100,
+            Some(BetErrorCode::OutcomeNotFound),
+        );
+        test_env.get_number_of_contests(Some(&0));
+    }
+
+    #[test]
+    fn bet_on_paused_ticker_is_refunded() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.pause_ticker_success("BTC", true);
+        test_env.bet_refund_on_failure(&1, &1, &100, Some(BetErrorCode::TickerUnavailable));
+    }
+
+    #[test]
+    fn bet_while_bets_paused_is_refunded() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.fail_safe_success(ContractStatus::BetsPaused);
+        test_env.bet_refund_on_failure(&1, &1, &100, Some(BetErrorCode::BetsNotAllowed));
+    }
+}
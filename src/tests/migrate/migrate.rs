@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        constants::CONTRACT_VERSION,
+        data::state::FeePercent,
+        tests::{
+            constants::{BASE_FEE_PERCENT_DENOMINATOR, BASE_FEE_PERCENT_NUMERATOR},
+            test_env::tests::TestEnv,
+        },
+    };
+
+    ////////TESTS////////
+    #[test]
+    fn initialize_stores_contract_version() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.get_contract_version(CONTRACT_VERSION);
+    }
+
+    #[test]
+    fn migrate_same_version() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.migrate_success(CONTRACT_VERSION);
+        test_env.get_contract_version(CONTRACT_VERSION);
+    }
+
+    #[test]
+    fn migrate_unversioned_contract() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.downgrade_to_v2_0_0();
+        test_env.migrate_success("2.0.0");
+        test_env.get_contract_version(CONTRACT_VERSION);
+    }
+
+    #[test]
+    fn cannot_migrate_to_older_version() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_contract_version("99.0.0");
+        test_env.migrate_fail();
+    }
+
+    #[test]
+    fn cannot_migrate_without_path() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_contract_version("1.0.0");
+        test_env.migrate_fail();
+    }
+
+    #[test]
+    fn cannot_migrate_invalid_version() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_contract_version("two");
+        test_env.migrate_fail();
+    }
+}
@@ -0,0 +1,128 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Uint128;
+
+    use crate::{
+        data::state::{ContractStatus, FeePercent},
+        tests::{
+            constants::{
+                AFTER_TIME_OF_RESOLVE, BASE_FEE_PERCENT_DENOMINATOR, BASE_FEE_PERCENT_NUMERATOR,
+            },
+            test_env::tests::TestEnv,
+        },
+    };
+
+    ////////TESTS////////
+    #[test]
+    fn migrate_seeds_legacy_tickers() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.downgrade_to_v2_0_0();
+        test_env.get_tickers(&vec![]);
+
+        test_env.migrate_success("2.0.0");
+        test_env.get_tickers(&vec![("BTC", true), ("ETH", true)]);
+    }
+
+    #[test]
+    fn bet_after_migrate() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.downgrade_to_v2_0_0();
+        test_env.migrate_success("2.0.0");
+
+        test_env.first_bet_on_contest_success(&1, &1, &100);
+    }
+
+    #[test]
+    fn claim_contest_from_before_migrate() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &1, &100);
+        test_env.downgrade_to_v2_0_0();
+        test_env.migrate_success("2.0.0");
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.claim_success(&contest_file, Some(&100));
+    }
+
+    #[test]
+    fn migrate_preserves_state() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_minimum_bet_success(&100);
+        test_env.set_fee_success(FeePercent::new(2, 100));
+        test_env.downgrade_to_v2_0_0();
+        test_env.migrate_success("2.0.0");
+
+        test_env.get_minimum_bet_success(Some(&100));
+        test_env.get_fee_percent(Some(&FeePercent::new(2, 100)));
+        test_env.get_contract_status(ContractStatus::Normal);
+        test_env.get_max_fee_percent(&FeePercent::new(10, 100));
+    }
+
+    #[test]
+    fn migrate_raises_max_fee_to_legacy_fee() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_max_fee_success(FeePercent::new(25, 100));
+        test_env.set_fee_success(FeePercent::new(20, 100));
+        test_env.downgrade_to_v2_0_0();
+        test_env.migrate_success("2.0.0");
+
+        test_env.get_fee_percent(Some(&FeePercent::new(20, 100)));
+        test_env.get_max_fee_percent(&FeePercent::new(20, 100));
+    }
+
+    #[test]
+    fn migrate_normalizes_legacy_zero_fee() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_stored_fee_percent(FeePercent::new(0, 0));
+        test_env.downgrade_to_v2_0_0();
+        test_env.migrate_success("2.0.0");
+        test_env.get_fee_percent(Some(&FeePercent::new(0, 100)));
+
+        test_env.first_bet_on_contest_success(&1, &1, &100);
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_on_contest_success(&1, &2, &100);
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.set_sender("keeper".to_owned());
+        test_env.resolve_contests_success(&[1], &[(1, 0)]);
+        test_env.get_claimable_fees(Some(&Uint128::zero()));
+
+        test_env.set_sender("creator".to_owned());
+        test_env.claim_success(&1, Some(&200));
+    }
+
+    #[test]
+    fn migrate_fails_on_invalid_legacy_fee() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_stored_fee_percent(FeePercent::new(1, 0));
+        test_env.downgrade_to_v2_0_0();
+        test_env.migrate_fail();
+    }
+}
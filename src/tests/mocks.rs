@@ -0,0 +1,93 @@
+#[cfg(test)]
+pub mod tests {
+    use std::str::FromStr;
+
+    use cosmwasm_std::{Addr, ContractInfo, Decimal, QuerierWrapper, StdError, StdResult, Storage};
+    use sp_secret_toolkit::{
+        master_viewing_key::response::IsViewingKeyValidResponse,
+        price_feed::{
+            data::price_posting::PricePosting,
+            response::response_types::prices_by_ids::PricesByIdsResponse,
+        },
+    };
+
+    use crate::services::integrations::{
+        master_viewing_key_service::ViewingKeyVerifier, price_feed_service::PriceSource,
+    };
+
+    // Returns the same prices for every query, owned by a single test environment
+    pub struct MockPriceSource {
+        prices: PricesByIdsResponse,
+        return_error: bool,
+    }
+
+    impl MockPriceSource {
+        pub fn set_prices(&mut self, prices: PricesByIdsResponse) {
+            self.prices = prices;
+        }
+
+        pub fn set_return_error(&mut self, return_error: bool) {
+            self.return_error = return_error;
+        }
+    }
+
+    impl Default for MockPriceSource {
+        // Prices at the close and resolve of the first BTC contest, with the price going up
+        fn default() -> Self {
+            MockPriceSource {
+                prices: PricesByIdsResponse {
+                    prices: vec![
+                        PricePosting::new(Decimal::from_str("58205.29").unwrap(), 1571797500),
+                        PricePosting::new(Decimal::from_str("58205.46").unwrap(), 1571797800),
+                    ],
+                },
+                return_error: false,
+            }
+        }
+    }
+
+    impl PriceSource for MockPriceSource {
+        fn query_prices(
+            &self,
+            _querier: &QuerierWrapper,
+            _price_feed: &ContractInfo,
+            _prices: &Vec<u64>,
+        ) -> StdResult<PricesByIdsResponse> {
+            if self.return_error {
+                return Err(StdError::generic_err("Test error: query_prices failed"));
+            }
+            Ok(self.prices.clone())
+        }
+    }
+
+    pub struct MockViewingKeyVerifier {
+        valid: bool,
+    }
+
+    impl MockViewingKeyVerifier {
+        pub fn set_valid(&mut self, valid: bool) {
+            self.valid = valid;
+        }
+    }
+
+    impl Default for MockViewingKeyVerifier {
+        fn default() -> Self {
+            MockViewingKeyVerifier { valid: true }
+        }
+    }
+
+    impl ViewingKeyVerifier for MockViewingKeyVerifier {
+        fn assert_valid_viewing_key(
+            &self,
+            _storage: &dyn Storage,
+            _querier: &QuerierWrapper,
+            _address: &Addr,
+            _viewing_key: &String,
+        ) -> StdResult<IsViewingKeyValidResponse> {
+            if !self.valid {
+                return Err(StdError::generic_err("Test error: invalid viewing key"));
+            }
+            Ok(IsViewingKeyValidResponse { validity: true })
+        }
+    }
+}
@@ -2,5 +2,7 @@ pub mod constants;
 pub mod contest_infos;
 pub mod execute;
 pub mod invoke;
+pub mod migrate;
+pub mod mocks;
 pub mod query;
 pub mod test_env;
@@ -0,0 +1,189 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data::{contest_info::ContestStatus, contests::SortOrder, state::FeePercent},
+        msgs::query::commands::get_contests::GetContests,
+        tests::{
+            constants::{
+                AFTER_TIME_OF_1_CLOSE, AFTER_TIME_OF_2_CLOSE, AFTER_TIME_OF_RESOLVE,
+                BASE_FEE_PERCENT_DENOMINATOR, BASE_FEE_PERCENT_NUMERATOR,
+            },
+            contest_infos::get_contest_open,
+            test_env::tests::TestEnv,
+        },
+    };
+
+    fn get_contests() -> GetContests {
+        GetContests {
+            start_after: None,
+            limit: None,
+            ticker: None,
+            status: None,
+            from_time: None,
+            to_time: None,
+            order: None,
+        }
+    }
+
+    fn bet_on_three_contests(test_env: &mut TestEnv) {
+        test_env.first_bet_on_contest_success(&1, &1, &100);
+        test_env.set_time(AFTER_TIME_OF_1_CLOSE);
+        test_env.first_bet_on_contest_success(&2, &1, &100);
+        test_env.set_time(AFTER_TIME_OF_2_CLOSE);
+        test_env.first_bet_on_contest_success(&3, &1, &100);
+    }
+
+    ////////TESTS////////
+    #[test]
+    fn no_contests() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.get_contests_success(get_contests(), &[], None);
+    }
+
+    #[test]
+    fn pages_newest_first_by_default() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        bet_on_three_contests(&mut test_env);
+
+        test_env.get_contests_success(get_contests(), &[3, 2, 1], None);
+        test_env.get_contests_success(
+            GetContests {
+                limit: Some(2),
+                ..get_contests()
+            },
+            &[3, 2],
+            Some(1),
+        );
+        test_env.get_contests_success(
+            GetContests {
+                start_after: Some(1),
+                limit: Some(2),
+                ..get_contests()
+            },
+            &[1],
+            None,
+        );
+    }
+
+    #[test]
+    fn pages_oldest_first() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        bet_on_three_contests(&mut test_env);
+
+        test_env.get_contests_success(
+            GetContests {
+                limit: Some(2),
+                order: Some(SortOrder::Ascending),
+                ..get_contests()
+            },
+            &[1, 2],
+            Some(1),
+        );
+        test_env.get_contests_success(
+            GetContests {
+                start_after: Some(1),
+                limit: Some(2),
+                order: Some(SortOrder::Ascending),
+                ..get_contests()
+            },
+            &[3],
+            None,
+        );
+    }
+
+    #[test]
+    fn filters_by_ticker_and_time_of_close() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.first_bet_on_contest_success(&14, &1, &100);
+        bet_on_three_contests(&mut test_env);
+
+        test_env.get_contests_success(
+            GetContests {
+                ticker: Some("BTC".to_owned()),
+                ..get_contests()
+            },
+            &[3, 2, 1],
+            None,
+        );
+
+        let time_of_close = get_contest_open(2)
+            .expect("Contest file not found")
+            .get_time_of_close();
+        test_env.get_contests_success(
+            GetContests {
+                ticker: Some("BTC".to_owned()),
+                from_time: Some(time_of_close),
+                to_time: Some(time_of_close),
+                ..get_contests()
+            },
+            &[2],
+            None,
+        );
+    }
+
+    #[test]
+    fn filters_by_status() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.first_bet_on_contest_success(&1, &1, &100);
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_on_contest_success(&1, &2, &100);
+        test_env.set_time(AFTER_TIME_OF_1_CLOSE);
+        test_env.first_bet_on_contest_success(&2, &1, &100);
+
+        test_env.get_contests_success(
+            GetContests {
+                status: Some(ContestStatus::Open),
+                ..get_contests()
+            },
+            &[2],
+            None,
+        );
+        test_env.get_contests_success(
+            GetContests {
+                status: Some(ContestStatus::Closed),
+                ..get_contests()
+            },
+            &[1],
+            None,
+        );
+
+        // A contest with bets on one side only is voided
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.get_contests_success(
+            GetContests {
+                status: Some(ContestStatus::Resolved),
+                ..get_contests()
+            },
+            &[1],
+            None,
+        );
+        test_env.get_contests_success(
+            GetContests {
+                status: Some(ContestStatus::Nullified),
+                ..get_contests()
+            },
+            &[2],
+            None,
+        );
+    }
+}
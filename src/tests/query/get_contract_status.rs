@@ -0,0 +1,32 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data::state::{ContractStatus, FeePercent},
+        tests::{
+            constants::{BASE_FEE_PERCENT_DENOMINATOR, BASE_FEE_PERCENT_NUMERATOR},
+            test_env::tests::TestEnv,
+        },
+    };
+
+    ////////TESTS////////
+    #[test]
+    fn get_contract_status_on_initialize() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.get_contract_status(ContractStatus::Normal);
+    }
+
+    #[test]
+    fn get_contract_status_after_fail_safe() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.fail_safe_success(ContractStatus::FailSafe);
+        test_env.get_contract_status(ContractStatus::FailSafe);
+    }
+}
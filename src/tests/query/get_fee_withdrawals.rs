@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data::state::FeePercent,
+        tests::{
+            constants::{
+                AFTER_TIME_OF_RESOLVE, BASE_FEE_PERCENT_DENOMINATOR, BASE_FEE_PERCENT_NUMERATOR,
+            },
+            test_env::tests::TestEnv,
+        },
+    };
+
+    ////////TESTS////////
+    #[test]
+    fn get_fee_withdrawals_on_initialize() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.get_fee_withdrawals(0, 10, &vec![], 0);
+    }
+
+    #[test]
+    fn get_fee_withdrawals_paginated() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &1, &1000);
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_on_contest_success(&contest_file, &2, &1000);
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.set_sender("creator".to_owned());
+        test_env.claim_success(&contest_file, Some(&1980));
+
+        for amount in 1..=5u128 {
+            test_env.withdraw_fees_success(&amount, None);
+        }
+
+        test_env.get_fee_withdrawals(0, 2, &vec![(1, "creator"), (2, "creator")], 5);
+        test_env.get_fee_withdrawals(1, 2, &vec![(3, "creator"), (4, "creator")], 5);
+        test_env.get_fee_withdrawals(2, 2, &vec![(5, "creator")], 5);
+        test_env.get_fee_withdrawals(3, 2, &vec![], 5);
+    }
+}
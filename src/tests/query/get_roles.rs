@@ -0,0 +1,37 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data::{roles::Role, state::FeePercent},
+        tests::{
+            constants::{BASE_FEE_PERCENT_DENOMINATOR, BASE_FEE_PERCENT_NUMERATOR},
+            test_env::tests::TestEnv,
+        },
+    };
+
+    ////////TESTS////////
+    #[test]
+    fn get_roles_on_initialize() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.get_roles(&vec![]);
+    }
+
+    #[test]
+    fn get_roles_for_multiple_addresses() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.grant_role_success("treasury", Role::Treasury);
+        test_env.grant_role_success("operator", Role::Pauser);
+        test_env.grant_role_success("operator", Role::TickerManager);
+        test_env.get_roles(&vec![
+            ("treasury", vec![Role::Treasury]),
+            ("operator", vec![Role::Pauser, Role::TickerManager]),
+        ]);
+    }
+}
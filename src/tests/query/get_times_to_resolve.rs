@@ -2,13 +2,15 @@
 mod tests {
     use std::vec;
 
+    use cosmwasm_std::{Addr, ContractInfo};
     use sp_secret_toolkit::price_feed::response::response_types::prices_by_ids::PricesByIdsResponse;
 
     use crate::{
-        data::{contest_info::ContestId, state::FeePercent},
+        data::{contest_info::ContestId, currency::Currency, state::FeePercent},
         msgs::query::commands::get_times_to_resolve::GetTimesToResolve,
-        responses::query::response_types::times_to_resolve::TimesToResolveResponse,
-        services::integrations::price_feed_service::pricefeed::set_oracle_result,
+        responses::query::response_types::times_to_resolve::{
+            PriceFeedTimes, TimesToResolveResponse,
+        },
         tests::{
             constants::{
                 AFTER_TIME_OF_1_CLOSE, AFTER_TIME_OF_2_CLOSE, AFTER_TIME_OF_3_CLOSE,
@@ -19,6 +21,13 @@ mod tests {
         },
     };
 
+    fn global_price_feed() -> ContractInfo {
+        ContractInfo {
+            address: Addr::unchecked("Price Feed Address"),
+            code_hash: "Price Feed CodeHash".to_owned(),
+        }
+    }
+
     ////////TESTS////////
     #[test]
     fn get_times_to_resolve_single() {
@@ -32,12 +41,21 @@ mod tests {
         test_env.set_time(AFTER_TIME_OF_2_CLOSE);
 
         let oracle_result = PricesByIdsResponse { prices: vec![] };
-        set_oracle_result(Some(oracle_result));
+        test_env.set_oracle_result(oracle_result);
 
         let expected_response = TimesToResolveResponse {
             times: vec![1571797500, 1571797800],
+            price_feeds: vec![PriceFeedTimes {
+                price_feed: global_price_feed(),
+                times: vec![1571797500, 1571797800],
+            }],
         };
-        let ids = vec![ContestId::new("BTC".to_string(), 1571797500)];
+        let ids = vec![ContestId::new(
+            "BTC".to_string(),
+            1571797500,
+            Currency::Snip20,
+            false,
+        )];
         let command = GetTimesToResolve { contest_ids: ids };
         test_env.query_times_to_resolve(command, expected_response);
     }
@@ -61,19 +79,65 @@ mod tests {
         test_env.set_time(AFTER_TIME_OF_RESOLVE);
 
         let oracle_result = PricesByIdsResponse { prices: vec![] };
-        set_oracle_result(Some(oracle_result));
+        test_env.set_oracle_result(oracle_result);
 
         let expected_response = TimesToResolveResponse {
             times: vec![
                 1571797500, 1571797800, 1571798100, 1571798400, 1571798700, 1571799000,
             ],
+            price_feeds: vec![PriceFeedTimes {
+                price_feed: global_price_feed(),
+                times: vec![
+                    1571797500, 1571797800, 1571798100, 1571798400, 1571798700, 1571799000,
+                ],
+            }],
+        };
+        let ids = vec![
+            ContestId::new("BTC".to_string(), 1571797500, Currency::Snip20, false),
+            ContestId::new("BTC".to_string(), 1571797800, Currency::Snip20, false),
+            ContestId::new("BTC".to_string(), 1571798100, Currency::Snip20, false),
+            ContestId::new("BTC".to_string(), 1571798400, Currency::Snip20, false),
+            ContestId::new("BTC".to_string(), 1571798700, Currency::Snip20, false),
+        ];
+        let command = GetTimesToResolve { contest_ids: ids };
+        test_env.query_times_to_resolve(command, expected_response);
+    }
+
+    #[test]
+    fn get_times_to_resolve_per_price_feed() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let eth_price_feed = ContractInfo {
+            address: Addr::unchecked("ETH Price Feed Address"),
+            code_hash: "ETH Price Feed CodeHash".to_owned(),
+        };
+        test_env.set_ticker_config_success("ETH", Some(3600), Some(eth_price_feed.clone()));
+        test_env.first_bet_on_contest_success(&1, &1, &100);
+        test_env.first_bet_on_contest_success(&14, &1, &100);
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+
+        let oracle_result = PricesByIdsResponse { prices: vec![] };
+        test_env.set_oracle_result(oracle_result);
+
+        let expected_response = TimesToResolveResponse {
+            times: vec![1571797500, 1571797800, 1571799600, 1571803200],
+            price_feeds: vec![
+                PriceFeedTimes {
+                    price_feed: global_price_feed(),
+                    times: vec![1571797500, 1571797800],
+                },
+                PriceFeedTimes {
+                    price_feed: eth_price_feed,
+                    times: vec![1571799600, 1571803200],
+                },
+            ],
         };
         let ids = vec![
-            ContestId::new("BTC".to_string(), 1571797500),
-            ContestId::new("BTC".to_string(), 1571797800),
-            ContestId::new("BTC".to_string(), 1571798100),
-            ContestId::new("BTC".to_string(), 1571798400),
-            ContestId::new("BTC".to_string(), 1571798700),
+            ContestId::new("BTC".to_string(), 1571797500, Currency::Snip20, false),
+            ContestId::new("ETH".to_string(), 1571799600, Currency::Snip20, false),
         ];
         let command = GetTimesToResolve { contest_ids: ids };
         test_env.query_times_to_resolve(command, expected_response);
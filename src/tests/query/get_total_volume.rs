@@ -1,9 +1,9 @@
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::Uint128;
+    use cosmwasm_std::{coins, Uint128};
 
     use crate::{
-        data::state::FeePercent,
+        data::{currency::Currency, state::FeePercent},
         tests::{
             constants::{
                 AFTER_TIME_OF_1_CLOSE, AFTER_TIME_OF_2_CLOSE, AFTER_TIME_OF_3_CLOSE,
@@ -152,4 +152,24 @@ mod tests {
 
         test_env.get_volume(Some(&Uint128::new(500)));
     }
+
+    #[test]
+    fn get_volume_kept_apart_per_currency() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.set_native_denom_success(Some("uscrt"), Some(1));
+        let native = Currency::Native {
+            denom: "uscrt".to_owned(),
+        };
+
+        test_env.first_bet_on_contest_success(&1, &1, &100);
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_native_success("BTC", &1, &coins(300, "uscrt"));
+
+        test_env.get_volume_in(&Currency::Snip20, &Uint128::new(100));
+        test_env.get_volume_in(&native, &Uint128::new(300));
+    }
 }
@@ -115,4 +115,18 @@ mod tests {
             )
         }
     }
+
+    #[test]
+    fn get_user_bet_with_invalid_viewing_key() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        let contest_file = 1;
+        test_env.first_bet_on_contest_success(&contest_file, &1, &100);
+
+        test_env.set_viewing_keys_valid(false);
+        test_env.get_user_bet_failure(&contest_file);
+    }
 }
@@ -0,0 +1,170 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Addr;
+
+    use crate::{
+        data::{bets::UserBetStatus, state::FeePercent},
+        msgs::query::commands::get_user_bets::GetUserBets,
+        tests::{
+            constants::{
+                AFTER_TIME_OF_1_CLOSE, AFTER_TIME_OF_2_CLOSE, AFTER_TIME_OF_RESOLVE,
+                BASE_FEE_PERCENT_DENOMINATOR, BASE_FEE_PERCENT_NUMERATOR,
+            },
+            test_env::tests::TestEnv,
+        },
+    };
+
+    fn get_user_bets() -> GetUserBets {
+        GetUserBets {
+            user: Addr::unchecked("creator"),
+            viewing_key: "valid viewing key".to_owned(),
+            start_after: None,
+            limit: None,
+            status: None,
+            ticker: None,
+        }
+    }
+
+    fn bet_on_three_contests(test_env: &mut TestEnv) {
+        test_env.first_bet_on_contest_success(&1, &1, &100);
+        test_env.set_time(AFTER_TIME_OF_1_CLOSE);
+        test_env.first_bet_on_contest_success(&2, &1, &100);
+        test_env.set_time(AFTER_TIME_OF_2_CLOSE);
+        test_env.first_bet_on_contest_success(&3, &1, &100);
+    }
+
+    ////////TESTS////////
+    #[test]
+    fn no_bets() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.get_user_bets_success(get_user_bets(), &[], None);
+    }
+
+    #[test]
+    fn pages_newest_first() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        bet_on_three_contests(&mut test_env);
+
+        test_env.get_user_bets_success(get_user_bets(), &[3, 2, 1], None);
+        test_env.get_user_bets_success(
+            GetUserBets {
+                limit: Some(2),
+                ..get_user_bets()
+            },
+            &[3, 2],
+            Some(1),
+        );
+        test_env.get_user_bets_success(
+            GetUserBets {
+                start_after: Some(1),
+                limit: Some(2),
+                ..get_user_bets()
+            },
+            &[1],
+            None,
+        );
+    }
+
+    #[test]
+    fn filters_by_ticker() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.first_bet_on_contest_success(&14, &1, &100);
+        test_env.first_bet_on_contest_success(&1, &1, &100);
+
+        test_env.get_user_bets_success(
+            GetUserBets {
+                ticker: Some("SOL".to_owned()),
+                ..get_user_bets()
+            },
+            &[],
+            None,
+        );
+        test_env.get_user_bets_success(
+            GetUserBets {
+                ticker: Some("BTC".to_owned()),
+                ..get_user_bets()
+            },
+            &[1],
+            None,
+        );
+    }
+
+    #[test]
+    fn filters_by_status() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        // Won on contest 1, lost on contest 2
+        test_env.first_bet_on_contest_success(&1, &1, &100);
+        test_env.set_sender("user2".to_owned());
+        test_env.bet_on_contest_success(&1, &2, &100);
+        test_env.set_time(AFTER_TIME_OF_1_CLOSE);
+        test_env.first_bet_on_contest_success(&2, &1, &100);
+        test_env.set_sender("creator".to_owned());
+        test_env.bet_on_contest_success(&2, &2, &100);
+        // Cancelled on contest 3
+        test_env.set_time(AFTER_TIME_OF_2_CLOSE);
+        test_env.first_bet_on_contest_success(&3, &1, &100);
+        test_env.cancel_bet_success(&3, &100, &0);
+
+        let with_status = |status: UserBetStatus| GetUserBets {
+            status: Some(status),
+            ..get_user_bets()
+        };
+        // Contest 1 has resolved by the time contest 3 opens
+        test_env.get_user_bets_success(with_status(UserBetStatus::Pending), &[2], None);
+        test_env.get_user_bets_success(with_status(UserBetStatus::Won), &[1], None);
+        test_env.get_user_bets_success(with_status(UserBetStatus::Refunded), &[3], None);
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.get_user_bets_success(with_status(UserBetStatus::Pending), &[], None);
+        test_env.get_user_bets_success(with_status(UserBetStatus::Won), &[1], None);
+        test_env.get_user_bets_success(with_status(UserBetStatus::Lost), &[2], None);
+
+        test_env.claim_success(&1, Some(&198));
+        test_env.get_user_bets_success(with_status(UserBetStatus::Won), &[], None);
+        test_env.get_user_bets_success(with_status(UserBetStatus::Claimed), &[1], None);
+    }
+
+    #[test]
+    fn nullified_contest_is_refunded() {
+        let mut test_env = TestEnv::new();
+        test_env.initialize(FeePercent::new(
+            BASE_FEE_PERCENT_NUMERATOR,
+            BASE_FEE_PERCENT_DENOMINATOR,
+        ));
+        test_env.first_bet_on_contest_success(&1, &1, &100);
+        test_env.get_user_bets_success(
+            GetUserBets {
+                status: Some(UserBetStatus::Pending),
+                ..get_user_bets()
+            },
+            &[1],
+            None,
+        );
+
+        test_env.set_time(AFTER_TIME_OF_RESOLVE);
+        test_env.get_user_bets_success(
+            GetUserBets {
+                status: Some(UserBetStatus::Refunded),
+                ..get_user_bets()
+            },
+            &[1],
+            None,
+        );
+    }
+}
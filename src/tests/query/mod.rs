@@ -2,16 +2,24 @@ pub mod get_claimable_contests;
 pub mod get_claimable_fees;
 pub mod get_contest;
 pub mod get_contests;
+pub mod get_contests_page;
+pub mod get_contract_status;
+pub mod get_fee_payouts;
 pub mod get_fee_percent;
+pub mod get_fee_withdrawals;
 pub mod get_last_ten_contests;
 pub mod get_minimum_bet;
+pub mod get_owner;
+pub mod get_roles;
 pub mod get_snip20;
+pub mod get_tickers;
 pub mod get_times_to_resolve;
 pub mod get_total_number_of_bets;
 pub mod get_total_number_of_contests;
 pub mod get_total_number_of_users;
 pub mod get_total_volume;
 pub mod get_user_bet;
+pub mod get_user_bets;
 pub mod get_users_last_ten_bets;
 pub mod get_users_list_of_bets;
 pub mod get_users_number_of_bets;
@@ -4,45 +4,101 @@ pub mod tests {
     use cosmwasm_std::{
         coins, from_binary,
         testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage},
-        to_binary, to_vec, Addr, Binary, ContractInfo, Empty, MessageInfo, OwnedDeps, StdResult,
-        Timestamp, Uint128,
+        to_binary, to_vec, Addr, BankMsg, Binary, Coin, ContractInfo, CosmosMsg, Decimal, Empty,
+        MessageInfo, OwnedDeps, Response, StdResult, SubMsgResponse, SubMsgResult, Timestamp,
+        Uint128, WasmMsg,
     };
+    use sp_secret_toolkit::price_feed::response::response_types::prices_by_ids::PricesByIdsResponse;
+    use std::str::FromStr;
 
     use crate::{
         command_handlers::{
-            admin_execute_handlers::{handle_claim_fees, handle_set_fee, handle_set_minimum_bet},
-            execute_handlers::{handle_claim, handle_claim_multiple, handle_receive},
+            admin_execute_handlers::{
+                handle_accept_ownership, handle_add_accepted_token, handle_add_ticker,
+                handle_cancel_ownership_transfer, handle_claim_fees, handle_create_contest,
+                handle_distribute_fees, handle_fail_safe, handle_grant_role, handle_pause_ticker,
+                handle_propose_new_owner, handle_remove_accepted_token, handle_remove_ticker,
+                handle_resolve_contest_manually, handle_revoke_role, handle_schedule_fee_change,
+                handle_set_bet_limits, handle_set_betting_cutoff, handle_set_cancellation_fee,
+                handle_set_expiration_window, handle_set_fee, handle_set_fee_recipients,
+                handle_set_keeper_reward, handle_set_max_fee, handle_set_minimum_bet,
+                handle_set_native_denom, handle_set_switching_fee, handle_set_ticker_config,
+                handle_set_ticker_outcomes, handle_void_contest,
+            },
+            execute_handlers::{
+                handle_bet_contest, handle_cancel_bet, handle_claim, handle_claim_all,
+                handle_claim_multiple, handle_receive, handle_resolve_contests, handle_switch_side,
+            },
             invoke_handlers::handle_bet_on_contest,
             query_handlers::{
+                handle_get_accepted_tokens, handle_get_bet_limits, handle_get_betting_cutoff,
                 handle_get_claimable_contests, handle_get_claimable_fees, handle_get_contest_by_id,
-                handle_get_contests_by_ids, handle_get_fee_percent, handle_get_last_ten_contests,
-                handle_get_minimum_bet, handle_get_snip20, handle_get_times_to_resolve_from_ids,
+                handle_get_contests, handle_get_contests_by_ids, handle_get_contract_status,
+                handle_get_expiration_window, handle_get_fee_payouts, handle_get_fee_percent,
+                handle_get_fee_recipients, handle_get_fee_withdrawals,
+                handle_get_last_ten_contests, handle_get_minimum_bet, handle_get_native_denom,
+                handle_get_owner, handle_get_pending_fee_change, handle_get_roles,
+                handle_get_snip20, handle_get_tickers, handle_get_times_to_resolve_from_ids,
                 handle_get_total_number_of_bets, handle_get_total_number_of_contests,
-                handle_get_total_users, handle_get_total_volume, handle_get_users_list_of_bets,
-                handle_get_users_number_of_bets, handle_user_bet, handle_users_last_ten_bets,
+                handle_get_total_users, handle_get_total_volume, handle_get_user_bets,
+                handle_get_users_list_of_bets, handle_get_users_number_of_bets, handle_user_bet,
+                handle_users_last_ten_bets,
             },
+            reply_handlers::handle_set_viewing_key_reply,
         },
-        contract::instantiate,
+        contract::{instantiate, migrate},
         data::{
-            contest_info::ContestInfo, state::FeePercent, user_info::get_users_last_claimed_index,
+            contest_info::{ContestId, ContestInfo},
+            contract_version::STORED_CONTRACT_VERSION,
+            currency::Currency,
+            fee_recipients::FeeRecipient,
+            outcome_band::OutcomeBand,
+            roles::Role,
+            state::{ContractStatus, FeePercent, State},
+            tickers::TICKERS,
+            user_info::get_users_last_claimed_index,
         },
+        migrations::v2_0_0,
         msgs::{
             execute::commands::{
-                claim::Claim, claim_multiple::ClaimMultiple, receive::Receive, set_fee::SetFee,
-                set_minimum_bet::SetMinimumBet,
+                accept_ownership::AcceptOwnership, add_accepted_token::AddAcceptedToken,
+                add_ticker::AddTicker, bet_contest::BetContest as NativeBetContest,
+                cancel_bet::CancelBet, cancel_ownership_transfer::CancelOwnershipTransfer,
+                claim::Claim, claim_all::ClaimAll, claim_fees::ClaimFees,
+                claim_multiple::ClaimMultiple, create_contest::CreateContest,
+                distribute_fees::DistributeFees, fail_safe::FailSafe, grant_role::GrantRole,
+                pause_ticker::PauseTicker, propose_new_owner::ProposeNewOwner, receive::Receive,
+                remove_accepted_token::RemoveAcceptedToken, remove_ticker::RemoveTicker,
+                resolve_contest_manually::ResolveContestManually,
+                resolve_contests::ResolveContests, revoke_role::RevokeRole,
+                schedule_fee_change::ScheduleFeeChange, set_bet_limits::SetBetLimits,
+                set_betting_cutoff::SetBettingCutoff, set_cancellation_fee::SetCancellationFee,
+                set_expiration_window::SetExpirationWindow, set_fee::SetFee,
+                set_fee_recipients::SetFeeRecipients, set_keeper_reward::SetKeeperReward,
+                set_max_fee::SetMaxFee, set_minimum_bet::SetMinimumBet,
+                set_native_denom::SetNativeDenom, set_switching_fee::SetSwitchingFee,
+                set_ticker_config::SetTickerConfig, set_ticker_outcomes::SetTickerOutcomes,
+                switch_side::SwitchSide, void_contest::VoidContest,
             },
             instantiate::InstantiateMsg,
             invoke::{commands::bet_contest::BetContest, invoke_msg::InvokeMsg},
+            migrate::MigrateMsg,
             query::commands::{
-                get_claimable_contests::GetClaimableContests, get_contest_by_id::GetContestById,
-                get_contests_by_ids::GetContestsByIds, get_times_to_resolve::GetTimesToResolve,
-                get_user_bet::GetUserBet, get_users_last_ten_bets::GetUsersLastTenBets,
+                get_bet_limits::GetBetLimits, get_claimable_contests::GetClaimableContests,
+                get_contest_by_id::GetContestById, get_contests::GetContests,
+                get_contests_by_ids::GetContestsByIds, get_fee_payouts::GetFeePayouts,
+                get_fee_withdrawals::GetFeeWithdrawals, get_times_to_resolve::GetTimesToResolve,
+                get_user_bet::GetUserBet, get_user_bets::GetUserBets,
+                get_users_last_ten_bets::GetUsersLastTenBets,
                 get_users_list_of_bets::GetUsersListOfBets,
                 get_users_number_of_bets::GetUsersNumberOfBets,
             },
         },
         responses::{
-            execute::execute_response::ExecuteResponse,
+            execute::{
+                execute_response::{ExecuteResponse, ResponseStatus},
+                response_types::bet::BetErrorCode,
+            },
             query::{
                 query_response::QueryResponse,
                 response_types::{
@@ -51,10 +107,10 @@ pub mod tests {
                 },
             },
         },
-        services::integrations::price_feed_service::pricefeed::reset_mock_result,
         tests::{
             constants::{BASE_FEE_PERCENT_DENOMINATOR, BASE_FEE_PERCENT_NUMERATOR, INTERVAL},
             contest_infos::get_contest_open,
+            mocks::tests::{MockPriceSource, MockViewingKeyVerifier},
         },
     };
 
@@ -63,6 +119,8 @@ pub mod tests {
         deps: OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
         info: cosmwasm_std::MessageInfo,
         env: cosmwasm_std::Env,
+        price_source: MockPriceSource,
+        viewing_key_verifier: MockViewingKeyVerifier,
     }
 
     impl TestEnv {
@@ -71,7 +129,13 @@ pub mod tests {
             let deps = mock_dependencies();
             let info = mock_info("creator", &coins(1000, "coin"));
             let env = mock_env();
-            TestEnv { deps, info, env }
+            TestEnv {
+                deps,
+                info,
+                env,
+                price_source: MockPriceSource::default(),
+                viewing_key_verifier: MockViewingKeyVerifier::default(),
+            }
         }
 
         pub fn set_sender(&mut self, sender: String) {
@@ -82,9 +146,36 @@ pub mod tests {
             self.env.block.time = Timestamp::from_seconds(seconds)
         }
 
+        pub fn set_oracle_result(&mut self, prices: PricesByIdsResponse) {
+            self.price_source.set_prices(prices);
+        }
+
+        pub fn set_oracle_error(&mut self, return_error: bool) {
+            self.price_source.set_return_error(return_error);
+        }
+
+        pub fn set_viewing_keys_valid(&mut self, valid: bool) {
+            self.viewing_key_verifier.set_valid(valid);
+        }
+
         pub fn initialize(&mut self, fee_percent: FeePercent) {
-            reset_mock_result();
-            let msg = InstantiateMsg {
+            let msg = Self::instantiate_msg(fee_percent);
+            let _res = instantiate(self.deps.as_mut(), self.env.clone(), self.info.clone(), msg)
+                .expect("contract initialization failed");
+        }
+
+        pub fn initialize_fail(&mut self, fee_percent: FeePercent) {
+            let msg = Self::instantiate_msg(fee_percent);
+            let response =
+                instantiate(self.deps.as_mut(), self.env.clone(), self.info.clone(), msg);
+            assert!(
+                response.is_err(),
+                "Expected contract initialization to fail but succeded"
+            );
+        }
+
+        fn instantiate_msg(fee_percent: FeePercent) -> InstantiateMsg {
+            InstantiateMsg {
                 price_feed_info: ContractInfo {
                     address: Addr::unchecked("Price Feed Address"),
                     code_hash: "Price Feed CodeHash".to_owned(),
@@ -101,9 +192,69 @@ pub mod tests {
                 },
                 fee_numerator: fee_percent.numerator().to_owned() as u64,
                 fee_denominator: fee_percent.denominator().to_owned() as u64,
+                tickers: vec!["BTC".to_owned(), "ETH".to_owned()],
+            }
+        }
+
+        // Rewrites storage into the layout written by a 2.0.0 deployment
+        pub fn downgrade_to_v2_0_0(&mut self) {
+            let state = State::singleton_load(&self.deps.storage).unwrap();
+            let legacy_state = v2_0_0::State {
+                owner: state.owner().to_owned(),
+                interval: state.interval().to_owned(),
+                minimum_bet: state.minimum_bet().to_owned(),
+                fee_percent: state.fee_percent().to_owned(),
+                claimable_fees: state.claimable_fees().to_owned(),
             };
-            let _res = instantiate(self.deps.as_mut(), self.env.clone(), self.info.clone(), msg)
-                .expect("contract initialization failed");
+            legacy_state.singleton_save(&mut self.deps.storage).unwrap();
+
+            STORED_CONTRACT_VERSION.remove(&mut self.deps.storage);
+            for ticker in ["BTC".to_owned(), "ETH".to_owned()] {
+                TICKERS.remove(&mut self.deps.storage, &ticker).unwrap();
+            }
+        }
+
+        // Writes the fee straight to state, as a 2.0.0 deployment could hold it unvalidated
+        pub fn set_stored_fee_percent(&mut self, fee_percent: FeePercent) {
+            let mut state = State::singleton_load(&self.deps.storage).unwrap();
+            state.set_fee_percent(fee_percent);
+            state.singleton_save(&mut self.deps.storage).unwrap();
+        }
+
+        pub fn set_contract_version(&mut self, version: &str) {
+            STORED_CONTRACT_VERSION
+                .save(&mut self.deps.storage, &version.to_owned())
+                .unwrap();
+        }
+
+        pub fn get_contract_version(&mut self, expected: &str) {
+            let version = STORED_CONTRACT_VERSION
+                .may_load(&self.deps.storage)
+                .unwrap();
+            assert_eq!(
+                version,
+                Some(expected.to_owned()),
+                "Contract version is not what was expected"
+            );
+        }
+
+        pub fn migrate_success(&mut self, expected_from: &str) {
+            let response = migrate(self.deps.as_mut(), self.env.clone(), MigrateMsg {})
+                .expect("Expected migrate to succeed");
+            let migrated_from = response
+                .attributes
+                .iter()
+                .find(|attribute| attribute.key == "migrated_from")
+                .expect("Expected migrated_from attribute");
+            assert_eq!(
+                migrated_from.value, expected_from,
+                "Migrated from version is not what was expected"
+            );
+        }
+
+        pub fn migrate_fail(&mut self) {
+            let response = migrate(self.deps.as_mut(), self.env.clone(), MigrateMsg {});
+            assert!(response.is_err(), "Expected migrate to fail but succeded")
         }
 
         pub fn ensure_index_incrementing(&mut self, expected: Option<u32>) {
@@ -120,8 +271,12 @@ pub mod tests {
             command: GetTimesToResolve,
             expected_response: TimesToResolveResponse,
         ) {
-            let binary_response =
-                handle_get_times_to_resolve_from_ids(self.deps.as_ref(), command).unwrap();
+            let binary_response = handle_get_times_to_resolve_from_ids(
+                self.deps.as_ref(),
+                command,
+                &self.price_source,
+            )
+            .unwrap();
             let query_response: QueryResponse = from_binary(&binary_response).unwrap();
             match query_response {
                 QueryResponse::TimesToResolve(response) => {
@@ -147,6 +302,9 @@ pub mod tests {
                     ticker: contest_info.get_ticker(),
                     outcome_id: *outcome_to_bet_on,
                     user: self.info.sender.clone(),
+                    contest_id: None,
+                    roll_over: None,
+                    refund_on_failure: None,
                 });
                 // Serialize the struct to a JSON byte vector
                 let serialized_msg = to_vec(&message).expect("Failed to serialize message");
@@ -168,6 +326,59 @@ pub mod tests {
             }
         }
 
+        pub fn bet_refund_on_failure(
+            &mut self,
+            file_number: &u8,
+            outcome_to_bet_on: &u8,
+            amount_to_bet: &u128,
+            expected_error_code: Option<BetErrorCode>,
+        ) {
+            let contest_info = Self::get_open_contest_from_file(file_number);
+            let message = InvokeMsg::BetContest(BetContest {
+                ticker: contest_info.get_ticker(),
+                outcome_id: *outcome_to_bet_on,
+                user: self.info.sender.clone(),
+                contest_id: None,
+                roll_over: None,
+                refund_on_failure: Some(true),
+            });
+            let command = Receive {
+                sender: Addr::unchecked("Snip20 Address"),
+                from: Addr::unchecked("Address"),
+                amount: Uint128::new(*amount_to_bet),
+                memo: None,
+                msg: to_binary(&message).expect("Failed to serialize message"),
+            };
+            let info = mock_info("Snip20 Address", &[]);
+            let response = handle_receive(self.deps.as_mut(), self.env.clone(), info, command)
+                .expect("Expected bet with refund on failure to succeed");
+
+            let data = response.data.clone().expect("Expected bet response data");
+            match from_binary::<ExecuteResponse>(&data) {
+                Ok(ExecuteResponse::Bet(bet_response)) => {
+                    assert_eq!(
+                        bet_response.error_code, expected_error_code,
+                        "Bet error code does not match"
+                    );
+                    match expected_error_code {
+                        Some(_) => {
+                            assert_eq!(bet_response.status, ResponseStatus::Failure);
+                            assert_eq!(
+                                response.messages.len(),
+                                1,
+                                "Expected refund to have snip20 msg on it"
+                            );
+                        }
+                        None => {
+                            assert_eq!(bet_response.status, ResponseStatus::Success);
+                            assert!(response.messages.is_empty(), "Expected no refund");
+                        }
+                    }
+                }
+                _ => panic!("Could not deserialize bet response"),
+            }
+        }
+
         pub fn first_bet_on_contest_success(
             &mut self,
             file_number: &u8,
@@ -179,12 +390,16 @@ pub mod tests {
                     ticker: contest_info.get_ticker(),
                     outcome_id: *outcome_to_bet_on,
                     user: self.info.sender.clone(),
+                    contest_id: None,
+                    roll_over: None,
+                    refund_on_failure: None,
                 };
                 let response = handle_bet_on_contest(
                     self.deps.as_mut(),
                     self.env.clone(),
                     command,
                     Uint128::new(amount_to_bet.clone()),
+                    Currency::Snip20,
                 );
                 response.expect("Failed to bet on contest");
             } else {
@@ -203,6 +418,9 @@ pub mod tests {
                     ticker: contest_info.get_ticker(),
                     outcome_id: *outcome_to_bet_on,
                     user: self.info.sender.clone(),
+                    contest_id: None,
+                    roll_over: None,
+                    refund_on_failure: None,
                 };
 
                 let response = handle_bet_on_contest(
@@ -210,6 +428,7 @@ pub mod tests {
                     self.env.clone(),
                     command,
                     Uint128::new(*amount_to_bet),
+                    Currency::Snip20,
                 );
                 assert!(
                     response.is_err(),
@@ -234,12 +453,16 @@ pub mod tests {
                     ticker: contest_info.get_ticker(),
                     outcome_id: *outcome_to_bet_on,
                     user: self.info.sender.clone(),
+                    contest_id: None,
+                    roll_over: None,
+                    refund_on_failure: None,
                 };
                 let response = handle_bet_on_contest(
                     self.deps.as_mut(),
                     self.env.clone(),
                     command,
                     Uint128::new(amount_to_bet.clone()),
+                    Currency::Snip20,
                 );
                 response.expect("Failed to bet on contest");
             } else {
@@ -258,6 +481,9 @@ pub mod tests {
                     ticker: contest_info.get_ticker(),
                     outcome_id: *outcome_to_bet_on,
                     user: self.info.sender.clone(),
+                    contest_id: None,
+                    roll_over: None,
+                    refund_on_failure: None,
                 };
 
                 let response = handle_bet_on_contest(
@@ -265,6 +491,7 @@ pub mod tests {
                     self.env.clone(),
                     command,
                     Uint128::new(*amount_to_bet),
+                    Currency::Snip20,
                 );
                 assert!(
                     response.is_err(),
@@ -278,9 +505,97 @@ pub mod tests {
             }
         }
 
+        pub fn bet_on_ticker_success(
+            &mut self,
+            ticker: &str,
+            outcome_to_bet_on: &u8,
+            amount_to_bet: &u128,
+            roll_over: Option<bool>,
+        ) {
+            let command = BetContest {
+                ticker: ticker.to_owned(),
+                outcome_id: *outcome_to_bet_on,
+                user: self.info.sender.clone(),
+                contest_id: None,
+                roll_over,
+                refund_on_failure: None,
+            };
+            let response = handle_bet_on_contest(
+                self.deps.as_mut(),
+                self.env.clone(),
+                command,
+                Uint128::new(*amount_to_bet),
+                Currency::Snip20,
+            );
+            response.expect("Failed to bet on contest");
+        }
+
+        pub fn bet_on_ticker_fail(
+            &mut self,
+            ticker: &str,
+            outcome_to_bet_on: &u8,
+            amount_to_bet: &u128,
+            roll_over: Option<bool>,
+        ) {
+            let command = BetContest {
+                ticker: ticker.to_owned(),
+                outcome_id: *outcome_to_bet_on,
+                user: self.info.sender.clone(),
+                contest_id: None,
+                roll_over,
+                refund_on_failure: None,
+            };
+            let response = handle_bet_on_contest(
+                self.deps.as_mut(),
+                self.env.clone(),
+                command,
+                Uint128::new(*amount_to_bet),
+                Currency::Snip20,
+            );
+            assert!(
+                response.is_err(),
+                "Expected bet on contest to fail, but it succeeded"
+            );
+        }
+
+        pub fn set_betting_cutoff_success(&mut self, seconds: u64) {
+            let command = SetBettingCutoff { seconds };
+            let response =
+                handle_set_betting_cutoff(self.deps.as_mut(), self.info.clone(), command)
+                    .expect("Expected set betting cutoff to succeed");
+            Self::assert_attribute(&response, "seconds", &seconds.to_string());
+        }
+
+        pub fn set_betting_cutoff_fail(&mut self, seconds: u64) {
+            let command = SetBettingCutoff { seconds };
+            let response =
+                handle_set_betting_cutoff(self.deps.as_mut(), self.info.clone(), command);
+            assert!(
+                response.is_err(),
+                "Expected set betting cutoff to fail but succeded"
+            )
+        }
+
+        pub fn get_betting_cutoff(&mut self, expected_seconds: u64) {
+            let binary_response = handle_get_betting_cutoff(self.deps.as_ref())
+                .expect("Expected GetBettingCutoff to succeed but failed");
+
+            let response: QueryResponse =
+                from_binary(&binary_response).expect("Failed to deserialize QueryResponse");
+
+            match response {
+                QueryResponse::BettingCutoff(betting_cutoff_response) => assert_eq!(
+                    betting_cutoff_response.betting_cutoff_seconds, expected_seconds,
+                    "Betting cutoff is not what was expected"
+                ),
+                _ => panic!("Expected BettingCutoff response but received something else"),
+            }
+        }
+
         pub fn set_minimum_bet_success(&mut self, minimum_bet: &u128) {
             let command = SetMinimumBet {
                 amount: Uint128::from(*minimum_bet),
+                currency: None,
             };
             let response = handle_set_minimum_bet(self.deps.as_mut(), self.info.clone(), command);
             assert!(response.is_ok(), "Expected Set minimum bet to succeed")
@@ -289,6 +604,7 @@ pub mod tests {
         pub fn set_minimum_bet_fail(&mut self, minimum_bet: &u128) {
             let command = SetMinimumBet {
                 amount: Uint128::from(*minimum_bet),
+                currency: None,
             };
             let response = handle_set_minimum_bet(self.deps.as_mut(), self.info.clone(), command);
             assert!(
@@ -297,378 +613,2383 @@ pub mod tests {
             )
         }
 
-        pub fn set_fee_success(&mut self, new_fee: FeePercent) {
-            let command = SetFee {
-                numerator: *new_fee.numerator() as u64,
-                denominator: *new_fee.denominator() as u64,
-            };
-            let response = handle_set_fee(self.deps.as_mut(), self.info.clone(), command);
-            assert!(response.is_ok(), "Expected Set fee to succeed")
+        pub fn set_bet_limits_success(
+            &mut self,
+            max_user_bet: Option<u128>,
+            max_pool: Option<u128>,
+            max_user_exposure: Option<u128>,
+        ) {
+            self.set_bet_limits_in_success(None, max_user_bet, max_pool, max_user_exposure);
         }
 
-        pub fn set_fee_fail(&mut self, new_fee: FeePercent) {
-            let command = SetFee {
-                numerator: *new_fee.numerator() as u64,
-                denominator: *new_fee.denominator() as u64,
+        pub fn set_bet_limits_in_success(
+            &mut self,
+            currency: Option<Currency>,
+            max_user_bet: Option<u128>,
+            max_pool: Option<u128>,
+            max_user_exposure: Option<u128>,
+        ) {
+            let command = SetBetLimits {
+                max_user_bet: max_user_bet.map(Uint128::new),
+                max_pool: max_pool.map(Uint128::new),
+                max_user_exposure: max_user_exposure.map(Uint128::new),
+                currency,
             };
-            let response = handle_set_fee(self.deps.as_mut(), self.info.clone(), command);
-            assert!(response.is_err(), "Expected set fee to fail but succeded")
+            let response = handle_set_bet_limits(self.deps.as_mut(), self.info.clone(), command)
+                .expect("Expected set bet limits to succeed");
+            Self::assert_attribute(&response, "action", "set_bet_limits");
         }
 
-        pub fn claim_fees_success(&mut self, expected_amount: Option<&u128>) {
-            let response_result = handle_claim_fees(self.deps.as_mut(), self.info.clone());
+        pub fn set_bet_limits_fail(&mut self, max_user_bet: Option<u128>) {
+            let command = SetBetLimits {
+                max_user_bet: max_user_bet.map(Uint128::new),
+                max_pool: None,
+                max_user_exposure: None,
+                currency: None,
+            };
+            let response = handle_set_bet_limits(self.deps.as_mut(), self.info.clone(), command);
             assert!(
-                response_result.is_ok(),
-                "Expected Claim to succeed but failed"
-            );
-            let response = response_result.unwrap();
-            assert_eq!(
-                response.messages.len(),
-                1,
-                "Expected claim response to have snip20 msg on it"
+                response.is_err(),
+                "Expected set bet limits to fail but succeeded"
             );
-            if let Some(binary_data) = response.data {
-                match from_binary::<ExecuteResponse>(&binary_data) {
-                    Ok(claim_response) => match claim_response {
-                        ExecuteResponse::Claim(claim_response) => {
-                            // Successfully deserialized and matched the Claim variant.
-                            // You can now use `claim_data` here.
-                            if let Some(expected) = expected_amount {
-                                assert_eq!(
-                                    claim_response.amount,
-                                    Uint128::from(*expected),
-                                    "Claim Amount does not match expected"
-                                )
-                            }
-                        }
-                        _ => assert!(false, "Could not deserialize claim response"),
-                    },
-                    Err(_e) => assert!(false, "Could not deserialize claim response"),
-                }
-            } else {
-                assert!(false, "Could not deserialize claim response")
-            }
         }
 
-        pub fn claim_fees_failure(&mut self) {
-            let response_result = handle_claim_fees(self.deps.as_mut(), self.info.clone());
-            assert!(
-                response_result.is_err(),
-                "Expected Claim Fees to Fail but Succeeded"
+        pub fn get_bet_limits(
+            &mut self,
+            expected_max_user_bet: Option<u128>,
+            expected_max_pool: Option<u128>,
+            expected_max_user_exposure: Option<u128>,
+        ) {
+            self.get_bet_limits_in(
+                None,
+                expected_max_user_bet,
+                expected_max_pool,
+                expected_max_user_exposure,
             );
         }
 
-        pub fn claim_success(&mut self, file_number: &u8, expected_amount: Option<&u128>) {
-            if let Ok(contest_info) = get_contest_open(*file_number) {
-                let command = Claim {
-                    contest_id: contest_info.get_id(),
-                };
-                let response_result = handle_claim(
-                    self.deps.as_mut(),
-                    self.env.clone(),
-                    self.info.clone(),
-                    command,
-                );
-                assert!(
-                    response_result.is_ok(),
-                    "Expected Claim to succeed but failed: {:?}",
-                    response_result
-                );
-                let response = response_result.unwrap();
-                assert_eq!(
-                    response.messages.len(),
-                    1,
-                    "Expected claim response to have snip20 msg on it"
-                );
-                if let Some(binary_data) = response.data {
-                    match from_binary::<ExecuteResponse>(&binary_data) {
-                        Ok(claim_response) => match claim_response {
-                            ExecuteResponse::Claim(claim_response) => {
-                                // Successfully deserialized and matched the Claim variant.
-                                // You can now use `claim_data` here.
-                                if let Some(expected) = expected_amount {
-                                    assert_eq!(
-                                        claim_response.amount,
-                                        Uint128::from(*expected),
-                                        "Claim Amount does not match expected"
-                                    )
-                                }
-                            }
-                            _ => assert!(false, "Could not deserialize claim response"),
-                        },
-                        Err(_e) => assert!(false, "Could not deserialize claim response"),
-                    }
-                } else {
-                    assert!(false, "Could not deserialize claim response")
+        pub fn get_bet_limits_in(
+            &mut self,
+            currency: Option<Currency>,
+            expected_max_user_bet: Option<u128>,
+            expected_max_pool: Option<u128>,
+            expected_max_user_exposure: Option<u128>,
+        ) {
+            let command = GetBetLimits { currency };
+            let binary_response = handle_get_bet_limits(self.deps.as_ref(), command)
+                .expect("Expected GetBetLimits to succeed but failed");
+
+            let response: QueryResponse =
+                from_binary(&binary_response).expect("Failed to deserialize QueryResponse");
+            match response {
+                QueryResponse::BetLimits(bet_limits_response) => {
+                    assert_eq!(
+                        bet_limits_response.max_user_bet,
+                        expected_max_user_bet.map(Uint128::new),
+                        "Max user bet is not what was expected"
+                    );
+                    assert_eq!(
+                        bet_limits_response.max_pool,
+                        expected_max_pool.map(Uint128::new),
+                        "Max pool is not what was expected"
+                    );
+                    assert_eq!(
+                        bet_limits_response.max_user_exposure,
+                        expected_max_user_exposure.map(Uint128::new),
+                        "Max user exposure is not what was expected"
+                    );
                 }
-            } else {
-                assert!(
-                    false,
-                    "Contest Info not found or already closed for betting"
-                )
+                _ => panic!("Expected BetLimits response but received something else"),
             }
         }
 
-        pub fn claim_failure(&mut self, file_number: &u8) {
-            if let Ok(contest_info) = get_contest_open(*file_number) {
-                let command = Claim {
-                    contest_id: contest_info.get_id(),
-                };
-                let response_result = handle_claim(
-                    self.deps.as_mut(),
-                    self.env.clone(),
-                    self.info.clone(),
-                    command,
-                );
-                assert!(
-                    response_result.is_err(),
-                    "Expected Claim to Fail but Succeeded"
-                );
-            } else {
-                assert!(
-                    false,
-                    "Contest Info not found or already closed for betting"
-                )
-            }
+        pub fn set_native_minimum_bet_success(&mut self, denom: &str, minimum_bet: &u128) {
+            let command = SetMinimumBet {
+                amount: Uint128::from(*minimum_bet),
+                currency: Some(Currency::Native {
+                    denom: denom.to_owned(),
+                }),
+            };
+            handle_set_minimum_bet(self.deps.as_mut(), self.info.clone(), command)
+                .expect("Expected set native minimum bet to succeed");
         }
 
-        pub fn claim_multiple_failure(&mut self, file_numbers: Vec<&u8>) {
-            let mut requested_ids = Vec::new();
-
-            // Loop through each file number to get contest info and signature.
-            for file_number in file_numbers {
-                let contest_info = Self::get_open_contest_from_file(file_number);
+        pub fn set_token_minimum_bet_success(&mut self, token: &str, minimum_bet: &u128) {
+            let command = SetMinimumBet {
+                amount: Uint128::from(*minimum_bet),
+                currency: Some(Currency::Token {
+                    address: token.to_owned(),
+                }),
+            };
+            handle_set_minimum_bet(self.deps.as_mut(), self.info.clone(), command)
+                .expect("Expected set token minimum bet to succeed");
+        }
 
-                // Collect the id from contest_info.
-                requested_ids.push(contest_info.get_id());
-            }
-            let command = ClaimMultiple {
-                contest_ids: requested_ids,
+        pub fn add_accepted_token_success(
+            &mut self,
+            token: &str,
+            minimum_bet: &u128,
+            expected_messages: usize,
+        ) {
+            let command = AddAcceptedToken {
+                token: ContractInfo {
+                    address: Addr::unchecked(token),
+                    code_hash: format!("{} Codehash", token),
+                },
+                minimum_bet: Uint128::from(*minimum_bet),
+                entropy: Binary::from(b"entropy"),
+            };
+            let response = handle_add_accepted_token(
+                self.deps.as_mut(),
+                self.env.clone(),
+                self.info.clone(),
+                command,
+            )
+            .expect("Expected add accepted token to succeed");
+            // New tokens register the receive hook and set a viewing key
+            assert_eq!(
+                response.messages.len(),
+                expected_messages,
+                "Unexpected number of token registration messages"
+            );
+            Self::assert_attribute(&response, "token", token);
+        }
+
+        // Answers the outstanding SetViewingKey as the token would
+        pub fn set_viewing_key_reply(&mut self, succeeded: bool) {
+            let result = if succeeded {
+                SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                })
+            } else {
+                SubMsgResult::Err("Viewing key not set".to_owned())
+            };
+            handle_set_viewing_key_reply(self.deps.as_mut(), result)
+                .expect("Expected set viewing key reply to succeed");
+        }
+
+        pub fn get_viewing_key_set(&mut self, token: &str, expected: bool) {
+            let binary_response = handle_get_accepted_tokens(self.deps.as_ref())
+                .expect("Expected GetAcceptedTokens to succeed but failed");
+
+            let response: QueryResponse =
+                from_binary(&binary_response).expect("Failed to deserialize QueryResponse");
+            match response {
+                QueryResponse::AcceptedTokens(accepted_tokens_response) => {
+                    let accepted_token = accepted_tokens_response
+                        .tokens
+                        .iter()
+                        .find(|accepted_token| accepted_token.token.address.as_str() == token)
+                        .expect("Expected token to be listed");
+                    assert_eq!(
+                        accepted_token.viewing_key_set, expected,
+                        "Viewing key status is not what was expected"
+                    );
+                }
+                _ => panic!("Expected AcceptedTokens response but received something else"),
+            }
+        }
+
+        pub fn add_accepted_token_fail(&mut self, token: &str) {
+            let command = AddAcceptedToken {
+                token: ContractInfo {
+                    address: Addr::unchecked(token),
+                    code_hash: format!("{} Codehash", token),
+                },
+                minimum_bet: Uint128::new(1),
+                entropy: Binary::from(b"entropy"),
+            };
+            let response = handle_add_accepted_token(
+                self.deps.as_mut(),
+                self.env.clone(),
+                self.info.clone(),
+                command,
+            );
+            assert!(
+                response.is_err(),
+                "Expected add accepted token to fail but succeeded"
+            );
+        }
+
+        pub fn remove_accepted_token_success(&mut self, token: &str) {
+            let command = RemoveAcceptedToken {
+                address: token.to_owned(),
+            };
+            let response =
+                handle_remove_accepted_token(self.deps.as_mut(), self.info.clone(), command)
+                    .expect("Expected remove accepted token to succeed");
+            Self::assert_attribute(&response, "token", token);
+        }
+
+        pub fn remove_accepted_token_fail(&mut self, token: &str) {
+            let command = RemoveAcceptedToken {
+                address: token.to_owned(),
+            };
+            let response =
+                handle_remove_accepted_token(self.deps.as_mut(), self.info.clone(), command);
+            assert!(
+                response.is_err(),
+                "Expected remove accepted token to fail but succeeded"
+            );
+        }
+
+        pub fn get_accepted_tokens(&mut self, expected_tokens: Vec<(&str, u128, u128, bool)>) {
+            let binary_response = handle_get_accepted_tokens(self.deps.as_ref())
+                .expect("Expected GetAcceptedTokens to succeed but failed");
+
+            let response: QueryResponse =
+                from_binary(&binary_response).expect("Failed to deserialize QueryResponse");
+            match response {
+                QueryResponse::AcceptedTokens(accepted_tokens_response) => assert_eq!(
+                    accepted_tokens_response
+                        .tokens
+                        .iter()
+                        .map(|token| (
+                            token.token.address.as_str(),
+                            token.minimum_bet.u128(),
+                            token.claimable_fees.u128(),
+                            token.accepting_bets,
+                        ))
+                        .collect::<Vec<(&str, u128, u128, bool)>>(),
+                    expected_tokens,
+                    "Accepted tokens are not what was expected"
+                ),
+                _ => panic!("Expected AcceptedTokens response but received something else"),
+            }
+        }
+
+        pub fn handle_receive_fail(
+            &mut self,
+            file_number: &u8,
+            outcome_to_bet_on: &u8,
+            amount_to_bet: &u128,
+            sender: &str,
+        ) {
+            let contest_info = Self::get_open_contest_from_file(file_number);
+            let message = InvokeMsg::BetContest(BetContest {
+                ticker: contest_info.get_ticker(),
+                outcome_id: *outcome_to_bet_on,
+                user: self.info.sender.clone(),
+                contest_id: None,
+                roll_over: None,
+                refund_on_failure: None,
+            });
+            let command = Receive {
+                sender: Addr::unchecked(sender),
+                from: Addr::unchecked("Address"),
+                amount: Uint128::new(*amount_to_bet),
+                memo: None,
+                msg: to_binary(&message).expect("Failed to serialize message"),
+            };
+            let info = mock_info(sender, &[]);
+            let response = handle_receive(self.deps.as_mut(), self.env.clone(), info, command);
+            assert!(
+                response.is_err(),
+                "Expected bet through receive to fail but succeeded"
+            );
+        }
+
+        pub fn claim_token_success(
+            &mut self,
+            file_number: &u8,
+            expected_amount: &u128,
+            token: &str,
+        ) {
+            let currency = Currency::Token {
+                address: token.to_owned(),
+            };
+            let command = Claim {
+                contest_id: Self::get_pool_id_from_file(file_number, &currency),
+            };
+            let response = handle_claim(
+                self.deps.as_mut(),
+                self.env.clone(),
+                self.info.clone(),
+                command,
+                &self.price_source,
+            )
+            .expect("Expected token claim to succeed");
+            assert_eq!(response.messages.len(), 1, "Expected a single transfer");
+            match &response.messages[0].msg {
+                CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, .. }) => assert_eq!(
+                    contract_addr, token,
+                    "Expected the payout to be sent by the contest's token"
+                ),
+                _ => panic!("Expected a token transfer"),
+            }
+            match from_binary::<ExecuteResponse>(&response.data.expect("Expected response data")) {
+                Ok(ExecuteResponse::Claim(claim_response)) => assert_eq!(
+                    claim_response.amount,
+                    Uint128::from(*expected_amount),
+                    "Claim Amount does not match expected"
+                ),
+                _ => panic!("Could not deserialize claim response"),
+            }
+        }
+
+        pub fn claim_token_fees_success(&mut self, token: &str, expected_amount: &u128) {
+            let command = ClaimFees {
+                amount: None,
+                recipient: None,
+                currency: Some(Currency::Token {
+                    address: token.to_owned(),
+                }),
+            };
+            self.claim_fees_command_success(command, Some(expected_amount));
+        }
+
+        pub fn set_fee_success(&mut self, new_fee: FeePercent) {
+            let command = SetFee {
+                numerator: *new_fee.numerator() as u64,
+                denominator: *new_fee.denominator() as u64,
+            };
+            let response = handle_set_fee(
+                self.deps.as_mut(),
+                self.env.clone(),
+                self.info.clone(),
+                command,
+            );
+            assert!(response.is_ok(), "Expected Set fee to succeed")
+        }
+
+        pub fn set_fee_fail(&mut self, new_fee: FeePercent) {
+            let command = SetFee {
+                numerator: *new_fee.numerator() as u64,
+                denominator: *new_fee.denominator() as u64,
+            };
+            let response = handle_set_fee(
+                self.deps.as_mut(),
+                self.env.clone(),
+                self.info.clone(),
+                command,
+            );
+            assert!(response.is_err(), "Expected set fee to fail but succeded")
+        }
+
+        pub fn set_max_fee_success(&mut self, max_fee: FeePercent) {
+            let command = SetMaxFee {
+                numerator: *max_fee.numerator() as u64,
+                denominator: *max_fee.denominator() as u64,
+            };
+            let response = handle_set_max_fee(self.deps.as_mut(), self.info.clone(), command);
+            assert!(response.is_ok(), "Expected set max fee to succeed")
+        }
+
+        pub fn set_max_fee_fail(&mut self, max_fee: FeePercent) {
+            let command = SetMaxFee {
+                numerator: *max_fee.numerator() as u64,
+                denominator: *max_fee.denominator() as u64,
+            };
+            let response = handle_set_max_fee(self.deps.as_mut(), self.info.clone(), command);
+            assert!(
+                response.is_err(),
+                "Expected set max fee to fail but succeded"
+            )
+        }
+
+        pub fn schedule_fee_change_success(&mut self, new_fee: FeePercent, effective_at: u64) {
+            let command = ScheduleFeeChange {
+                numerator: *new_fee.numerator() as u64,
+                denominator: *new_fee.denominator() as u64,
+                effective_at,
+            };
+            let response = handle_schedule_fee_change(
+                self.deps.as_mut(),
+                self.env.clone(),
+                self.info.clone(),
+                command,
+            )
+            .expect("Expected schedule fee change to succeed");
+            Self::assert_attribute(&response, "action", "schedule_fee_change");
+            Self::assert_attribute(&response, "effective_at", &effective_at.to_string());
+        }
+
+        pub fn schedule_fee_change_fail(&mut self, new_fee: FeePercent, effective_at: u64) {
+            let command = ScheduleFeeChange {
+                numerator: *new_fee.numerator() as u64,
+                denominator: *new_fee.denominator() as u64,
+                effective_at,
+            };
+            let response = handle_schedule_fee_change(
+                self.deps.as_mut(),
+                self.env.clone(),
+                self.info.clone(),
+                command,
+            );
+            assert!(
+                response.is_err(),
+                "Expected schedule fee change to fail but succeded"
+            )
+        }
+
+        pub fn get_pending_fee_change(&mut self, expected: Option<(FeePercent, u64)>) {
+            let binary_response =
+                handle_get_pending_fee_change(self.deps.as_ref(), self.env.clone())
+                    .expect("Expected GetPendingFeeChange to succeed but failed");
+
+            let response: QueryResponse =
+                from_binary(&binary_response).expect("Failed to deserialize QueryResponse");
+
+            match response {
+                QueryResponse::PendingFeeChange(pending_fee_change_response) => {
+                    let pending_fee_change = pending_fee_change_response
+                        .pending_fee_change
+                        .map(|change| (change.fee_percent().clone(), *change.effective_at()));
+                    assert_eq!(
+                        pending_fee_change, expected,
+                        "Pending fee change is not what was expected"
+                    );
+                }
+                _ => panic!("Expected PendingFeeChange response but received something else"),
+            }
+        }
+
+        pub fn get_max_fee_percent(&mut self, expected_max_fee_percent: &FeePercent) {
+            let binary_response = handle_get_fee_percent(self.deps.as_ref(), self.env.clone())
+                .expect("Expected GetFeePercent to succeed but failed");
+
+            let response: QueryResponse =
+                from_binary(&binary_response).expect("Failed to deserialize QueryResponse");
+
+            match response {
+                QueryResponse::FeePercent(fee_percent_response) => {
+                    assert_eq!(
+                        fee_percent_response.max_fee_percent, *expected_max_fee_percent,
+                        "Max fee percent is not what was expected"
+                    );
+                }
+                _ => panic!("Expected FeePercent response but received something else"),
+            }
+        }
+
+        pub fn add_ticker_success(&mut self, ticker: &str) {
+            let command = AddTicker {
+                ticker: ticker.to_owned(),
+            };
+            let response = handle_add_ticker(
+                self.deps.as_mut(),
+                self.env.clone(),
+                self.info.clone(),
+                command,
+            );
+            assert!(response.is_ok(), "Expected add ticker to succeed")
+        }
+
+        pub fn add_ticker_fail(&mut self, ticker: &str) {
+            let command = AddTicker {
+                ticker: ticker.to_owned(),
+            };
+            let response = handle_add_ticker(
+                self.deps.as_mut(),
+                self.env.clone(),
+                self.info.clone(),
+                command,
+            );
+            assert!(
+                response.is_err(),
+                "Expected add ticker to fail but succeded"
+            )
+        }
+
+        pub fn remove_ticker_success(&mut self, ticker: &str) {
+            let command = RemoveTicker {
+                ticker: ticker.to_owned(),
+            };
+            let response = handle_remove_ticker(self.deps.as_mut(), self.info.clone(), command);
+            assert!(response.is_ok(), "Expected remove ticker to succeed")
+        }
+
+        pub fn remove_ticker_fail(&mut self, ticker: &str) {
+            let command = RemoveTicker {
+                ticker: ticker.to_owned(),
+            };
+            let response = handle_remove_ticker(self.deps.as_mut(), self.info.clone(), command);
+            assert!(
+                response.is_err(),
+                "Expected remove ticker to fail but succeded"
+            )
+        }
+
+        pub fn pause_ticker_success(&mut self, ticker: &str, paused: bool) {
+            let command = PauseTicker {
+                ticker: ticker.to_owned(),
+                paused,
+            };
+            let response = handle_pause_ticker(self.deps.as_mut(), self.info.clone(), command);
+            assert!(response.is_ok(), "Expected pause ticker to succeed")
+        }
+
+        pub fn pause_ticker_fail(&mut self, ticker: &str, paused: bool) {
+            let command = PauseTicker {
+                ticker: ticker.to_owned(),
+                paused,
+            };
+            let response = handle_pause_ticker(self.deps.as_mut(), self.info.clone(), command);
+            assert!(
+                response.is_err(),
+                "Expected pause ticker to fail but succeded"
+            )
+        }
+
+        pub fn claim_fees_success(&mut self, expected_amount: Option<&u128>) {
+            let command = ClaimFees {
+                amount: None,
+                recipient: None,
+                currency: None,
+            };
+            self.claim_fees_command_success(command, expected_amount);
+        }
+
+        pub fn claim_fees_failure(&mut self) {
+            let command = ClaimFees {
+                amount: None,
+                recipient: None,
+                currency: None,
+            };
+            self.claim_fees_command_failure(command);
+        }
+
+        pub fn withdraw_fees_success(&mut self, amount: &u128, recipient: Option<&str>) {
+            let command = ClaimFees {
+                amount: Some(Uint128::from(*amount)),
+                recipient: recipient.map(Addr::unchecked),
+                currency: None,
+            };
+            self.claim_fees_command_success(command, Some(amount));
+        }
+
+        pub fn withdraw_fees_failure(&mut self, amount: &u128, recipient: Option<&str>) {
+            let command = ClaimFees {
+                amount: Some(Uint128::from(*amount)),
+                recipient: recipient.map(Addr::unchecked),
+                currency: None,
+            };
+            self.claim_fees_command_failure(command);
+        }
+
+        fn claim_fees_command_success(
+            &mut self,
+            command: ClaimFees,
+            expected_amount: Option<&u128>,
+        ) {
+            let response_result = handle_claim_fees(
+                self.deps.as_mut(),
+                self.env.clone(),
+                self.info.clone(),
+                command,
+            );
+            assert!(
+                response_result.is_ok(),
+                "Expected Claim to succeed but failed"
+            );
+            let response = response_result.unwrap();
+            assert_eq!(
+                response.messages.len(),
+                1,
+                "Expected claim response to have snip20 msg on it"
+            );
+            if let Some(binary_data) = response.data {
+                match from_binary::<ExecuteResponse>(&binary_data) {
+                    Ok(claim_response) => match claim_response {
+                        ExecuteResponse::Claim(claim_response) => {
+                            if let Some(expected) = expected_amount {
+                                assert_eq!(
+                                    claim_response.amount,
+                                    Uint128::from(*expected),
+                                    "Claim Amount does not match expected"
+                                )
+                            }
+                        }
+                        _ => assert!(false, "Could not deserialize claim response"),
+                    },
+                    Err(_e) => assert!(false, "Could not deserialize claim response"),
+                }
+            } else {
+                assert!(false, "Could not deserialize claim response")
+            }
+        }
+
+        fn claim_fees_command_failure(&mut self, command: ClaimFees) {
+            let response_result = handle_claim_fees(
+                self.deps.as_mut(),
+                self.env.clone(),
+                self.info.clone(),
+                command,
+            );
+            assert!(
+                response_result.is_err(),
+                "Expected Claim Fees to Fail but Succeeded"
+            );
+        }
+
+        pub fn claim_success(&mut self, file_number: &u8, expected_amount: Option<&u128>) {
+            if let Ok(contest_info) = get_contest_open(*file_number) {
+                let command = Claim {
+                    contest_id: contest_info.get_id(),
+                };
+                let response_result = handle_claim(
+                    self.deps.as_mut(),
+                    self.env.clone(),
+                    self.info.clone(),
+                    command,
+                    &self.price_source,
+                );
+                assert!(
+                    response_result.is_ok(),
+                    "Expected Claim to succeed but failed: {:?}",
+                    response_result
+                );
+                let response = response_result.unwrap();
+                assert_eq!(
+                    response.messages.len(),
+                    1,
+                    "Expected claim response to have snip20 msg on it"
+                );
+                if let Some(binary_data) = response.data {
+                    match from_binary::<ExecuteResponse>(&binary_data) {
+                        Ok(claim_response) => match claim_response {
+                            ExecuteResponse::Claim(claim_response) => {
+                                // Successfully deserialized and matched the Claim variant.
+                                // You can now use `claim_data` here.
+                                if let Some(expected) = expected_amount {
+                                    assert_eq!(
+                                        claim_response.amount,
+                                        Uint128::from(*expected),
+                                        "Claim Amount does not match expected"
+                                    )
+                                }
+                            }
+                            _ => assert!(false, "Could not deserialize claim response"),
+                        },
+                        Err(_e) => assert!(false, "Could not deserialize claim response"),
+                    }
+                } else {
+                    assert!(false, "Could not deserialize claim response")
+                }
+            } else {
+                assert!(
+                    false,
+                    "Contest Info not found or already closed for betting"
+                )
+            }
+        }
+
+        pub fn cancel_bet_success(
+            &mut self,
+            file_number: &u8,
+            expected_refund: &u128,
+            expected_fee: &u128,
+        ) {
+            self.cancel_bet_in_success(
+                file_number,
+                &Currency::Snip20,
+                expected_refund,
+                expected_fee,
+            );
+        }
+
+        pub fn cancel_bet_in_success(
+            &mut self,
+            file_number: &u8,
+            currency: &Currency,
+            expected_refund: &u128,
+            expected_fee: &u128,
+        ) {
+            let command = CancelBet {
+                contest_id: Self::get_pool_id_from_file(file_number, currency),
+            };
+            let response = handle_cancel_bet(
+                self.deps.as_mut(),
+                self.env.clone(),
+                self.info.clone(),
+                command,
+            )
+            .expect("Expected cancel bet to succeed");
+            let expected_messages = if *expected_refund == 0 { 0 } else { 1 };
+            assert_eq!(
+                response.messages.len(),
+                expected_messages,
+                "Expected cancel bet response to refund only when owed"
+            );
+            match from_binary::<ExecuteResponse>(&response.data.expect("Expected response data")) {
+                Ok(ExecuteResponse::CancelBet(cancel_bet_response)) => {
+                    assert_eq!(cancel_bet_response.status, ResponseStatus::Success);
+                    assert_eq!(
+                        cancel_bet_response.refunded,
+                        Uint128::from(*expected_refund),
+                        "Refunded amount does not match expected"
+                    );
+                    assert_eq!(
+                        cancel_bet_response.fee,
+                        Uint128::from(*expected_fee),
+                        "Cancellation fee does not match expected"
+                    );
+                }
+                _ => panic!("Could not deserialize cancel bet response"),
+            }
+        }
+
+        pub fn cancel_bet_fail(&mut self, file_number: &u8) {
+            let contest_info = Self::get_open_contest_from_file(file_number);
+            let command = CancelBet {
+                contest_id: contest_info.get_id(),
+            };
+            let response = handle_cancel_bet(
+                self.deps.as_mut(),
+                self.env.clone(),
+                self.info.clone(),
+                command,
+            );
+            assert!(
+                response.is_err(),
+                "Expected cancel bet to fail but succeeded"
+            );
+        }
+
+        pub fn switch_side_success(
+            &mut self,
+            file_number: &u8,
+            outcome_id: &u8,
+            expected_amount: &u128,
+            expected_fee: &u128,
+        ) {
+            let contest_info = Self::get_open_contest_from_file(file_number);
+            let command = SwitchSide {
+                contest_id: contest_info.get_id(),
+                outcome_id: *outcome_id,
+            };
+            let response = handle_switch_side(
+                self.deps.as_mut(),
+                self.env.clone(),
+                self.info.clone(),
+                command,
+            )
+            .expect("Expected switch side to succeed");
+            assert!(
+                response.messages.is_empty(),
+                "Expected switch side not to move any tokens"
+            );
+            match from_binary::<ExecuteResponse>(&response.data.expect("Expected response data")) {
+                Ok(ExecuteResponse::SwitchSide(switch_side_response)) => {
+                    assert_eq!(switch_side_response.outcome_id, *outcome_id);
+                    assert_eq!(
+                        switch_side_response.amount,
+                        Uint128::from(*expected_amount),
+                        "Switched amount does not match expected"
+                    );
+                    assert_eq!(
+                        switch_side_response.fee,
+                        Uint128::from(*expected_fee),
+                        "Switching fee does not match expected"
+                    );
+                }
+                _ => panic!("Could not deserialize switch side response"),
+            }
+        }
+
+        pub fn switch_side_fail(&mut self, file_number: &u8, outcome_id: &u8) {
+            let contest_info = Self::get_open_contest_from_file(file_number);
+            let command = SwitchSide {
+                contest_id: contest_info.get_id(),
+                outcome_id: *outcome_id,
+            };
+            let response = handle_switch_side(
+                self.deps.as_mut(),
+                self.env.clone(),
+                self.info.clone(),
+                command,
+            );
+            assert!(
+                response.is_err(),
+                "Expected switch side to fail but succeeded"
+            );
+        }
+
+        pub fn set_switching_fee_success(&mut self, fee: FeePercent) {
+            let command = SetSwitchingFee {
+                numerator: *fee.numerator() as u64,
+                denominator: *fee.denominator() as u64,
+            };
+            let response = handle_set_switching_fee(self.deps.as_mut(), self.info.clone(), command)
+                .expect("Expected set switching fee to succeed");
+            Self::assert_attribute(&response, "action", "set_switching_fee");
+        }
+
+        pub fn set_switching_fee_fail(&mut self, fee: FeePercent) {
+            let command = SetSwitchingFee {
+                numerator: *fee.numerator() as u64,
+                denominator: *fee.denominator() as u64,
+            };
+            let response = handle_set_switching_fee(self.deps.as_mut(), self.info.clone(), command);
+            assert!(
+                response.is_err(),
+                "Expected set switching fee to fail but succeeded"
+            );
+        }
+
+        pub fn set_keeper_reward_success(&mut self, reward: FeePercent) {
+            let command = SetKeeperReward {
+                numerator: *reward.numerator() as u64,
+                denominator: *reward.denominator() as u64,
+            };
+            let response = handle_set_keeper_reward(self.deps.as_mut(), self.info.clone(), command)
+                .expect("Expected set keeper reward to succeed");
+            Self::assert_attribute(&response, "action", "set_keeper_reward");
+        }
+
+        pub fn set_keeper_reward_fail(&mut self, reward: FeePercent) {
+            let command = SetKeeperReward {
+                numerator: *reward.numerator() as u64,
+                denominator: *reward.denominator() as u64,
+            };
+            let response = handle_set_keeper_reward(self.deps.as_mut(), self.info.clone(), command);
+            assert!(
+                response.is_err(),
+                "Expected set keeper reward to fail but succeeded"
+            );
+        }
+
+        pub fn resolve_contests_success(
+            &mut self,
+            file_numbers: &[u8],
+            expected_resolved: &[(u8, u128)],
+        ) {
+            let command = ResolveContests {
+                contest_ids: file_numbers
+                    .iter()
+                    .map(|file_number| Self::get_open_contest_from_file(file_number).get_id())
+                    .collect(),
+            };
+            let response = handle_resolve_contests(
+                self.deps.as_mut(),
+                self.env.clone(),
+                self.info.clone(),
+                command,
+                &self.price_source,
+            )
+            .expect("Expected resolve contests to succeed");
+            let expected_messages = if expected_resolved.iter().any(|(_, reward)| *reward > 0) {
+                1
+            } else {
+                0
+            };
+            assert_eq!(
+                response.messages.len(),
+                expected_messages,
+                "Expected one transfer when a keeper reward is paid"
+            );
+            match from_binary::<ExecuteResponse>(&response.data.expect("Expected response data")) {
+                Ok(ExecuteResponse::ResolveContests(resolve_contests_response)) => {
+                    assert_eq!(resolve_contests_response.status, ResponseStatus::Success);
+                    let expected: Vec<(ContestId, Uint128)> = expected_resolved
+                        .iter()
+                        .map(|(file_number, reward)| {
+                            (
+                                Self::get_open_contest_from_file(file_number).get_id(),
+                                Uint128::from(*reward),
+                            )
+                        })
+                        .collect();
+                    assert_eq!(
+                        resolve_contests_response
+                            .resolved
+                            .iter()
+                            .map(|resolved| (resolved.contest_id.clone(), resolved.keeper_reward))
+                            .collect::<Vec<(ContestId, Uint128)>>(),
+                        expected,
+                        "Resolved contests are not what was expected"
+                    );
+                }
+                _ => panic!("Could not deserialize resolve contests response"),
+            }
+        }
+
+        pub fn resolve_contest_manually_success(
+            &mut self,
+            file_number: &u8,
+            close_price: &str,
+            resolve_price: &str,
+            reason: &str,
+            expected_outcome_id: &u8,
+        ) {
+            let command = ResolveContestManually {
+                contest_id: Self::get_open_contest_from_file(file_number).get_id(),
+                close_price: Decimal::from_str(close_price).unwrap(),
+                resolve_price: Decimal::from_str(resolve_price).unwrap(),
+                reason: reason.to_owned(),
+            };
+            let response = handle_resolve_contest_manually(
+                self.deps.as_mut(),
+                self.env.clone(),
+                self.info.clone(),
+                command,
+            )
+            .expect("Expected manual resolution to succeed");
+            Self::assert_attribute(&response, "action", "resolve_contest_manually");
+            Self::assert_attribute(&response, "outcome_id", &expected_outcome_id.to_string());
+            Self::assert_attribute(&response, "reason", reason);
+        }
+
+        pub fn resolve_contest_manually_fail(
+            &mut self,
+            file_number: &u8,
+            close_price: &str,
+            resolve_price: &str,
+            reason: &str,
+        ) {
+            let command = ResolveContestManually {
+                contest_id: Self::get_open_contest_from_file(file_number).get_id(),
+                close_price: Decimal::from_str(close_price).unwrap(),
+                resolve_price: Decimal::from_str(resolve_price).unwrap(),
+                reason: reason.to_owned(),
+            };
+            let response = handle_resolve_contest_manually(
+                self.deps.as_mut(),
+                self.env.clone(),
+                self.info.clone(),
+                command,
+            );
+            assert!(
+                response.is_err(),
+                "Expected manual resolution to fail but succeeded"
+            );
+        }
+
+        pub fn void_contest_success(&mut self, file_number: &u8, reason: &str) {
+            let command = VoidContest {
+                contest_id: Self::get_open_contest_from_file(file_number).get_id(),
+                reason: reason.to_owned(),
+            };
+            let response = handle_void_contest(
+                self.deps.as_mut(),
+                self.env.clone(),
+                self.info.clone(),
+                command,
+            )
+            .expect("Expected void contest to succeed");
+            Self::assert_attribute(&response, "action", "void_contest");
+            Self::assert_attribute(&response, "reason", reason);
+        }
+
+        pub fn void_contest_fail(&mut self, file_number: &u8, reason: &str) {
+            let command = VoidContest {
+                contest_id: Self::get_open_contest_from_file(file_number).get_id(),
+                reason: reason.to_owned(),
+            };
+            let response = handle_void_contest(
+                self.deps.as_mut(),
+                self.env.clone(),
+                self.info.clone(),
+                command,
+            );
+            assert!(
+                response.is_err(),
+                "Expected void contest to fail but succeeded"
+            );
+        }
+
+        pub fn get_manual_resolution_reason(
+            &mut self,
+            file_number: &u8,
+            expected_reason: Option<&str>,
+        ) {
+            let command = GetContestById {
+                contest_id: Self::get_open_contest_from_file(file_number).get_id(),
+            };
+            let binary_response = handle_get_contest_by_id(self.deps.as_ref(), command)
+                .expect("Expected Get Contest to succeed but failed");
+            match from_binary::<QueryResponse>(&binary_response) {
+                Ok(QueryResponse::ContestData(contest_data)) => {
+                    assert_eq!(
+                        contest_data
+                            .manual_resolution
+                            .as_ref()
+                            .map(|resolution| resolution.reason().as_str()),
+                        expected_reason,
+                        "Manual resolution reason is not what was expected"
+                    );
+                }
+                _ => panic!("Expected ContestData response but received something else"),
+            }
+        }
+
+        pub fn set_expiration_window_success(&mut self, seconds: u64) {
+            let command = SetExpirationWindow { seconds };
+            let response =
+                handle_set_expiration_window(self.deps.as_mut(), self.info.clone(), command)
+                    .expect("Expected set expiration window to succeed");
+            Self::assert_attribute(&response, "action", "set_expiration_window");
+        }
+
+        pub fn set_expiration_window_fail(&mut self, seconds: u64) {
+            let command = SetExpirationWindow { seconds };
+            let response =
+                handle_set_expiration_window(self.deps.as_mut(), self.info.clone(), command);
+            assert!(
+                response.is_err(),
+                "Expected set expiration window to fail but succeeded"
+            );
+        }
+
+        pub fn get_expiration_window(&mut self, expected_seconds: u64) {
+            let binary_response = handle_get_expiration_window(self.deps.as_ref())
+                .expect("Expected get expiration window to succeed");
+            match from_binary::<QueryResponse>(&binary_response) {
+                Ok(QueryResponse::ExpirationWindow(expiration_window)) => {
+                    assert_eq!(
+                        expiration_window.expiration_window_seconds, expected_seconds,
+                        "Expiration window is not what was expected"
+                    );
+                }
+                _ => panic!("Expected ExpirationWindow response but received something else"),
+            }
+        }
+
+        pub fn set_cancellation_fee_success(&mut self, fee: FeePercent) {
+            let command = SetCancellationFee {
+                numerator: *fee.numerator() as u64,
+                denominator: *fee.denominator() as u64,
+            };
+            let response =
+                handle_set_cancellation_fee(self.deps.as_mut(), self.info.clone(), command)
+                    .expect("Expected set cancellation fee to succeed");
+            Self::assert_attribute(&response, "action", "set_cancellation_fee");
+        }
+
+        pub fn set_cancellation_fee_fail(&mut self, fee: FeePercent) {
+            let command = SetCancellationFee {
+                numerator: *fee.numerator() as u64,
+                denominator: *fee.denominator() as u64,
+            };
+            let response =
+                handle_set_cancellation_fee(self.deps.as_mut(), self.info.clone(), command);
+            assert!(
+                response.is_err(),
+                "Expected set cancellation fee to fail but succeeded"
+            );
+        }
+
+        pub fn claim_failure(&mut self, file_number: &u8) {
+            if let Ok(contest_info) = get_contest_open(*file_number) {
+                let command = Claim {
+                    contest_id: contest_info.get_id(),
+                };
+                let response_result = handle_claim(
+                    self.deps.as_mut(),
+                    self.env.clone(),
+                    self.info.clone(),
+                    command,
+                    &self.price_source,
+                );
+                assert!(
+                    response_result.is_err(),
+                    "Expected Claim to Fail but Succeeded"
+                );
+            } else {
+                assert!(
+                    false,
+                    "Contest Info not found or already closed for betting"
+                )
+            }
+        }
+
+        pub fn claim_all_success(
+            &mut self,
+            max_contests: u32,
+            expected_claims: &[(u8, u128)],
+            expected_remaining: u32,
+        ) {
+            let command = ClaimAll { max_contests };
+            let response = handle_claim_all(
+                self.deps.as_mut(),
+                self.env.clone(),
+                self.info.clone(),
+                command,
+                &self.price_source,
+            )
+            .expect("Expected ClaimAll to succeed but it failed");
+            let expected_messages = if expected_claims.is_empty() { 0 } else { 1 };
+            assert_eq!(
+                response.messages.len(),
+                expected_messages,
+                "Expected one transfer when anything is claimed"
+            );
+            match from_binary::<ExecuteResponse>(&response.data.expect("Expected response data")) {
+                Ok(ExecuteResponse::ClaimAll(claim_all_response)) => {
+                    assert_eq!(claim_all_response.status, ResponseStatus::Success);
+                    let expected: Vec<(ContestId, Uint128)> = expected_claims
+                        .iter()
+                        .map(|(file_number, amount)| {
+                            (
+                                Self::get_open_contest_from_file(file_number).get_id(),
+                                Uint128::from(*amount),
+                            )
+                        })
+                        .collect();
+                    assert_eq!(
+                        claim_all_response
+                            .claims
+                            .iter()
+                            .map(|claim| (claim.contest_id.clone(), claim.amount))
+                            .collect::<Vec<(ContestId, Uint128)>>(),
+                        expected,
+                        "Claims are not what was expected"
+                    );
+                    assert_eq!(
+                        claim_all_response.remaining, expected_remaining,
+                        "Remaining contests do not match expected"
+                    );
+                }
+                _ => panic!("Could not deserialize claim all response"),
+            }
+        }
+
+        pub fn claim_multiple_failure(&mut self, file_numbers: Vec<&u8>) {
+            self.claim_multiple_in_failure(
+                file_numbers
+                    .into_iter()
+                    .map(|file_number| (file_number, Currency::Snip20))
+                    .collect(),
+            );
+        }
+
+        pub fn claim_multiple_in_failure(&mut self, pools: Vec<(&u8, Currency)>) {
+            let mut requested_ids = Vec::new();
+
+            // Loop through each file number to get the id of the pool in its currency.
+            for (file_number, currency) in pools {
+                requested_ids.push(Self::get_pool_id_from_file(file_number, &currency));
+            }
+            let command = ClaimMultiple {
+                contest_ids: requested_ids,
+            };
+            let response_result = handle_claim_multiple(
+                self.deps.as_mut(),
+                self.env.clone(),
+                self.info.clone(),
+                command,
+                &self.price_source,
+            );
+            assert!(
+                response_result.is_err(),
+                "Expected Claim to Fail but Succeeded"
+            );
+        }
+
+        pub fn claim_multiple_success(
+            &mut self,
+            file_numbers: Vec<&u8>,
+            expected_amount: Option<&u128>,
+        ) {
+            let mut requested_ids = Vec::new();
+
+            // Loop through each file number to get contest info and signature.
+            for file_number in file_numbers {
+                let contest_info = Self::get_open_contest_from_file(file_number);
+
+                // Collect the id from contest_info.
+                requested_ids.push(contest_info.get_id());
+            }
+            let command = ClaimMultiple {
+                contest_ids: requested_ids,
+            };
+            let response_result = handle_claim_multiple(
+                self.deps.as_mut(),
+                self.env.clone(),
+                self.info.clone(),
+                command,
+                &self.price_source,
+            );
+            assert!(
+                response_result.is_ok(),
+                "Expected Claim to succeed but failed"
+            );
+            let response = response_result.unwrap();
+
+            if let Some(binary_data) = response.data {
+                match from_binary::<ExecuteResponse>(&binary_data) {
+                    Ok(claim_response) => match claim_response {
+                        ExecuteResponse::Claim(claim_response) => {
+                            // Successfully deserialized and matched the Claim variant.
+                            // You can now use `claim_data` here.
+                            if let Some(expected) = expected_amount {
+                                assert_eq!(
+                                    claim_response.amount,
+                                    Uint128::from(*expected),
+                                    "Claim Amount does not match expected"
+                                )
+                            }
+                        }
+                        _ => assert!(false, "Could not deserialize claim response"),
+                    },
+                    Err(_e) => assert!(false, "Could not deserialize claim response"),
+                }
+            } else {
+                assert!(false, "Could not deserialize claim response")
+            }
+        }
+
+        pub fn get_contest_success(&mut self, file_number: &u8) {
+            if let Ok(contest_info) = get_contest_open(*file_number) {
+                let command = GetContestById {
+                    contest_id: contest_info.get_id(),
+                };
+                let response_result = handle_get_contest_by_id(self.deps.as_ref(), command);
+                assert!(
+                    response_result.is_ok(),
+                    "Expected Get Contest to receive but failed"
+                );
+
+                match from_binary::<QueryResponse>(&response_result.unwrap()) {
+                    Ok(claim_response) => match claim_response {
+                        QueryResponse::ContestData(contest_data) => {
+                            // Successfully deserialized and matched the Claim variant.
+                            // You can now use `claim_data` here.
+                            assert_eq!(
+                                contest_data.contest_info, contest_info,
+                                "Expected Different Contest Info"
+                            )
+                        }
+                        _ => assert!(false, "Could not deserialize contest data response"),
+                    },
+                    Err(_e) => assert!(false, "Could not deserialize contest data response"),
+                }
+            } else {
+                assert!(
+                    false,
+                    "Contest Info not found or already closed for betting"
+                )
+            }
+        }
+
+        pub fn get_contest_in_success(&mut self, file_number: &u8, currency: &Currency) {
+            let contest_id = Self::get_pool_id_from_file(file_number, currency);
+            let command = GetContestById {
+                contest_id: contest_id.clone(),
+            };
+            let binary_response = handle_get_contest_by_id(self.deps.as_ref(), command)
+                .expect("Expected Get Contest to succeed but failed");
+
+            match from_binary::<QueryResponse>(&binary_response) {
+                Ok(QueryResponse::ContestData(contest_data)) => {
+                    assert_eq!(
+                        contest_data.contest_info.get_id(),
+                        contest_id,
+                        "Expected the contest of the pool"
+                    );
+                    assert_eq!(
+                        contest_data.contest_info.get_currency(),
+                        currency,
+                        "Expected the pool's currency"
+                    );
+                }
+                _ => panic!("Could not deserialize contest data response"),
+            }
+        }
+
+        pub fn get_contests_success(
+            &mut self,
+            command: GetContests,
+            expected_files: &[u8],
+            expected_next_cursor: Option<u32>,
+        ) {
+            let binary_response = handle_get_contests(
+                self.deps.as_ref(),
+                self.env.clone(),
+                command,
+                &self.price_source,
+            )
+            .expect("Expected GetContests to succeed but it failed");
+
+            let response: QueryResponse =
+                from_binary(&binary_response).expect("Failed to deserialize QueryResponse");
+            match response {
+                QueryResponse::ContestsPage(contests_page_response) => {
+                    let expected_ids: Vec<ContestId> = expected_files
+                        .iter()
+                        .map(|file_number| Self::get_open_contest_from_file(file_number).get_id())
+                        .collect();
+                    assert_eq!(
+                        contests_page_response
+                            .contests
+                            .iter()
+                            .map(|contest| contest.contest_info.get_id())
+                            .collect::<Vec<ContestId>>(),
+                        expected_ids,
+                        "Contests are not what was expected"
+                    );
+                    assert_eq!(
+                        contests_page_response.next_cursor, expected_next_cursor,
+                        "Next cursor is not what was expected"
+                    );
+                }
+                _ => panic!("Expected ContestsPage response but received something else"),
+            }
+        }
+
+        pub fn get_user_bets_success(
+            &mut self,
+            command: GetUserBets,
+            expected_files: &[u8],
+            expected_next_cursor: Option<u32>,
+        ) {
+            let binary_response = handle_get_user_bets(
+                self.deps.as_ref(),
+                self.env.clone(),
+                command,
+                &self.price_source,
+                &self.viewing_key_verifier,
+            )
+            .expect("Expected GetUserBets to succeed but it failed");
+
+            let response: QueryResponse =
+                from_binary(&binary_response).expect("Failed to deserialize QueryResponse");
+            match response {
+                QueryResponse::UserBetsPage(user_bets_page_response) => {
+                    let expected_ids: Vec<ContestId> = expected_files
+                        .iter()
+                        .map(|file_number| Self::get_open_contest_from_file(file_number).get_id())
+                        .collect();
+                    assert_eq!(
+                        user_bets_page_response
+                            .contests_bets
+                            .iter()
+                            .map(|contest_bet| contest_bet.contest_info.get_id())
+                            .collect::<Vec<ContestId>>(),
+                        expected_ids,
+                        "User bets are not what was expected"
+                    );
+                    assert_eq!(
+                        user_bets_page_response.next_cursor, expected_next_cursor,
+                        "Next cursor is not what was expected"
+                    );
+                }
+                _ => panic!("Expected UserBetsPage response but received something else"),
+            }
+        }
+
+        pub fn get_contests_by_ids_success(
+            &mut self,
+            file_numbers: &Vec<u8>,
+            expected_num_contests: Option<&u128>,
+        ) {
+            let mut requested_ids = Vec::new();
+
+            // Loop through each file number to get contest info and signature.
+            for file_number in file_numbers {
+                let contest_info = Self::get_open_contest_from_file(file_number);
+
+                // Collect the id from contest_info.
+                requested_ids.push(contest_info.get_id());
+            }
+
+            // Populate the GetContests command with the collected ids.
+            let command = GetContestsByIds {
+                contest_ids: requested_ids.clone(),
+            };
+
+            // Handle the get contests command and unwrap the successful result.
+            let binary_response = handle_get_contests_by_ids(
+                self.deps.as_ref(),
+                self.env.clone(),
+                command,
+                &self.price_source,
+            )
+            .expect("Expected GetContests to succeed but it failed");
+
+            // Deserialize the binary response into QueryResponse.
+            let response: QueryResponse =
+                from_binary(&binary_response).expect("Failed to deserialize QueryResponse");
+
+            if let QueryResponse::ContestDataList(contest_data_list_response) = response {
+                // Check if the number of contests matches the expected number, if provided.
+                if let Some(expected_count) = expected_num_contests {
+                    assert_eq!(
+                        *expected_count as usize,
+                        contest_data_list_response.contests.len(),
+                        "Number of contests does not match the expected number"
+                    );
+                }
+            } else {
+                panic!("Expected ContestDataList response but received something else");
+            }
+        }
+
+        fn get_open_contest_from_file(file_number: &u8) -> ContestInfo {
+            if let Ok(contest_info) = get_contest_open(*file_number) {
+                return contest_info;
+            } else {
+                assert!(false, "Contest File not found");
+                return ContestInfo::new("BTC".to_owned(), 1, 1, vec![], Currency::Snip20, false);
+            }
+        }
+
+        // The id of a file's contest in the pool held in the given currency
+        fn get_pool_id_from_file(file_number: &u8, currency: &Currency) -> ContestId {
+            let contest_info = Self::get_open_contest_from_file(file_number);
+            ContestId::new(
+                contest_info.get_ticker(),
+                contest_info.get_time_of_close(),
+                currency.clone(),
+                false,
+            )
+        }
+
+        pub fn get_snip20_success(&mut self) {
+            let binary_response = handle_get_snip20(self.deps.as_ref())
+                .expect("Expected Get snip20 to succeed but failed");
+
+            let response: QueryResponse =
+                from_binary(&binary_response).expect("Failed to deserialize QueryResponse");
+            if let QueryResponse::Snip20(snip20_response) = response {
+                assert!(
+                    !snip20_response.snip20.address.as_str().is_empty(),
+                    "Address should not be empty"
+                );
+                assert!(
+                    !snip20_response.snip20.code_hash.is_empty(),
+                    "Code hash should not be empty"
+                );
+            } else {
+                panic!("Expected ContestDataList response but received something else");
+            }
+        }
+        pub fn get_minimum_bet_success(&mut self, expected_minimum_bet_option: Option<&u128>) {
+            let binary_response = handle_get_minimum_bet(self.deps.as_ref())
+                .expect("Expected GetMinimumBet to succeed but failed");
+
+            let response: QueryResponse =
+                from_binary(&binary_response).expect("Failed to deserialize QueryResponse");
+
+            match response {
+                QueryResponse::MinimumBet(minimum_bet_response) => {
+                    if let Some(expected_minimum_bet) = expected_minimum_bet_option {
+                        // If an expected minimum bet is specified, assert it matches the retrieved value.
+                        assert_eq!(
+                            minimum_bet_response.minimum_bet,
+                            Uint128::from(*expected_minimum_bet),
+                            "Minimum Bet is not what was expected"
+                        );
+                    } else {
+                        // If no expected minimum bet is provided, just check that a minimum bet exists and is non-zero.
+                        assert!(
+                            minimum_bet_response.minimum_bet > Uint128::zero(),
+                            "Minimum Bet should exist and be greater than zero"
+                        );
+                    }
+                }
+                _ => panic!("Expected MinimumBet response but received something else"),
+            }
+        }
+
+        pub fn get_fee_percent(&mut self, expected_fee_percent_option: Option<&FeePercent>) {
+            let binary_response = handle_get_fee_percent(self.deps.as_ref(), self.env.clone())
+                .expect("Expected GetFeePercent to succeed but failed");
+
+            let response: QueryResponse =
+                from_binary(&binary_response).expect("Failed to deserialize QueryResponse");
+
+            match response {
+                QueryResponse::FeePercent(fee_percent_response) => {
+                    if let Some(expected_fee_percent) = expected_fee_percent_option {
+                        // If an expected fee percent is specified, assert it matches the retrieved value.
+                        assert_eq!(
+                            fee_percent_response.fee_percent, *expected_fee_percent,
+                            "Fee percent is not what was expected"
+                        );
+                    } else {
+                        // If no expected fee percent is provided, just check that itr matches the base fee percent.
+                        assert_eq!(
+                            fee_percent_response.fee_percent,
+                            FeePercent::new(
+                                BASE_FEE_PERCENT_NUMERATOR,
+                                BASE_FEE_PERCENT_DENOMINATOR
+                            ),
+                            "Fee percent should exist and be greater than zero"
+                        );
+                    }
+                }
+                _ => panic!("Expected FeePercent response but received something else"),
+            }
+        }
+
+        pub fn propose_new_owner_success(&mut self, new_owner: &str) {
+            let command = ProposeNewOwner {
+                new_owner: Addr::unchecked(new_owner),
+            };
+            let response = handle_propose_new_owner(self.deps.as_mut(), self.info.clone(), command)
+                .expect("Expected propose new owner to succeed");
+            Self::assert_attribute(&response, "action", "propose_new_owner");
+            Self::assert_attribute(&response, "pending_owner", new_owner);
+        }
+
+        pub fn propose_new_owner_fail(&mut self, new_owner: &str) {
+            let command = ProposeNewOwner {
+                new_owner: Addr::unchecked(new_owner),
+            };
+            let response = handle_propose_new_owner(self.deps.as_mut(), self.info.clone(), command);
+            assert!(
+                response.is_err(),
+                "Expected propose new owner to fail but succeded"
+            )
+        }
+
+        pub fn accept_ownership_success(&mut self, previous_owner: &str) {
+            let response =
+                handle_accept_ownership(self.deps.as_mut(), self.info.clone(), AcceptOwnership {})
+                    .expect("Expected accept ownership to succeed");
+            Self::assert_attribute(&response, "action", "accept_ownership");
+            Self::assert_attribute(&response, "previous_owner", previous_owner);
+            Self::assert_attribute(&response, "owner", self.info.sender.as_str());
+        }
+
+        pub fn accept_ownership_fail(&mut self) {
+            let response =
+                handle_accept_ownership(self.deps.as_mut(), self.info.clone(), AcceptOwnership {});
+            assert!(
+                response.is_err(),
+                "Expected accept ownership to fail but succeded"
+            )
+        }
+
+        pub fn cancel_ownership_transfer_success(&mut self, cancelled_pending_owner: &str) {
+            let response = handle_cancel_ownership_transfer(
+                self.deps.as_mut(),
+                self.info.clone(),
+                CancelOwnershipTransfer {},
+            )
+            .expect("Expected cancel ownership transfer to succeed");
+            Self::assert_attribute(&response, "action", "cancel_ownership_transfer");
+            Self::assert_attribute(
+                &response,
+                "cancelled_pending_owner",
+                cancelled_pending_owner,
+            );
+        }
+
+        pub fn cancel_ownership_transfer_fail(&mut self) {
+            let response = handle_cancel_ownership_transfer(
+                self.deps.as_mut(),
+                self.info.clone(),
+                CancelOwnershipTransfer {},
+            );
+            assert!(
+                response.is_err(),
+                "Expected cancel ownership transfer to fail but succeded"
+            )
+        }
+
+        pub fn get_owner(&mut self, expected_owner: &str, expected_pending_owner: Option<&str>) {
+            let binary_response = handle_get_owner(self.deps.as_ref())
+                .expect("Expected GetOwner to succeed but failed");
+
+            let response: QueryResponse =
+                from_binary(&binary_response).expect("Failed to deserialize QueryResponse");
+
+            match response {
+                QueryResponse::Owner(owner_response) => {
+                    assert_eq!(
+                        owner_response.owner.as_str(),
+                        expected_owner,
+                        "Owner is not what was expected"
+                    );
+                    assert_eq!(
+                        owner_response
+                            .pending_owner
+                            .as_ref()
+                            .map(|owner| owner.as_str()),
+                        expected_pending_owner,
+                        "Pending owner is not what was expected"
+                    );
+                }
+                _ => panic!("Expected Owner response but received something else"),
+            }
+        }
+
+        pub fn grant_role_success(&mut self, address: &str, role: Role) {
+            let command = GrantRole {
+                address: Addr::unchecked(address),
+                role,
+            };
+            let response = handle_grant_role(self.deps.as_mut(), self.info.clone(), command)
+                .expect("Expected grant role to succeed");
+            Self::assert_attribute(&response, "action", "grant_role");
+            Self::assert_attribute(&response, "address", address);
+        }
+
+        pub fn grant_role_fail(&mut self, address: &str, role: Role) {
+            let command = GrantRole {
+                address: Addr::unchecked(address),
+                role,
+            };
+            let response = handle_grant_role(self.deps.as_mut(), self.info.clone(), command);
+            assert!(
+                response.is_err(),
+                "Expected grant role to fail but succeded"
+            )
+        }
+
+        pub fn revoke_role_success(&mut self, address: &str, role: Role) {
+            let command = RevokeRole {
+                address: Addr::unchecked(address),
+                role,
+            };
+            let response = handle_revoke_role(self.deps.as_mut(), self.info.clone(), command)
+                .expect("Expected revoke role to succeed");
+            Self::assert_attribute(&response, "action", "revoke_role");
+            Self::assert_attribute(&response, "address", address);
+        }
+
+        pub fn revoke_role_fail(&mut self, address: &str, role: Role) {
+            let command = RevokeRole {
+                address: Addr::unchecked(address),
+                role,
+            };
+            let response = handle_revoke_role(self.deps.as_mut(), self.info.clone(), command);
+            assert!(
+                response.is_err(),
+                "Expected revoke role to fail but succeded"
+            )
+        }
+
+        pub fn get_roles(&mut self, expected_assignments: &Vec<(&str, Vec<Role>)>) {
+            let binary_response = handle_get_roles(self.deps.as_ref())
+                .expect("Expected GetRoles to succeed but failed");
+
+            let response: QueryResponse =
+                from_binary(&binary_response).expect("Failed to deserialize QueryResponse");
+
+            match response {
+                QueryResponse::Roles(roles_response) => {
+                    assert_eq!(
+                        roles_response.assignments.len(),
+                        expected_assignments.len(),
+                        "Number of role assignments is not what was expected"
+                    );
+                    for (address, roles) in expected_assignments.iter() {
+                        let assignment = roles_response
+                            .assignments
+                            .iter()
+                            .find(|assignment| assignment.address.as_str() == *address)
+                            .expect("Expected address to have roles assigned");
+                        assert_eq!(&assignment.roles, roles, "Roles are not what was expected");
+                    }
+                }
+                _ => panic!("Expected Roles response but received something else"),
+            }
+        }
+
+        pub fn set_fee_recipients_success(&mut self, recipients: &Vec<(&str, u16)>) {
+            let command = SetFeeRecipients {
+                recipients: Self::to_fee_recipients(recipients),
+            };
+            let response =
+                handle_set_fee_recipients(self.deps.as_mut(), self.info.clone(), command);
+            assert!(
+                response.is_ok(),
+                "Expected set fee recipients to succeed but failed"
+            );
+        }
+
+        pub fn set_fee_recipients_fail(&mut self, recipients: &Vec<(&str, u16)>) {
+            let command = SetFeeRecipients {
+                recipients: Self::to_fee_recipients(recipients),
+            };
+            let response =
+                handle_set_fee_recipients(self.deps.as_mut(), self.info.clone(), command);
+            assert!(
+                response.is_err(),
+                "Expected set fee recipients to fail but succeded"
+            );
+        }
+
+        fn to_fee_recipients(recipients: &Vec<(&str, u16)>) -> Vec<FeeRecipient> {
+            recipients
+                .iter()
+                .map(|(address, share_bps)| {
+                    FeeRecipient::new(Addr::unchecked(*address), *share_bps)
+                })
+                .collect()
+        }
+
+        pub fn distribute_fees_success(&mut self, expected_distributions: &Vec<(&str, u128)>) {
+            self.distribute_fees_in_success(&Currency::Snip20, expected_distributions);
+        }
+
+        pub fn distribute_fees_in_success(
+            &mut self,
+            currency: &Currency,
+            expected_distributions: &Vec<(&str, u128)>,
+        ) {
+            let response = handle_distribute_fees(
+                self.deps.as_mut(),
+                self.env.clone(),
+                self.info.clone(),
+                DistributeFees {
+                    currency: Some(currency.clone()),
+                },
+            )
+            .expect("Expected distribute fees to succeed but failed");
+
+            assert_eq!(
+                response.messages.len(),
+                expected_distributions.len(),
+                "Expected one transfer per recipient paid"
+            );
+            for (message, (recipient, amount)) in
+                response.messages.iter().zip(expected_distributions.iter())
+            {
+                match (currency, &message.msg) {
+                    (Currency::Native { denom }, CosmosMsg::Bank(BankMsg::Send { .. })) => {
+                        assert_eq!(
+                            message.msg,
+                            CosmosMsg::Bank(BankMsg::Send {
+                                to_address: recipient.to_string(),
+                                amount: coins(*amount, denom),
+                            }),
+                            "Expected the distribution to be sent in the native denom"
+                        )
+                    }
+                    (Currency::Snip20, CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, .. })) => {
+                        assert_eq!(contract_addr, "Snip20 Address")
+                    }
+                    (
+                        Currency::Token { address },
+                        CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, .. }),
+                    ) => assert_eq!(
+                        contract_addr, address,
+                        "Expected the distribution to be sent by the fee token"
+                    ),
+                    _ => panic!("Expected the distribution to be sent in the fee currency"),
+                }
+            }
+
+            let data = response
+                .data
+                .expect("Expected distribute fees response to have data");
+            match from_binary::<ExecuteResponse>(&data) {
+                Ok(ExecuteResponse::DistributeFees(distribute_fees_response)) => {
+                    let distributions: Vec<(&str, u128)> = distribute_fees_response
+                        .distributions
+                        .iter()
+                        .map(|distribution| {
+                            (distribution.recipient.as_str(), distribution.amount.u128())
+                        })
+                        .collect();
+                    assert_eq!(
+                        &distributions, expected_distributions,
+                        "Distributions are not what was expected"
+                    );
+                }
+                _ => panic!("Could not deserialize distribute fees response"),
+            }
+        }
+
+        pub fn distribute_fees_fail(&mut self) {
+            let response = handle_distribute_fees(
+                self.deps.as_mut(),
+                self.env.clone(),
+                self.info.clone(),
+                DistributeFees { currency: None },
+            );
+            assert!(
+                response.is_err(),
+                "Expected distribute fees to fail but succeded"
+            );
+        }
+
+        pub fn get_fee_recipients(&mut self, expected_recipients: &Vec<(&str, u16)>) {
+            let binary_response = handle_get_fee_recipients(self.deps.as_ref())
+                .expect("Expected GetFeeRecipients to succeed but failed");
+
+            let response: QueryResponse =
+                from_binary(&binary_response).expect("Failed to deserialize QueryResponse");
+
+            match response {
+                QueryResponse::FeeRecipients(fee_recipients_response) => {
+                    assert_eq!(
+                        fee_recipients_response.recipients,
+                        Self::to_fee_recipients(expected_recipients),
+                        "Fee recipients are not what was expected"
+                    );
+                }
+                _ => panic!("Expected FeeRecipients response but received something else"),
+            }
+        }
+
+        pub fn get_fee_payouts(
+            &mut self,
+            recipient: Option<&str>,
+            page: u32,
+            page_size: u32,
+            expected_payouts: &Vec<(&str, u128)>,
+            expected_total: u32,
+        ) {
+            let command = GetFeePayouts {
+                recipient: recipient.map(Addr::unchecked),
+                page,
+                page_size,
+            };
+            let binary_response = handle_get_fee_payouts(self.deps.as_ref(), command)
+                .expect("Expected GetFeePayouts to succeed but failed");
+
+            let response: QueryResponse =
+                from_binary(&binary_response).expect("Failed to deserialize QueryResponse");
+
+            match response {
+                QueryResponse::FeePayouts(fee_payouts_response) => {
+                    let payouts: Vec<(&str, u128)> = fee_payouts_response
+                        .payouts
+                        .iter()
+                        .map(|payout| (payout.recipient().as_str(), payout.amount().u128()))
+                        .collect();
+                    assert_eq!(
+                        &payouts, expected_payouts,
+                        "Fee payouts are not what was expected"
+                    );
+                    assert_eq!(
+                        fee_payouts_response.total, expected_total,
+                        "Total fee payouts is not what was expected"
+                    );
+                }
+                _ => panic!("Expected FeePayouts response but received something else"),
+            }
+        }
+
+        pub fn get_fee_withdrawals(
+            &mut self,
+            page: u32,
+            page_size: u32,
+            expected_withdrawals: &Vec<(u128, &str)>,
+            expected_total: u32,
+        ) {
+            let command = GetFeeWithdrawals { page, page_size };
+            let binary_response = handle_get_fee_withdrawals(self.deps.as_ref(), command)
+                .expect("Expected GetFeeWithdrawals to succeed but failed");
+
+            let response: QueryResponse =
+                from_binary(&binary_response).expect("Failed to deserialize QueryResponse");
+
+            match response {
+                QueryResponse::FeeWithdrawals(fee_withdrawals_response) => {
+                    let withdrawals: Vec<(u128, &str)> = fee_withdrawals_response
+                        .withdrawals
+                        .iter()
+                        .map(|withdrawal| {
+                            (withdrawal.amount().u128(), withdrawal.recipient().as_str())
+                        })
+                        .collect();
+                    assert_eq!(
+                        &withdrawals, expected_withdrawals,
+                        "Fee withdrawals are not what was expected"
+                    );
+                    assert_eq!(
+                        fee_withdrawals_response.total, expected_total,
+                        "Total fee withdrawals is not what was expected"
+                    );
+                }
+                _ => panic!("Expected FeeWithdrawals response but received something else"),
+            }
+        }
+
+        fn assert_bank_send(response: &Response, recipient: &str, amount: u128, denom: &str) {
+            assert_eq!(response.messages.len(), 1, "Expected a single transfer");
+            assert_eq!(
+                response.messages[0].msg,
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: recipient.to_owned(),
+                    amount: vec![Coin::new(amount, denom)],
+                }),
+                "Native transfer is not what was expected"
+            );
+        }
+
+        pub fn set_native_denom_success(&mut self, denom: Option<&str>, minimum_bet: Option<u128>) {
+            let command = SetNativeDenom {
+                denom: denom.map(str::to_owned),
+                minimum_bet: minimum_bet.map(Uint128::new),
+            };
+            let response = handle_set_native_denom(self.deps.as_mut(), self.info.clone(), command)
+                .expect("Expected set native denom to succeed");
+            Self::assert_attribute(&response, "denom", denom.unwrap_or_default());
+        }
+
+        pub fn set_native_denom_fail(&mut self, denom: Option<&str>, minimum_bet: Option<u128>) {
+            let command = SetNativeDenom {
+                denom: denom.map(str::to_owned),
+                minimum_bet: minimum_bet.map(Uint128::new),
+            };
+            let response = handle_set_native_denom(self.deps.as_mut(), self.info.clone(), command);
+            assert!(
+                response.is_err(),
+                "Expected set native denom to fail but succeeded"
+            );
+        }
+
+        pub fn get_native_denom(
+            &mut self,
+            expected_denom: Option<&str>,
+            expected_minimum_bet: u128,
+        ) {
+            let binary_response = handle_get_native_denom(self.deps.as_ref())
+                .expect("Expected GetNativeDenom to succeed but failed");
+
+            let response: QueryResponse =
+                from_binary(&binary_response).expect("Failed to deserialize QueryResponse");
+            match response {
+                QueryResponse::NativeDenom(native_denom_response) => {
+                    assert_eq!(
+                        native_denom_response.denom.as_deref(),
+                        expected_denom,
+                        "Native denom is not what was expected"
+                    );
+                    assert_eq!(
+                        native_denom_response.minimum_bet,
+                        Uint128::new(expected_minimum_bet),
+                        "Native minimum bet is not what was expected"
+                    );
+                }
+                _ => panic!("Expected NativeDenom response but received something else"),
+            }
+        }
+
+        fn bet_native(
+            &mut self,
+            ticker: &str,
+            outcome_id: &u8,
+            funds: &[Coin],
+            contest_id: Option<ContestId>,
+            refund_on_failure: Option<bool>,
+        ) -> StdResult<Response> {
+            let command = NativeBetContest {
+                ticker: ticker.to_owned(),
+                outcome_id: *outcome_id,
+                contest_id,
+                roll_over: None,
+                refund_on_failure,
+            };
+            let info = mock_info(self.info.sender.as_str(), funds);
+            handle_bet_contest(self.deps.as_mut(), self.env.clone(), info, command)
+        }
+
+        pub fn bet_native_success(&mut self, ticker: &str, outcome_id: &u8, funds: &[Coin]) {
+            let response = self
+                .bet_native(ticker, outcome_id, funds, None, None)
+                .expect("Expected native bet to succeed");
+            assert!(
+                response.messages.is_empty(),
+                "Expected an accepted bet to keep the funds"
+            );
+        }
+
+        pub fn bet_native_fail(&mut self, ticker: &str, outcome_id: &u8, funds: &[Coin]) {
+            let response = self.bet_native(ticker, outcome_id, funds, None, None);
+            assert!(
+                response.is_err(),
+                "Expected native bet to fail but succeeded"
+            );
+        }
+
+        pub fn bet_native_on_contest_by_id_fail(
+            &mut self,
+            contest_id: &ContestId,
+            outcome_id: &u8,
+            funds: &[Coin],
+        ) {
+            let response = self.bet_native(
+                contest_id.ticker(),
+                outcome_id,
+                funds,
+                Some(contest_id.clone()),
+                None,
+            );
+            assert!(
+                response.is_err(),
+                "Expected native bet to fail but succeeded"
+            );
+        }
+
+        pub fn bet_native_refunded(
+            &mut self,
+            ticker: &str,
+            outcome_id: &u8,
+            funds: &[Coin],
+            expected_error_code: BetErrorCode,
+        ) {
+            let response = self
+                .bet_native(ticker, outcome_id, funds, None, Some(true))
+                .expect("Expected native bet to be refunded");
+            let sender = self.info.sender.to_string();
+            Self::assert_bank_send(&response, &sender, funds[0].amount.u128(), &funds[0].denom);
+            match from_binary::<ExecuteResponse>(&response.data.expect("Expected response data")) {
+                Ok(ExecuteResponse::Bet(bet_response)) => assert_eq!(
+                    bet_response.error_code,
+                    Some(expected_error_code),
+                    "Bet error code does not match"
+                ),
+                _ => panic!("Could not deserialize bet response"),
+            }
+        }
+
+        pub fn claim_native_success(
+            &mut self,
+            file_number: &u8,
+            expected_amount: &u128,
+            denom: &str,
+        ) {
+            let currency = Currency::Native {
+                denom: denom.to_owned(),
+            };
+            let command = Claim {
+                contest_id: Self::get_pool_id_from_file(file_number, &currency),
+            };
+            let response = handle_claim(
+                self.deps.as_mut(),
+                self.env.clone(),
+                self.info.clone(),
+                command,
+                &self.price_source,
+            )
+            .expect("Expected native claim to succeed");
+            let sender = self.info.sender.to_string();
+            Self::assert_bank_send(&response, &sender, *expected_amount, denom);
+        }
+
+        pub fn claim_native_fees_success(&mut self, denom: &str, expected_amount: &u128) {
+            let command = ClaimFees {
+                amount: None,
+                recipient: None,
+                currency: Some(Currency::Native {
+                    denom: denom.to_owned(),
+                }),
+            };
+            let response = handle_claim_fees(
+                self.deps.as_mut(),
+                self.env.clone(),
+                self.info.clone(),
+                command,
+            )
+            .expect("Expected claiming native fees to succeed");
+            let sender = self.info.sender.to_string();
+            Self::assert_bank_send(&response, &sender, *expected_amount, denom);
+        }
+
+        pub fn get_native_claimable_fees(&mut self, expected_fees: Vec<(&str, u128)>) {
+            let binary_response = handle_get_claimable_fees(self.deps.as_ref())
+                .expect("Expected GetClaimableFees to succeed but failed");
+
+            let response: QueryResponse =
+                from_binary(&binary_response).expect("Failed to deserialize QueryResponse");
+            match response {
+                QueryResponse::ClaimableFees(claimable_fees_response) => assert_eq!(
+                    claimable_fees_response
+                        .native_claimable_fees
+                        .iter()
+                        .map(|fee| (fee.denom.as_str(), fee.amount.u128()))
+                        .collect::<Vec<(&str, u128)>>(),
+                    expected_fees,
+                    "Native claimable fees are not what was expected"
+                ),
+                _ => panic!("Expected ClaimableFees response but received something else"),
+            }
+        }
+
+        fn assert_attribute(response: &Response, key: &str, expected_value: &str) {
+            let attribute = response
+                .attributes
+                .iter()
+                .find(|attribute| attribute.key == key)
+                .expect("Expected attribute on response");
+            assert_eq!(
+                attribute.value, expected_value,
+                "Attribute value is not what was expected"
+            );
+        }
+
+        pub fn fail_safe_success(&mut self, status: ContractStatus) {
+            let command = FailSafe { status };
+            let response = handle_fail_safe(self.deps.as_mut(), self.info.clone(), command);
+            assert!(response.is_ok(), "Expected fail safe to succeed")
+        }
+
+        pub fn fail_safe_fail(&mut self, status: ContractStatus) {
+            let command = FailSafe { status };
+            let response = handle_fail_safe(self.deps.as_mut(), self.info.clone(), command);
+            assert!(response.is_err(), "Expected fail safe to fail but succeded")
+        }
+
+        pub fn get_contract_status(&mut self, expected_status: ContractStatus) {
+            let binary_response = handle_get_contract_status(self.deps.as_ref())
+                .expect("Expected GetContractStatus to succeed but failed");
+
+            let response: QueryResponse =
+                from_binary(&binary_response).expect("Failed to deserialize QueryResponse");
+
+            match response {
+                QueryResponse::ContractStatus(contract_status_response) => {
+                    assert_eq!(
+                        contract_status_response.status, expected_status,
+                        "Contract status is not what was expected"
+                    );
+                }
+                _ => panic!("Expected ContractStatus response but received something else"),
+            }
+        }
+
+        pub fn set_ticker_config_success(
+            &mut self,
+            ticker: &str,
+            interval: Option<u64>,
+            price_feed: Option<ContractInfo>,
+        ) {
+            let command = SetTickerConfig {
+                ticker: ticker.to_owned(),
+                interval,
+                price_feed,
+            };
+            let response = handle_set_ticker_config(self.deps.as_mut(), self.info.clone(), command);
+            assert!(response.is_ok(), "Expected set ticker config to succeed")
+        }
+
+        pub fn set_ticker_config_fail(
+            &mut self,
+            ticker: &str,
+            interval: Option<u64>,
+            price_feed: Option<ContractInfo>,
+        ) {
+            let command = SetTickerConfig {
+                ticker: ticker.to_owned(),
+                interval,
+                price_feed,
+            };
+            let response = handle_set_ticker_config(self.deps.as_mut(), self.info.clone(), command);
+            assert!(
+                response.is_err(),
+                "Expected set ticker config to fail but succeded"
+            )
+        }
+
+        pub fn set_ticker_outcomes_success(
+            &mut self,
+            ticker: &str,
+            outcome_bands: Option<Vec<OutcomeBand>>,
+        ) {
+            let command = SetTickerOutcomes {
+                ticker: ticker.to_owned(),
+                outcome_bands,
+            };
+            let response =
+                handle_set_ticker_outcomes(self.deps.as_mut(), self.info.clone(), command);
+            assert!(response.is_ok(), "Expected set ticker outcomes to succeed")
+        }
+
+        pub fn set_ticker_outcomes_fail(
+            &mut self,
+            ticker: &str,
+            outcome_bands: Option<Vec<OutcomeBand>>,
+        ) {
+            let command = SetTickerOutcomes {
+                ticker: ticker.to_owned(),
+                outcome_bands,
+            };
+            let response =
+                handle_set_ticker_outcomes(self.deps.as_mut(), self.info.clone(), command);
+            assert!(
+                response.is_err(),
+                "Expected set ticker outcomes to fail but succeded"
+            )
+        }
+
+        pub fn create_contest_success(
+            &mut self,
+            ticker: &str,
+            time_of_close: u64,
+            time_of_resolve: u64,
+            options: Vec<OutcomeBand>,
+        ) -> ContestId {
+            let command = CreateContest {
+                ticker: ticker.to_owned(),
+                time_of_close,
+                time_of_resolve,
+                options,
+                currency: None,
+            };
+            let response = handle_create_contest(
+                self.deps.as_mut(),
+                self.env.clone(),
+                self.info.clone(),
+                command,
+            )
+            .expect("Expected create contest to succeed");
+            let contest_id =
+                ContestId::new(ticker.to_owned(), time_of_close, Currency::Snip20, true);
+            Self::assert_attribute(&response, "contest_id", &contest_id.to_string());
+            contest_id
+        }
+
+        pub fn create_contest_fail(
+            &mut self,
+            ticker: &str,
+            time_of_close: u64,
+            time_of_resolve: u64,
+            options: Vec<OutcomeBand>,
+        ) {
+            let command = CreateContest {
+                ticker: ticker.to_owned(),
+                time_of_close,
+                time_of_resolve,
+                options,
+                currency: None,
+            };
+            let response = handle_create_contest(
+                self.deps.as_mut(),
+                self.env.clone(),
+                self.info.clone(),
+                command,
+            );
+            assert!(
+                response.is_err(),
+                "Expected create contest to fail but succeded"
+            )
+        }
+
+        pub fn bet_on_contest_by_id_success(
+            &mut self,
+            contest_id: &ContestId,
+            outcome_to_bet_on: &u8,
+            amount_to_bet: &u128,
+        ) {
+            let command = BetContest {
+                ticker: contest_id.ticker().to_owned(),
+                outcome_id: *outcome_to_bet_on,
+                user: self.info.sender.clone(),
+                contest_id: Some(contest_id.clone()),
+                roll_over: None,
+                refund_on_failure: None,
             };
-            let response_result = handle_claim_multiple(
+            let response = handle_bet_on_contest(
                 self.deps.as_mut(),
                 self.env.clone(),
-                self.info.clone(),
                 command,
+                Uint128::new(*amount_to_bet),
+                Currency::Snip20,
             );
-            assert!(
-                response_result.is_err(),
-                "Expected Claim to Fail but Succeeded"
-            );
+            response.expect("Failed to bet on contest");
         }
 
-        pub fn claim_multiple_success(
+        pub fn bet_on_contest_by_id_fail(
             &mut self,
-            file_numbers: Vec<&u8>,
-            expected_amount: Option<&u128>,
+            contest_id: &ContestId,
+            outcome_to_bet_on: &u8,
+            amount_to_bet: &u128,
         ) {
-            let mut requested_ids = Vec::new();
-
-            // Loop through each file number to get contest info and signature.
-            for file_number in file_numbers {
-                let contest_info = Self::get_open_contest_from_file(file_number);
-
-                // Collect the id from contest_info.
-                requested_ids.push(contest_info.get_id());
-            }
-            let command = ClaimMultiple {
-                contest_ids: requested_ids,
+            let command = BetContest {
+                ticker: contest_id.ticker().to_owned(),
+                outcome_id: *outcome_to_bet_on,
+                user: self.info.sender.clone(),
+                contest_id: Some(contest_id.clone()),
+                roll_over: None,
+                refund_on_failure: None,
             };
-            let response_result = handle_claim_multiple(
+            let response = handle_bet_on_contest(
                 self.deps.as_mut(),
                 self.env.clone(),
-                self.info.clone(),
                 command,
+                Uint128::new(*amount_to_bet),
+                Currency::Snip20,
             );
             assert!(
-                response_result.is_ok(),
-                "Expected Claim to succeed but failed"
+                response.is_err(),
+                "Expected bet on contest to fail, but it succeeded"
             );
-            let response = response_result.unwrap();
-
-            if let Some(binary_data) = response.data {
-                match from_binary::<ExecuteResponse>(&binary_data) {
-                    Ok(claim_response) => match claim_response {
-                        ExecuteResponse::Claim(claim_response) => {
-                            // Successfully deserialized and matched the Claim variant.
-                            // You can now use `claim_data` here.
-                            if let Some(expected) = expected_amount {
-                                assert_eq!(
-                                    claim_response.amount,
-                                    Uint128::from(*expected),
-                                    "Claim Amount does not match expected"
-                                )
-                            }
-                        }
-                        _ => assert!(false, "Could not deserialize claim response"),
-                    },
-                    Err(_e) => assert!(false, "Could not deserialize claim response"),
-                }
-            } else {
-                assert!(false, "Could not deserialize claim response")
-            }
-        }
-
-        pub fn get_contest_success(&mut self, file_number: &u8) {
-            if let Ok(contest_info) = get_contest_open(*file_number) {
-                let command = GetContestById {
-                    contest_id: contest_info.get_id(),
-                };
-                let response_result = handle_get_contest_by_id(self.deps.as_ref(), command);
-                assert!(
-                    response_result.is_ok(),
-                    "Expected Get Contest to receive but failed"
-                );
-
-                match from_binary::<QueryResponse>(&response_result.unwrap()) {
-                    Ok(claim_response) => match claim_response {
-                        QueryResponse::ContestData(contest_data) => {
-                            // Successfully deserialized and matched the Claim variant.
-                            // You can now use `claim_data` here.
-                            assert_eq!(
-                                contest_data.contest_info, contest_info,
-                                "Expected Different Contest Info"
-                            )
-                        }
-                        _ => assert!(false, "Could not deserialize contest data response"),
-                    },
-                    Err(_e) => assert!(false, "Could not deserialize contest data response"),
-                }
-            } else {
-                assert!(
-                    false,
-                    "Contest Info not found or already closed for betting"
-                )
-            }
         }
 
-        pub fn get_contests_by_ids_success(
+        pub fn claim_contest_by_id_success(
             &mut self,
-            file_numbers: &Vec<u8>,
-            expected_num_contests: Option<&u128>,
+            contest_id: &ContestId,
+            expected_amount: &u128,
         ) {
-            let mut requested_ids = Vec::new();
-
-            // Loop through each file number to get contest info and signature.
-            for file_number in file_numbers {
-                let contest_info = Self::get_open_contest_from_file(file_number);
-
-                // Collect the id from contest_info.
-                requested_ids.push(contest_info.get_id());
-            }
-
-            // Populate the GetContests command with the collected ids.
-            let command = GetContestsByIds {
-                contest_ids: requested_ids.clone(),
+            let command = Claim {
+                contest_id: contest_id.clone(),
             };
-
-            // Handle the get contests command and unwrap the successful result.
-            let binary_response =
-                handle_get_contests_by_ids(self.deps.as_ref(), self.env.clone(), command)
-                    .expect("Expected GetContests to succeed but it failed");
-
-            // Deserialize the binary response into QueryResponse.
-            let response: QueryResponse =
-                from_binary(&binary_response).expect("Failed to deserialize QueryResponse");
-
-            if let QueryResponse::ContestDataList(contest_data_list_response) = response {
-                // Check if the number of contests matches the expected number, if provided.
-                if let Some(expected_count) = expected_num_contests {
-                    assert_eq!(
-                        *expected_count as usize,
-                        contest_data_list_response.contests.len(),
-                        "Number of contests does not match the expected number"
-                    );
-                }
-            } else {
-                panic!("Expected ContestDataList response but received something else");
+            let response = handle_claim(
+                self.deps.as_mut(),
+                self.env.clone(),
+                self.info.clone(),
+                command,
+                &self.price_source,
+            )
+            .expect("Expected Claim to succeed but failed");
+            match response
+                .data
+                .map(|data| from_binary::<ExecuteResponse>(&data))
+            {
+                Some(Ok(ExecuteResponse::Claim(claim_response))) => assert_eq!(
+                    claim_response.amount,
+                    Uint128::from(*expected_amount),
+                    "Claim Amount does not match expected"
+                ),
+                _ => panic!("Could not deserialize claim response"),
             }
         }
 
-        fn get_open_contest_from_file(file_number: &u8) -> ContestInfo {
-            if let Ok(contest_info) = get_contest_open(*file_number) {
-                return contest_info;
-            } else {
-                assert!(false, "Contest File not found");
-                return ContestInfo::new("BTC".to_owned(), 1, 1, vec![]);
-            }
+        pub fn claim_contest_by_id_failure(&mut self, contest_id: &ContestId) {
+            let command = Claim {
+                contest_id: contest_id.clone(),
+            };
+            let response = handle_claim(
+                self.deps.as_mut(),
+                self.env.clone(),
+                self.info.clone(),
+                command,
+                &self.price_source,
+            );
+            assert!(response.is_err(), "Expected Claim to Fail but Succeeded");
         }
 
-        pub fn get_snip20_success(&mut self) {
-            let binary_response = handle_get_snip20(self.deps.as_ref())
-                .expect("Expected Get snip20 to succeed but failed");
-
-            let response: QueryResponse =
-                from_binary(&binary_response).expect("Failed to deserialize QueryResponse");
-            if let QueryResponse::Snip20(snip20_response) = response {
-                assert!(
-                    !snip20_response.snip20.address.as_str().is_empty(),
-                    "Address should not be empty"
-                );
-                assert!(
-                    !snip20_response.snip20.code_hash.is_empty(),
-                    "Code hash should not be empty"
-                );
-            } else {
-                panic!("Expected ContestDataList response but received something else");
-            }
-        }
-        pub fn get_minimum_bet_success(&mut self, expected_minimum_bet_option: Option<&u128>) {
-            let binary_response = handle_get_minimum_bet(self.deps.as_ref())
-                .expect("Expected GetMinimumBet to succeed but failed");
+        pub fn get_contest_price_feed(&mut self, file_number: &u8, expected: &ContractInfo) {
+            let contest_info = Self::get_open_contest_from_file(file_number);
+            let command = GetContestById {
+                contest_id: contest_info.get_id(),
+            };
+            let binary_response = handle_get_contest_by_id(self.deps.as_ref(), command)
+                .expect("Expected Get Contest to succeed but failed");
 
             let response: QueryResponse =
                 from_binary(&binary_response).expect("Failed to deserialize QueryResponse");
 
             match response {
-                QueryResponse::MinimumBet(minimum_bet_response) => {
-                    if let Some(expected_minimum_bet) = expected_minimum_bet_option {
-                        // If an expected minimum bet is specified, assert it matches the retrieved value.
-                        assert_eq!(
-                            minimum_bet_response.minimum_bet,
-                            Uint128::from(*expected_minimum_bet),
-                            "Minimum Bet is not what was expected"
-                        );
-                    } else {
-                        // If no expected minimum bet is provided, just check that a minimum bet exists and is non-zero.
-                        assert!(
-                            minimum_bet_response.minimum_bet > Uint128::zero(),
-                            "Minimum Bet should exist and be greater than zero"
-                        );
-                    }
+                QueryResponse::ContestData(contest_data) => {
+                    assert_eq!(
+                        &contest_data.price_feed, expected,
+                        "Price feed is not what was expected"
+                    );
                 }
-                _ => panic!("Expected MinimumBet response but received something else"),
+                _ => panic!("Expected ContestData response but received something else"),
             }
         }
 
-        pub fn get_fee_percent(&mut self, expected_fee_percent_option: Option<&FeePercent>) {
-            let binary_response = handle_get_fee_percent(self.deps.as_ref())
-                .expect("Expected GetFeePercent to succeed but failed");
+        pub fn get_tickers(&mut self, expected_tickers: &Vec<(&str, bool)>) {
+            let binary_response = handle_get_tickers(self.deps.as_ref())
+                .expect("Expected GetTickers to succeed but failed");
 
             let response: QueryResponse =
                 from_binary(&binary_response).expect("Failed to deserialize QueryResponse");
 
             match response {
-                QueryResponse::FeePercent(fee_percent_response) => {
-                    if let Some(expected_fee_percent) = expected_fee_percent_option {
-                        // If an expected fee percent is specified, assert it matches the retrieved value.
-                        assert_eq!(
-                            fee_percent_response.fee_percent, *expected_fee_percent,
-                            "Fee percent is not what was expected"
-                        );
-                    } else {
-                        // If no expected fee percent is provided, just check that itr matches the base fee percent.
-                        assert_eq!(
-                            fee_percent_response.fee_percent,
-                            FeePercent::new(
-                                BASE_FEE_PERCENT_NUMERATOR,
-                                BASE_FEE_PERCENT_DENOMINATOR
-                            ),
-                            "Fee percent should exist and be greater than zero"
-                        );
-                    }
+                QueryResponse::Tickers(tickers_response) => {
+                    let tickers: Vec<(&str, bool)> = tickers_response
+                        .tickers
+                        .iter()
+                        .map(|ticker_info| (ticker_info.ticker().as_str(), *ticker_info.enabled()))
+                        .collect();
+                    assert_eq!(
+                        &tickers, expected_tickers,
+                        "Tickers are not what was expected"
+                    );
                 }
-                _ => panic!("Expected FeePercent response but received something else"),
+                _ => panic!("Expected Tickers response but received something else"),
             }
         }
 
@@ -724,8 +3045,12 @@ pub mod tests {
                 viewing_key: "Valid Viewing Key".to_owned(),
             };
 
-            let binary_response = handle_get_users_number_of_bets(self.deps.as_ref(), command)
-                .expect("Expected GetUsersNumberOfBets to succeed but failed");
+            let binary_response = handle_get_users_number_of_bets(
+                self.deps.as_ref(),
+                command,
+                &self.viewing_key_verifier,
+            )
+            .expect("Expected GetUsersNumberOfBets to succeed but failed");
 
             let response: QueryResponse =
                 from_binary(&binary_response).expect("Failed to deserialize QueryResponse");
@@ -792,6 +3117,29 @@ pub mod tests {
             }
         }
 
+        pub fn get_volume_in(&mut self, currency: &Currency, expected_volume: &Uint128) {
+            let binary_response = handle_get_total_volume(self.deps.as_ref())
+                .expect("Expected GetTotalVolume to succeed but failed");
+
+            match from_binary::<QueryResponse>(&binary_response) {
+                Ok(QueryResponse::TotalVolume(total_volume_response)) => {
+                    let volume = match currency {
+                        Currency::Snip20 => total_volume_response.total_volume,
+                        _ => total_volume_response
+                            .currency_volumes
+                            .iter()
+                            .find(|currency_volume| &currency_volume.currency == currency)
+                            .map_or(Uint128::zero(), |currency_volume| currency_volume.volume),
+                    };
+                    assert_eq!(
+                        volume, *expected_volume,
+                        "Total volume is not what was expected"
+                    );
+                }
+                _ => panic!("Expected TotalVolumeResponse but received something else"),
+            }
+        }
+
         pub fn get_claimable_fees(&mut self, expected_claimable_fees_option: Option<&Uint128>) {
             let binary_response = handle_get_claimable_fees(self.deps.as_ref())
                 .expect("Expected GetClaimableFees to succeed but failed");
@@ -826,16 +3174,34 @@ pub mod tests {
             expected_side_option: Option<&u8>,
             expected_has_been_paid_option: Option<&bool>,
         ) {
-            let contest_info = Self::get_open_contest_from_file(file_number);
+            self.get_user_bet_in_success(
+                file_number,
+                &Currency::Snip20,
+                expected_bet_option,
+                expected_side_option,
+                expected_has_been_paid_option,
+            );
+        }
+
+        pub fn get_user_bet_in_success(
+            &mut self,
+            file_number: &u8,
+            currency: &Currency,
+            expected_bet_option: Option<&u128>,
+            expected_side_option: Option<&u8>,
+            expected_has_been_paid_option: Option<&bool>,
+        ) {
+            let contest_id = Self::get_pool_id_from_file(file_number, currency);
 
             let command = GetUserBet {
                 user: self.info.sender.clone(),
-                contest_id: contest_info.get_id(),
+                contest_id: contest_id.clone(),
                 viewing_key: "Valid Viewing Key".to_owned(),
             };
 
-            let binary_response = handle_user_bet(self.deps.as_ref(), command)
-                .expect("Expected GetUserBet to succeed but failed");
+            let binary_response =
+                handle_user_bet(self.deps.as_ref(), command, &self.viewing_key_verifier)
+                    .expect("Expected GetUserBet to succeed but failed");
 
             let response: QueryResponse =
                 from_binary(&binary_response).expect("Failed to deserialize QueryResponse");
@@ -846,7 +3212,7 @@ pub mod tests {
                 assert_eq!(bet.get_user(), &self.info.sender, "User does not match");
                 assert_eq!(
                     bet.get_contest_id(),
-                    &contest_info.get_id(),
+                    &contest_id,
                     "Contest ID does not match"
                 );
 
@@ -877,6 +3243,38 @@ pub mod tests {
             }
         }
 
+        pub fn get_user_bet_cancellation(
+            &mut self,
+            file_number: &u8,
+            expected_cancellation: Option<(u128, u128)>,
+        ) {
+            let contest_info = Self::get_open_contest_from_file(file_number);
+
+            let command = GetUserBet {
+                user: self.info.sender.clone(),
+                contest_id: contest_info.get_id(),
+                viewing_key: "Valid Viewing Key".to_owned(),
+            };
+
+            let binary_response =
+                handle_user_bet(self.deps.as_ref(), command, &self.viewing_key_verifier)
+                    .expect("Expected GetUserBet to succeed but failed");
+
+            let response: QueryResponse =
+                from_binary(&binary_response).expect("Failed to deserialize QueryResponse");
+            match response {
+                QueryResponse::UserBet(user_bet_response) => assert_eq!(
+                    user_bet_response.cancellation.map(|cancellation| (
+                        cancellation.refunded().u128(),
+                        cancellation.fee().u128()
+                    )),
+                    expected_cancellation,
+                    "Bet cancellation does not match"
+                ),
+                _ => panic!("Expected UserBet response but received something else"),
+            }
+        }
+
         pub fn get_user_bet_failure(&mut self, file_number: &u8) {
             let contest_info = Self::get_open_contest_from_file(file_number);
 
@@ -886,8 +3284,9 @@ pub mod tests {
                 viewing_key: "Valid Viewing Key".to_owned(),
             };
 
-            let _binary_response = handle_user_bet(self.deps.as_ref(), command)
-                .expect_err("Expected GetUserBet to fail but succeeded");
+            let _binary_response =
+                handle_user_bet(self.deps.as_ref(), command, &self.viewing_key_verifier)
+                    .expect_err("Expected GetUserBet to fail but succeeded");
         }
 
         fn query_users_claimable_contests(&mut self) -> StdResult<UsersBetsResponse> {
@@ -895,8 +3294,13 @@ pub mod tests {
                 user: self.info.sender.clone(),
                 viewing_key: "valid viewing key".to_owned(),
             };
-            let binary_response =
-                handle_get_claimable_contests(self.deps.as_ref(), self.env.clone(), command)?;
+            let binary_response = handle_get_claimable_contests(
+                self.deps.as_ref(),
+                self.env.clone(),
+                command,
+                &self.price_source,
+                &self.viewing_key_verifier,
+            )?;
             let query_response: QueryResponse = from_binary(&binary_response)?;
             match query_response {
                 QueryResponse::UsersBets(response) => Ok(response),
@@ -936,8 +3340,13 @@ pub mod tests {
                 user: self.info.sender.clone(),
                 viewing_key: "valid viewing key".to_owned(),
             };
-            let binary_response =
-                handle_users_last_ten_bets(self.deps.as_ref(), self.env.clone(), command)?;
+            let binary_response = handle_users_last_ten_bets(
+                self.deps.as_ref(),
+                self.env.clone(),
+                command,
+                &self.price_source,
+                &self.viewing_key_verifier,
+            )?;
             let query_response: QueryResponse = from_binary(&binary_response)?;
             match query_response {
                 QueryResponse::UsersBets(response) => Ok(response),
@@ -992,8 +3401,11 @@ pub mod tests {
         }
 
         fn query_last_ten_contests(&mut self) -> StdResult<ContestDataListResponse> {
-            let binary_response =
-                handle_get_last_ten_contests(self.deps.as_ref(), self.env.clone())?;
+            let binary_response = handle_get_last_ten_contests(
+                self.deps.as_ref(),
+                self.env.clone(),
+                &self.price_source,
+            )?;
             let query_response: QueryResponse = from_binary(&binary_response)?;
             match query_response {
                 QueryResponse::ContestDataList(response) => Ok(response),
@@ -1055,8 +3467,13 @@ pub mod tests {
                 viewing_key: "valid viewing key".to_owned(),
                 contest_ids: ids,
             };
-            let binary_response =
-                handle_get_users_list_of_bets(self.deps.as_ref(), self.env.clone(), command)?;
+            let binary_response = handle_get_users_list_of_bets(
+                self.deps.as_ref(),
+                self.env.clone(),
+                command,
+                &self.price_source,
+                &self.viewing_key_verifier,
+            )?;
             let query_response: QueryResponse = from_binary(&binary_response)?;
             match query_response {
                 QueryResponse::UsersBets(response) => Ok(response),